@@ -0,0 +1,355 @@
+//! The AES-128 block cipher (FIPS 197) and the CBC and CMAC modes of operation.
+//!
+//! This is a straightforward table-based implementation intended for microcontrollers without
+//! cryptographic acceleration. It makes no attempt at being resistant to timing attacks.
+
+
+/// The length of an AES block in bytes.
+pub const BLOCK_LENGTH: usize = 16;
+
+/// The length of an AES-128 key in bytes.
+pub const KEY_LENGTH: usize = 16;
+
+/// The number of rounds of AES-128.
+const ROUNDS: usize = 10;
+
+
+/// The AES substitution box.
+const SBOX: [u8; 256] = make_sbox();
+
+/// The inverse of the AES substitution box.
+const INV_SBOX: [u8; 256] = make_inv_sbox();
+
+
+/// Multiplies two elements of GF(2^8) modulo the AES polynomial.
+const fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product: u8 = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        // x^8 + x^4 + x^3 + x + 1 =~= (1) 0001 1011
+        let carry = a & 0b1000_0000 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0b0001_1011;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Precomputes the AES substitution box.
+const fn make_sbox() -> [u8; 256] {
+    let mut ret = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        // find the multiplicative inverse (0 maps to 0)
+        let mut inverse: u8 = 0;
+        let mut j = 1;
+        while j < 256 {
+            if gf_mul(i as u8, j as u8) == 1 {
+                inverse = j as u8;
+                break;
+            }
+            j += 1;
+        }
+
+        // affine transformation
+        ret[i] = inverse
+            ^ inverse.rotate_left(1)
+            ^ inverse.rotate_left(2)
+            ^ inverse.rotate_left(3)
+            ^ inverse.rotate_left(4)
+            ^ 0x63;
+        i += 1;
+    }
+    ret
+}
+
+/// Precomputes the inverse of the AES substitution box.
+const fn make_inv_sbox() -> [u8; 256] {
+    let sbox = make_sbox();
+    let mut ret = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        ret[sbox[i] as usize] = i as u8;
+        i += 1;
+    }
+    ret
+}
+
+
+/// An AES-128 key, expanded into its round keys.
+#[derive(Clone)]
+pub struct Aes128 {
+    round_keys: [[u8; BLOCK_LENGTH]; ROUNDS + 1],
+}
+impl Aes128 {
+    /// Expands the given key.
+    pub fn new(key: &[u8; KEY_LENGTH]) -> Self {
+        let mut words = [[0u8; 4]; 4 * (ROUNDS + 1)];
+        for (i, word) in words.iter_mut().take(4).enumerate() {
+            word.copy_from_slice(&key[4*i..4*i+4]);
+        }
+
+        let mut round_constant: u8 = 0x01;
+        for i in 4..words.len() {
+            let mut word = words[i-1];
+            if i % 4 == 0 {
+                word.rotate_left(1);
+                for b in &mut word {
+                    *b = SBOX[usize::from(*b)];
+                }
+                word[0] ^= round_constant;
+                round_constant = gf_mul(round_constant, 0x02);
+            }
+            for (j, b) in word.iter_mut().enumerate() {
+                *b ^= words[i-4][j];
+            }
+            words[i] = word;
+        }
+
+        let mut round_keys = [[0u8; BLOCK_LENGTH]; ROUNDS + 1];
+        for (r, round_key) in round_keys.iter_mut().enumerate() {
+            for c in 0..4 {
+                round_key[4*c..4*c+4].copy_from_slice(&words[4*r + c]);
+            }
+        }
+        Self {
+            round_keys,
+        }
+    }
+
+    /// Encrypts a single block in place.
+    pub fn encrypt_block(&self, block: &mut [u8; BLOCK_LENGTH]) {
+        xor_into(block, &self.round_keys[0]);
+        for round in 1..=ROUNDS {
+            for b in block.iter_mut() {
+                *b = SBOX[usize::from(*b)];
+            }
+            shift_rows(block);
+            if round != ROUNDS {
+                mix_columns(block);
+            }
+            xor_into(block, &self.round_keys[round]);
+        }
+    }
+
+    /// Decrypts a single block in place.
+    pub fn decrypt_block(&self, block: &mut [u8; BLOCK_LENGTH]) {
+        xor_into(block, &self.round_keys[ROUNDS]);
+        for round in (0..ROUNDS).rev() {
+            inv_shift_rows(block);
+            for b in block.iter_mut() {
+                *b = INV_SBOX[usize::from(*b)];
+            }
+            xor_into(block, &self.round_keys[round]);
+            if round != 0 {
+                inv_mix_columns(block);
+            }
+        }
+    }
+
+    /// Encrypts the data in place in CBC mode. Returns `false` and leaves the data untouched if its
+    /// length is not a multiple of the block length.
+    pub fn cbc_encrypt(&self, iv: &[u8; BLOCK_LENGTH], data: &mut [u8]) -> bool {
+        if !data.len().is_multiple_of(BLOCK_LENGTH) {
+            return false;
+        }
+
+        let mut previous = *iv;
+        for chunk in data.chunks_exact_mut(BLOCK_LENGTH) {
+            let mut block: [u8; BLOCK_LENGTH] = chunk.try_into().unwrap();
+            xor_into(&mut block, &previous);
+            self.encrypt_block(&mut block);
+            chunk.copy_from_slice(&block);
+            previous = block;
+        }
+        true
+    }
+
+    /// Decrypts the data in place in CBC mode. Returns `false` and leaves the data untouched if its
+    /// length is not a multiple of the block length.
+    pub fn cbc_decrypt(&self, iv: &[u8; BLOCK_LENGTH], data: &mut [u8]) -> bool {
+        if !data.len().is_multiple_of(BLOCK_LENGTH) {
+            return false;
+        }
+
+        let mut previous = *iv;
+        for chunk in data.chunks_exact_mut(BLOCK_LENGTH) {
+            let ciphertext: [u8; BLOCK_LENGTH] = chunk.try_into().unwrap();
+            let mut block = ciphertext;
+            self.decrypt_block(&mut block);
+            xor_into(&mut block, &previous);
+            chunk.copy_from_slice(&block);
+            previous = ciphertext;
+        }
+        true
+    }
+
+    /// Calculates the CMAC (RFC 4493) of the given message.
+    pub fn cmac(&self, message: &[u8]) -> [u8; BLOCK_LENGTH] {
+        // derive the subkeys
+        let mut subkey1 = [0u8; BLOCK_LENGTH];
+        self.encrypt_block(&mut subkey1);
+        double(&mut subkey1);
+        let mut subkey2 = subkey1;
+        double(&mut subkey2);
+
+        // all blocks but the last one
+        let full_blocks = if message.is_empty() { 0 } else { (message.len() - 1) / BLOCK_LENGTH };
+        let mut state = [0u8; BLOCK_LENGTH];
+        for chunk in message[..full_blocks*BLOCK_LENGTH].chunks_exact(BLOCK_LENGTH) {
+            xor_into(&mut state, chunk.try_into().unwrap());
+            self.encrypt_block(&mut state);
+        }
+
+        // the last block is either complete (xor with K1) or padded (xor with K2)
+        let rest = &message[full_blocks*BLOCK_LENGTH..];
+        let mut last = [0u8; BLOCK_LENGTH];
+        last[..rest.len()].copy_from_slice(rest);
+        if rest.len() == BLOCK_LENGTH {
+            xor_into(&mut last, &subkey1);
+        } else {
+            last[rest.len()] = 0b1000_0000;
+            xor_into(&mut last, &subkey2);
+        }
+        xor_into(&mut state, &last);
+        self.encrypt_block(&mut state);
+        state
+    }
+}
+
+
+/// XORs the second block into the first.
+pub(crate) fn xor_into(block: &mut [u8; BLOCK_LENGTH], other: &[u8; BLOCK_LENGTH]) {
+    for (b, o) in block.iter_mut().zip(other.iter()) {
+        *b ^= *o;
+    }
+}
+
+/// Multiplies the block by x in GF(2^128), as required for CMAC subkey derivation.
+fn double(block: &mut [u8; BLOCK_LENGTH]) {
+    let carry = block[0] & 0b1000_0000 != 0;
+    for i in 0..BLOCK_LENGTH {
+        let next_bit = if i + 1 < BLOCK_LENGTH { block[i+1] >> 7 } else { 0 };
+        block[i] = (block[i] << 1) | next_bit;
+    }
+    if carry {
+        block[BLOCK_LENGTH-1] ^= 0x87;
+    }
+}
+
+fn shift_rows(block: &mut [u8; BLOCK_LENGTH]) {
+    // the block is stored column by column; row r is rotated left by r
+    let original = *block;
+    for c in 0..4 {
+        for r in 0..4 {
+            block[4*c + r] = original[4*((c + r) % 4) + r];
+        }
+    }
+}
+
+fn inv_shift_rows(block: &mut [u8; BLOCK_LENGTH]) {
+    let original = *block;
+    for c in 0..4 {
+        for r in 0..4 {
+            block[4*((c + r) % 4) + r] = original[4*c + r];
+        }
+    }
+}
+
+fn mix_columns(block: &mut [u8; BLOCK_LENGTH]) {
+    for column in block.chunks_exact_mut(4) {
+        let a = [column[0], column[1], column[2], column[3]];
+        column[0] = gf_mul(a[0], 2) ^ gf_mul(a[1], 3) ^ a[2] ^ a[3];
+        column[1] = a[0] ^ gf_mul(a[1], 2) ^ gf_mul(a[2], 3) ^ a[3];
+        column[2] = a[0] ^ a[1] ^ gf_mul(a[2], 2) ^ gf_mul(a[3], 3);
+        column[3] = gf_mul(a[0], 3) ^ a[1] ^ a[2] ^ gf_mul(a[3], 2);
+    }
+}
+
+fn inv_mix_columns(block: &mut [u8; BLOCK_LENGTH]) {
+    for column in block.chunks_exact_mut(4) {
+        let a = [column[0], column[1], column[2], column[3]];
+        column[0] = gf_mul(a[0], 14) ^ gf_mul(a[1], 11) ^ gf_mul(a[2], 13) ^ gf_mul(a[3], 9);
+        column[1] = gf_mul(a[0], 9) ^ gf_mul(a[1], 14) ^ gf_mul(a[2], 11) ^ gf_mul(a[3], 13);
+        column[2] = gf_mul(a[0], 13) ^ gf_mul(a[1], 9) ^ gf_mul(a[2], 14) ^ gf_mul(a[3], 11);
+        column[3] = gf_mul(a[0], 11) ^ gf_mul(a[1], 13) ^ gf_mul(a[2], 9) ^ gf_mul(a[3], 14);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Aes128;
+
+    const NIST_KEY: [u8; 16] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+    ];
+
+    #[test]
+    fn test_block() {
+        // FIPS 197, appendix C.1
+        let key = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        ];
+        let plaintext = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+        ];
+        let aes = Aes128::new(&key);
+        let mut block = plaintext;
+        aes.encrypt_block(&mut block);
+        assert_eq!(block, [
+            0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4, 0xc5, 0x5a,
+        ]);
+        aes.decrypt_block(&mut block);
+        assert_eq!(block, plaintext);
+    }
+
+    #[test]
+    fn test_cbc() {
+        // NIST SP 800-38A, F.2.1 (first two blocks)
+        let iv = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        ];
+        let plaintext = [
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+        ];
+        let aes = Aes128::new(&NIST_KEY);
+        let mut data = plaintext;
+        assert!(aes.cbc_encrypt(&iv, &mut data));
+        assert_eq!(data, [
+            0x76, 0x49, 0xab, 0xac, 0x81, 0x19, 0xb2, 0x46, 0xce, 0xe9, 0x8e, 0x9b, 0x12, 0xe9, 0x19, 0x7d,
+            0x50, 0x86, 0xcb, 0x9b, 0x50, 0x72, 0x19, 0xee, 0x95, 0xdb, 0x11, 0x3a, 0x91, 0x76, 0x78, 0xb2,
+        ]);
+        assert!(aes.cbc_decrypt(&iv, &mut data));
+        assert_eq!(data, plaintext);
+
+        let mut odd = [0u8; 15];
+        assert!(!aes.cbc_encrypt(&iv, &mut odd));
+    }
+
+    #[test]
+    fn test_cmac() {
+        // RFC 4493, section 4
+        let aes = Aes128::new(&NIST_KEY);
+        assert_eq!(aes.cmac(&[]), [
+            0xbb, 0x1d, 0x69, 0x29, 0xe9, 0x59, 0x37, 0x28, 0x7f, 0xa3, 0x7d, 0x12, 0x9b, 0x75, 0x67, 0x46,
+        ]);
+        assert_eq!(aes.cmac(&[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+        ]), [
+            0x07, 0x0a, 0x16, 0xb4, 0x6b, 0x4d, 0x41, 0x44, 0xf7, 0x9b, 0xdd, 0x9d, 0xd0, 0x4a, 0x28, 0x7c,
+        ]);
+        assert_eq!(aes.cmac(&[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11,
+        ]), [
+            0xdf, 0xa6, 0x67, 0x47, 0xde, 0x9a, 0xe6, 0x30, 0x30, 0xca, 0x32, 0x61, 0x14, 0x97, 0xc8, 0x27,
+        ]);
+    }
+}
@@ -0,0 +1,292 @@
+//! Allocation of sender IDs from the base ID of an ESP3 device.
+//!
+//! Apart from its own chip ID, an EnOcean module may send telegrams using any of the 128 IDs
+//! starting at its base ID. Using a distinct ID per channel (e.g. per heating zone) allows
+//! actuators to be taught to a single channel each. [`SenderAllocator`] reads the base ID via an
+//! [`Esp3Session`] and hands out the IDs. Since the base ID can only be changed a few times during
+//! the lifetime of a module, it is only ever changed through [`SenderAllocator::change_base_id`].
+
+
+use core::fmt;
+
+use crate::esp3::CommandData;
+use crate::esp3::response_data::CommonCommandResponse;
+use crate::esp3::session::{DecodedResponse, Esp3Session, SessionEvent};
+
+
+/// The number of sender IDs starting at the base ID.
+pub const SENDER_IDS_PER_BASE_ID: u8 = 128;
+
+/// The lowest valid base ID.
+pub const MIN_BASE_ID: u32 = 0xFF80_0000;
+
+/// The highest valid base ID.
+pub const MAX_BASE_ID: u32 = 0xFFFF_FF80;
+
+
+/// The reason why a sender ID could not be handed out or the base ID could not be changed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AllocationError {
+    /// The base ID has not been read from the device yet.
+    BaseIdUnknown,
+
+    /// The channel is not less than [`SENDER_IDS_PER_BASE_ID`].
+    InvalidChannel { channel: u8 },
+
+    /// The channel has already been claimed.
+    ChannelInUse { channel: u8 },
+
+    /// All channels have been claimed.
+    Exhausted,
+
+    /// The base ID is outside the valid range or not a multiple of [`SENDER_IDS_PER_BASE_ID`].
+    InvalidBaseId { base_id: u32 },
+
+    /// The device has reported that the base ID cannot be changed anymore.
+    NoWriteCyclesLeft,
+}
+impl fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BaseIdUnknown
+                => write!(f, "base ID not known yet"),
+            Self::InvalidChannel { channel }
+                => write!(f, "invalid channel {} (at most {} supported)", channel, SENDER_IDS_PER_BASE_ID - 1),
+            Self::ChannelInUse { channel }
+                => write!(f, "channel {} already in use", channel),
+            Self::Exhausted
+                => write!(f, "all channels in use"),
+            Self::InvalidBaseId { base_id }
+                => write!(f, "invalid base ID 0x{:08X}", base_id),
+            Self::NoWriteCyclesLeft
+                => write!(f, "base ID cannot be changed anymore"),
+        }
+    }
+}
+
+
+/// What the allocator is doing with the base ID of the device.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum BaseIdState {
+    NeedsRead,
+    Reading { request_id: u32 },
+    Known,
+    NeedsWrite { base_id: u32 },
+    Writing { request_id: u32 },
+}
+
+
+/// Hands out the sender IDs starting at the base ID of the device.
+///
+/// Each channel, numbered 0 to 127, always corresponds to the same sender ID, as long as the base
+/// ID is not changed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SenderAllocator {
+    state: BaseIdState,
+    opt_base_id: Option<u32>,
+    opt_remaining_write_cycles: Option<u8>,
+    claimed: u128,
+}
+impl SenderAllocator {
+    /// Creates a new allocator that has yet to read the base ID.
+    pub const fn new() -> Self {
+        Self {
+            state: BaseIdState::NeedsRead,
+            opt_base_id: None,
+            opt_remaining_write_cycles: None,
+            claimed: 0,
+        }
+    }
+
+    /// The base ID of the device, if it has been read.
+    pub fn base_id(&self) -> Option<u32> {
+        self.opt_base_id
+    }
+
+    /// The number of times the base ID can still be changed, if the device has reported it.
+    pub fn remaining_write_cycles(&self) -> Option<u8> {
+        self.opt_remaining_write_cycles
+    }
+
+    /// The sender ID of the given channel, regardless of whether it has been claimed.
+    pub fn sender_id(&self, channel: u8) -> Result<u32, AllocationError> {
+        if channel >= SENDER_IDS_PER_BASE_ID {
+            return Err(AllocationError::InvalidChannel { channel });
+        }
+        let base_id = self.opt_base_id.ok_or(AllocationError::BaseIdUnknown)?;
+        Ok(base_id + u32::from(channel))
+    }
+
+    /// Whether the given channel has been claimed.
+    pub fn is_claimed(&self, channel: u8) -> bool {
+        channel < SENDER_IDS_PER_BASE_ID && (self.claimed & (1 << channel)) != 0
+    }
+
+    /// Claims the given channel, returning its sender ID.
+    pub fn claim(&mut self, channel: u8) -> Result<u32, AllocationError> {
+        let sender_id = self.sender_id(channel)?;
+        if self.is_claimed(channel) {
+            return Err(AllocationError::ChannelInUse { channel });
+        }
+        self.claimed |= 1 << channel;
+        Ok(sender_id)
+    }
+
+    /// Claims the lowest unclaimed channel, returning it along with its sender ID.
+    pub fn allocate(&mut self) -> Result<(u8, u32), AllocationError> {
+        if self.opt_base_id.is_none() {
+            return Err(AllocationError::BaseIdUnknown);
+        }
+        let channel = (0..SENDER_IDS_PER_BASE_ID)
+            .find(|c| !self.is_claimed(*c))
+            .ok_or(AllocationError::Exhausted)?;
+        let sender_id = self.claim(channel)?;
+        Ok((channel, sender_id))
+    }
+
+    /// Releases the given channel. Returns whether it had been claimed.
+    pub fn release(&mut self, channel: u8) -> bool {
+        if !self.is_claimed(channel) {
+            return false;
+        }
+        self.claimed &= !(1 << channel);
+        true
+    }
+
+    /// Requests that the base ID of the device be changed.
+    ///
+    /// The device only allows this a few times, and all sender IDs change along with the base ID,
+    /// so actuators taught to them have to be taught again. The base ID is written during the next
+    /// call to [`SenderAllocator::poll`] and read back afterwards.
+    pub fn change_base_id(&mut self, base_id: u32) -> Result<(), AllocationError> {
+        if !(MIN_BASE_ID..=MAX_BASE_ID).contains(&base_id) || (base_id & (u32::from(SENDER_IDS_PER_BASE_ID) - 1)) != 0 {
+            return Err(AllocationError::InvalidBaseId { base_id });
+        }
+        if self.state != BaseIdState::Known {
+            return Err(AllocationError::BaseIdUnknown);
+        }
+        if self.opt_remaining_write_cycles == Some(0) {
+            return Err(AllocationError::NoWriteCyclesLeft);
+        }
+        self.state = BaseIdState::NeedsWrite { base_id };
+        Ok(())
+    }
+
+    /// Enqueues the command required to read or write the base ID into the session, if any.
+    pub fn poll<const QUEUE: usize>(&mut self, session: &mut Esp3Session<QUEUE>) {
+        match self.state {
+            BaseIdState::NeedsRead => {
+                if let Ok(request_id) = session.enqueue(CommandData::CoRdIdBase) {
+                    self.state = BaseIdState::Reading { request_id };
+                }
+            },
+            BaseIdState::NeedsWrite { base_id } => {
+                if let Ok(request_id) = session.enqueue(CommandData::CoWrIdBase { base_id }) {
+                    self.state = BaseIdState::Writing { request_id };
+                }
+            },
+            BaseIdState::Reading { .. } | BaseIdState::Known | BaseIdState::Writing { .. } => {},
+        }
+    }
+
+    /// Processes the outcome of a request made through the session.
+    ///
+    /// Outcomes of requests that have not been made by this allocator are ignored. Failed reads
+    /// are retried; failed writes are not.
+    pub fn handle_session_event(&mut self, event: &SessionEvent<'_>) {
+        match self.state {
+            BaseIdState::Reading { request_id } if request_id == event.request_id => {
+                if let Ok(DecodedResponse::Common(CommonCommandResponse::RdIdBase(response))) = &event.result {
+                    self.opt_base_id = Some(response.base_id);
+                    self.opt_remaining_write_cycles = response.opt_remaining_write_cycles;
+                    self.state = BaseIdState::Known;
+                } else {
+                    self.state = BaseIdState::NeedsRead;
+                }
+            },
+            BaseIdState::Writing { request_id } if request_id == event.request_id => {
+                // read back the base ID and the remaining write cycles in any case
+                self.state = BaseIdState::NeedsRead;
+            },
+            _ => {},
+        }
+    }
+}
+impl Default for SenderAllocator {
+    fn default() -> Self { Self::new() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{AllocationError, SenderAllocator};
+    use crate::esp3::{CommandData, Esp3Packet, ReturnCode};
+    use crate::esp3::session::Esp3Session;
+    use crate::max_array::MaxArray;
+
+    fn read_base_id(allocator: &mut SenderAllocator, session: &mut Esp3Session<2>, remaining_write_cycles: u8) {
+        allocator.poll(session);
+        assert_eq!(session.poll_transmit(0), Some(Esp3Packet::CommonCommand(CommandData::CoRdIdBase)));
+        let response = Esp3Packet::Response {
+            return_code: ReturnCode::Ok,
+            response_data: MaxArray::from_iter_or_panic([0xFF, 0x9A, 0x00, 0x80].into_iter().peekable()),
+            optional_data: MaxArray::from_iter_or_panic([remaining_write_cycles].into_iter().peekable()),
+        };
+        let event = session.handle_packet(&response, 0).unwrap();
+        allocator.handle_session_event(&event);
+    }
+
+    #[test]
+    fn test_allocate() {
+        let mut allocator = SenderAllocator::new();
+        let mut session: Esp3Session<2> = Esp3Session::new(500);
+        assert_eq!(allocator.allocate(), Err(AllocationError::BaseIdUnknown));
+
+        read_base_id(&mut allocator, &mut session, 10);
+        assert_eq!(allocator.base_id(), Some(0xFF9A0080));
+        assert_eq!(allocator.remaining_write_cycles(), Some(10));
+
+        assert_eq!(allocator.claim(2), Ok(0xFF9A0082));
+        assert_eq!(allocator.claim(2), Err(AllocationError::ChannelInUse { channel: 2 }));
+        assert_eq!(allocator.claim(128), Err(AllocationError::InvalidChannel { channel: 128 }));
+        assert_eq!(allocator.allocate(), Ok((0, 0xFF9A0080)));
+        assert_eq!(allocator.allocate(), Ok((1, 0xFF9A0081)));
+        assert_eq!(allocator.allocate(), Ok((3, 0xFF9A0083)));
+        assert!(allocator.release(1));
+        assert!(!allocator.release(1));
+        assert_eq!(allocator.allocate(), Ok((1, 0xFF9A0081)));
+
+        // nothing is ever written unless asked for
+        allocator.poll(&mut session);
+        assert_eq!(session.poll_transmit(0), None);
+    }
+
+    #[test]
+    fn test_change_base_id() {
+        let mut allocator = SenderAllocator::new();
+        let mut session: Esp3Session<2> = Esp3Session::new(500);
+        assert_eq!(allocator.change_base_id(0xFF800000), Err(AllocationError::BaseIdUnknown));
+
+        read_base_id(&mut allocator, &mut session, 1);
+        assert_eq!(allocator.change_base_id(0xFF800001), Err(AllocationError::InvalidBaseId { base_id: 0xFF800001 }));
+        assert_eq!(allocator.change_base_id(0x01020300), Err(AllocationError::InvalidBaseId { base_id: 0x01020300 }));
+        assert_eq!(allocator.change_base_id(0xFF800100), Ok(()));
+
+        allocator.poll(&mut session);
+        assert_eq!(
+            session.poll_transmit(0),
+            Some(Esp3Packet::CommonCommand(CommandData::CoWrIdBase { base_id: 0xFF800100 })),
+        );
+        let ok = Esp3Packet::Response {
+            return_code: ReturnCode::Ok,
+            response_data: MaxArray::new(),
+            optional_data: MaxArray::new(),
+        };
+        let event = session.handle_packet(&ok, 0).unwrap();
+        allocator.handle_session_event(&event);
+
+        // the device reports no remaining write cycles
+        read_base_id(&mut allocator, &mut session, 0);
+        assert_eq!(allocator.change_base_id(0xFF800200), Err(AllocationError::NoWriteCyclesLeft));
+    }
+}
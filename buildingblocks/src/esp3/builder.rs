@@ -0,0 +1,346 @@
+//! Construction of radio packets to be sent by the controller.
+//!
+//! The optional data of an ESP3 packet is positional: a field can only be given if all the fields
+//! preceding it are given as well. The builders in this module fill in the values that the ESP3
+//! specification defines for the send case whenever a later field is requested, so that e.g. an
+//! addressed telegram can be sent without knowing which placeholder bytes have to precede the
+//! destination ID.
+
+
+use core::fmt;
+
+use crate::esp3::{Esp3Packet, MAX_ESP3_PACKET_LENGTH, OneByteBoolean, SecurityLevel};
+use crate::esp3::erp::ErpData;
+use crate::max_array::MaxArray;
+
+
+/// The destination ID with which a telegram is sent to all devices.
+pub const BROADCAST_ID: u32 = 0xFFFF_FFFF;
+
+/// The subtelegram number to use when sending a telegram.
+pub const SEND_SUB_TELEGRAM_NUMBER: u8 = 3;
+
+/// The signal strength value to use when sending a telegram.
+pub const SEND_DBM: u8 = 0xFF;
+
+/// The source ID to use when sending a telegram; the device replaces it with its own ID.
+pub const SEND_SOURCE_ID: u32 = 0x0000_0000;
+
+
+/// The reason why a packet could not be built.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BuildError {
+    /// The data does not fit into the packet.
+    DataTooLong { length: usize, maximum: usize },
+
+    /// The security level is not defined by the ESP3 specification.
+    InvalidSecurityLevel { value: u8 },
+}
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataTooLong { length, maximum }
+                => write!(f, "data too long ({} bytes, at most {} supported)", length, maximum),
+            Self::InvalidSecurityLevel { value }
+                => write!(f, "invalid security level 0x{:02X}", value),
+        }
+    }
+}
+
+
+/// Verifies that the security level can be sent.
+fn check_security_level(opt_security_level: Option<SecurityLevel>) -> Result<(), BuildError> {
+    match opt_security_level {
+        Some(SecurityLevel::Other(value)) => Err(BuildError::InvalidSecurityLevel { value }),
+        _ => Ok(()),
+    }
+}
+
+/// Verifies that the data fits into an array of the given size and copies it.
+fn copy_data<const MAX_SIZE: usize>(data: &[u8]) -> Result<MaxArray<u8, MAX_SIZE>, BuildError> {
+    let mut ret = MaxArray::new();
+    if !ret.fill_from(data.iter().copied().peekable()) {
+        return Err(BuildError::DataTooLong {
+            length: data.len(),
+            maximum: MAX_SIZE,
+        });
+    }
+    Ok(ret)
+}
+
+/// Encodes the packet, including the header, the CRCs and the sync byte.
+fn frame(packet: &Esp3Packet) -> MaxArray<u8, MAX_ESP3_PACKET_LENGTH> {
+    // the builders only produce packets with consistent optional data
+    packet.to_packet()
+        .expect("builder produced inconsistent optional data")
+}
+
+
+/// Builds an [`Esp3Packet::RadioErp1`] packet that sends an ERP1 telegram.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RadioErp1Builder {
+    telegram: ErpData,
+    opt_destination_id: Option<u32>,
+    opt_security_level: Option<SecurityLevel>,
+}
+impl RadioErp1Builder {
+    /// Starts building a packet that broadcasts the given telegram.
+    pub fn new(telegram: ErpData) -> Self {
+        Self {
+            telegram,
+            opt_destination_id: None,
+            opt_security_level: None,
+        }
+    }
+
+    /// Addresses the telegram to the device with the given ID.
+    pub fn destination_id(mut self, destination_id: u32) -> Self {
+        self.opt_destination_id = Some(destination_id);
+        self
+    }
+
+    /// Sets the security level with which the telegram is sent.
+    pub fn security_level(mut self, security_level: SecurityLevel) -> Self {
+        self.opt_security_level = Some(security_level);
+        self
+    }
+
+    /// Builds the packet.
+    pub fn build(&self) -> Result<Esp3Packet, BuildError> {
+        check_security_level(self.opt_security_level)?;
+
+        let needs_destination = self.opt_destination_id.is_some() || self.opt_security_level.is_some();
+        let opt_destination_id = needs_destination
+            .then(|| self.opt_destination_id.unwrap_or(BROADCAST_ID));
+        Ok(Esp3Packet::RadioErp1 {
+            radio_telegram: self.telegram.to_bytes(),
+            opt_sub_telegram_number: needs_destination.then_some(SEND_SUB_TELEGRAM_NUMBER),
+            opt_destination_id,
+            opt_dbm: self.opt_security_level.is_some().then_some(SEND_DBM),
+            opt_security_level: self.opt_security_level,
+        })
+    }
+
+    /// Builds the packet and encodes it into bytes ready to be sent.
+    pub fn to_bytes(&self) -> Result<MaxArray<u8, MAX_ESP3_PACKET_LENGTH>, BuildError> {
+        self.build().map(|packet| frame(&packet))
+    }
+}
+
+
+/// Builds an [`Esp3Packet::RadioMessage`] packet that sends a message which the device wraps into
+/// telegrams of the appropriate radio protocol.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RadioMessageBuilder<'a> {
+    rorg: u8,
+    data: &'a [u8],
+    opt_destination_id: Option<u32>,
+    opt_source_id: Option<u32>,
+    opt_security_level: Option<SecurityLevel>,
+}
+impl<'a> RadioMessageBuilder<'a> {
+    /// Starts building a packet that broadcasts a message with the given RORG and data.
+    pub fn new(rorg: u8, data: &'a [u8]) -> Self {
+        Self {
+            rorg,
+            data,
+            opt_destination_id: None,
+            opt_source_id: None,
+            opt_security_level: None,
+        }
+    }
+
+    /// Addresses the message to the device with the given ID.
+    pub fn destination_id(mut self, destination_id: u32) -> Self {
+        self.opt_destination_id = Some(destination_id);
+        self
+    }
+
+    /// Sends the message with the given ID instead of the device's own ID.
+    pub fn source_id(mut self, source_id: u32) -> Self {
+        self.opt_source_id = Some(source_id);
+        self
+    }
+
+    /// Sets the security level with which the message is sent.
+    pub fn security_level(mut self, security_level: SecurityLevel) -> Self {
+        self.opt_security_level = Some(security_level);
+        self
+    }
+
+    /// Builds the packet.
+    pub fn build(&self) -> Result<Esp3Packet, BuildError> {
+        check_security_level(self.opt_security_level)?;
+
+        let needs_source = self.opt_source_id.is_some() || self.opt_security_level.is_some();
+        let needs_destination = self.opt_destination_id.is_some() || needs_source;
+        Ok(Esp3Packet::RadioMessage {
+            rorg: self.rorg,
+            data: copy_data(self.data)?,
+            opt_destination_id: needs_destination
+                .then(|| self.opt_destination_id.unwrap_or(BROADCAST_ID)),
+            opt_source_id: needs_source
+                .then(|| self.opt_source_id.unwrap_or(SEND_SOURCE_ID)),
+            opt_dbm: self.opt_security_level.is_some().then_some(SEND_DBM),
+            opt_security_level: self.opt_security_level,
+        })
+    }
+
+    /// Builds the packet and encodes it into bytes ready to be sent.
+    pub fn to_bytes(&self) -> Result<MaxArray<u8, MAX_ESP3_PACKET_LENGTH>, BuildError> {
+        self.build().map(|packet| frame(&packet))
+    }
+}
+
+
+/// Builds an [`Esp3Packet::RemoteManCommand`] packet that sends a remote management command.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RemoteManCommandBuilder<'a> {
+    function: u16,
+    manufacturer: u16,
+    message: &'a [u8],
+    opt_destination_id: Option<u32>,
+    send_with_delay: bool,
+}
+impl<'a> RemoteManCommandBuilder<'a> {
+    /// Starts building a packet that broadcasts the given remote management command.
+    pub fn new(function: u16, manufacturer: u16, message: &'a [u8]) -> Self {
+        Self {
+            function,
+            manufacturer,
+            message,
+            opt_destination_id: None,
+            send_with_delay: false,
+        }
+    }
+
+    /// Addresses the command to the device with the given ID.
+    pub fn destination_id(mut self, destination_id: u32) -> Self {
+        self.opt_destination_id = Some(destination_id);
+        self
+    }
+
+    /// Sends the command after a random delay.
+    ///
+    /// The specification requires this when answering a broadcast command, so that the answers of
+    /// multiple devices do not collide.
+    pub fn send_with_delay(mut self, send_with_delay: bool) -> Self {
+        self.send_with_delay = send_with_delay;
+        self
+    }
+
+    /// Builds the packet.
+    pub fn build(&self) -> Result<Esp3Packet, BuildError> {
+        let needs_destination = self.opt_destination_id.is_some() || self.send_with_delay;
+        Ok(Esp3Packet::RemoteManCommand {
+            function: self.function,
+            manufacturer: self.manufacturer,
+            message: copy_data(self.message)?,
+            opt_destination_id: needs_destination
+                .then(|| self.opt_destination_id.unwrap_or(BROADCAST_ID)),
+            opt_source_id: self.send_with_delay.then_some(SEND_SOURCE_ID),
+            opt_dbm: self.send_with_delay.then_some(SEND_DBM),
+            opt_send_with_delay: self.send_with_delay.then_some(OneByteBoolean::Yes),
+        })
+    }
+
+    /// Builds the packet and encodes it into bytes ready to be sent.
+    pub fn to_bytes(&self) -> Result<MaxArray<u8, MAX_ESP3_PACKET_LENGTH>, BuildError> {
+        self.build().map(|packet| frame(&packet))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BROADCAST_ID, BuildError, RadioErp1Builder, RadioMessageBuilder, RemoteManCommandBuilder,
+    };
+    use crate::esp3::{Esp3Packet, MAX_DATA_LENGTH, SecurityLevel};
+    use crate::esp3::erp::{ErpData, RepeatedSwitchTelegram};
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_radio_erp1() {
+        let telegram = ErpData::RepeatedSwitch(RepeatedSwitchTelegram {
+            data: 0x50,
+            sender: 0xFEF61A2B,
+            status: 0x30,
+        });
+
+        let broadcast = RadioErp1Builder::new(telegram.clone()).to_bytes().unwrap();
+        assert_eq!(
+            broadcast.as_slice(),
+            &[0x55, 0x00, 0x07, 0x00, 0x01, 0x11, 0xF6, 0x50, 0xFE, 0xF6, 0x1A, 0x2B, 0x30, 0x39],
+        );
+
+        let addressed = RadioErp1Builder::new(telegram.clone())
+            .destination_id(0x01020304)
+            .to_bytes().unwrap();
+        assert_eq!(
+            Esp3Packet::from_slice(addressed.as_slice()),
+            Some(Esp3Packet::RadioErp1 {
+                radio_telegram: telegram.to_bytes(),
+                opt_sub_telegram_number: Some(3),
+                opt_destination_id: Some(0x01020304),
+                opt_dbm: None,
+                opt_security_level: None,
+            }),
+        );
+
+        // the security level requires all preceding fields
+        let secure = RadioErp1Builder::new(telegram.clone())
+            .security_level(SecurityLevel::NoSecurity)
+            .build().unwrap();
+        assert_eq!(
+            secure,
+            Esp3Packet::RadioErp1 {
+                radio_telegram: telegram.to_bytes(),
+                opt_sub_telegram_number: Some(3),
+                opt_destination_id: Some(BROADCAST_ID),
+                opt_dbm: Some(0xFF),
+                opt_security_level: Some(SecurityLevel::NoSecurity),
+            },
+        );
+        assert!(secure.to_packet().is_some());
+
+        assert_eq!(
+            RadioErp1Builder::new(telegram)
+                .security_level(SecurityLevel::Other(0x42))
+                .build(),
+            Err(BuildError::InvalidSecurityLevel { value: 0x42 }),
+        );
+    }
+
+    #[test]
+    fn test_radio_message_and_remote_man() {
+        let message = RadioMessageBuilder::new(0xD2, &[0x01, 0x02])
+            .source_id(0xFF800001)
+            .build().unwrap();
+        assert_eq!(message.to_packet_optional().unwrap().as_slice(), &[
+            0xFF, 0xFF, 0xFF, 0xFF,
+            0xFF, 0x80, 0x00, 0x01,
+        ]);
+
+        let command = RemoteManCommandBuilder::new(0x004, 0x7FF, &[])
+            .send_with_delay(true)
+            .build().unwrap();
+        assert_eq!(command.to_packet_optional().unwrap().as_slice(), &[
+            0xFF, 0xFF, 0xFF, 0xFF,
+            0x00, 0x00, 0x00, 0x00,
+            0xFF,
+            0x01,
+        ]);
+
+        let command = RemoteManCommandBuilder::new(0x004, 0x7FF, &[])
+            .destination_id(0x01020304)
+            .build().unwrap();
+        assert_eq!(command.to_packet_optional().unwrap().as_slice(), &[0x01, 0x02, 0x03, 0x04]);
+
+        let too_long = vec![0u8; MAX_DATA_LENGTH];
+        assert_eq!(
+            RadioMessageBuilder::new(0xD2, &too_long).build(),
+            Err(BuildError::DataTooLong { length: MAX_DATA_LENGTH, maximum: MAX_DATA_LENGTH - 1 }),
+        );
+    }
+}
@@ -0,0 +1,541 @@
+//! Incremental decoding of ESP3 packets from a byte stream.
+//!
+//! Incoming bytes are collected in a [RingBuffer], which an [Esp3Decoder] examines in place;
+//! complete frames are returned as soon as their last byte has arrived. Corrupted or oversized
+//! frames are skipped and accounted for in [DecoderCounters].
+
+
+use crate::crc8::crc8_ccitt;
+use crate::esp3::{Esp3PacketRef, FOOTER_LENGTH, HEADER_LENGTH, MAX_DATA_LENGTH, SYNC_BYTE};
+use crate::esp3::error::{ParseError, ParseErrorKind};
+use crate::ring_buffer::RingBuffer;
+
+
+/// Counters describing what the decoder has encountered in the byte stream so far.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DecoderCounters {
+    /// The number of packets that have been decoded successfully.
+    pub packets: u32,
+
+    /// The number of times the decoder had to discard bytes to find the next sync byte.
+    pub resyncs: u32,
+
+    /// The number of frames whose header CRC8 did not match.
+    pub header_crc_failures: u32,
+
+    /// The number of frames whose data CRC8 did not match.
+    pub data_crc_failures: u32,
+
+    /// The number of frames that were too long for the ring buffer and have been skipped.
+    pub oversized_frames: u32,
+
+    /// The number of frames that were abandoned before they were complete.
+    pub truncated_frames: u32,
+
+    /// The number of frames with valid checksums whose contents could not be decoded.
+    pub undecodable_frames: u32,
+}
+
+
+/// The current state of the decoder.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum DecoderState {
+    /// Waiting for a sync byte.
+    Hunting,
+
+    /// Waiting for the header of the frame starting at the front of the ring buffer.
+    Header,
+
+    /// Waiting for the data, optional data and data CRC8 of the frame starting at the front of the
+    /// ring buffer.
+    Body { total_length: usize },
+
+    /// Discarding the remainder of a frame that does not fit into the ring buffer.
+    Skipping { remaining: usize },
+}
+
+
+/// A decoder that extracts ESP3 packets from a stream of bytes collected in a [RingBuffer].
+///
+/// The decoder does not copy the bytes out of the ring buffer; a frame is only removed from the
+/// ring buffer once it has been rejected or once the decoder is polled again after returning it.
+#[derive(Clone, Debug)]
+pub struct Esp3Decoder {
+    state: DecoderState,
+    in_garbage: bool,
+    complete_length: usize,
+    counters: DecoderCounters,
+    last_error: Option<ParseError>,
+}
+impl Esp3Decoder {
+    /// Creates a new decoder that is waiting for a sync byte.
+    pub const fn new() -> Self {
+        Self {
+            state: DecoderState::Hunting,
+            in_garbage: false,
+            complete_length: 0,
+            counters: DecoderCounters {
+                packets: 0,
+                resyncs: 0,
+                header_crc_failures: 0,
+                data_crc_failures: 0,
+                oversized_frames: 0,
+                truncated_frames: 0,
+                undecodable_frames: 0,
+            },
+            last_error: None,
+        }
+    }
+
+    /// The counters collected by this decoder.
+    #[inline]
+    pub fn counters(&self) -> &DecoderCounters {
+        &self.counters
+    }
+
+    /// Resets all the counters collected by this decoder to zero.
+    pub fn reset_counters(&mut self) {
+        self.counters = DecoderCounters::default();
+    }
+
+    /// Returns the reason why the most recent corrupted or undecodable frame was rejected, and
+    /// forgets it.
+    ///
+    /// Offsets are relative to the start of the frame.
+    pub fn take_error(&mut self) -> Option<ParseError> {
+        self.last_error.take()
+    }
+
+    /// Whether the decoder is currently in the middle of a frame.
+    pub fn is_mid_frame(&self) -> bool {
+        self.state != DecoderState::Hunting
+    }
+
+    /// Examines the bytes collected in the ring buffer. Returns the next frame with valid
+    /// checksums, borrowed from the ring buffer, once all of its bytes have arrived.
+    ///
+    /// The frame remains at the front of the ring buffer until the next call to this function,
+    /// [Esp3Decoder::poll_packet] or [Esp3Decoder::abandon_frame]. The ring buffer must not be
+    /// modified otherwise in the meantime, except by pushing new bytes. Frames longer than the
+    /// capacity of the ring buffer are skipped.
+    pub fn poll_frame<'r, const SIZE: usize>(&mut self, ring: &'r mut RingBuffer<u8, SIZE>) -> Option<&'r [u8]> {
+        self.discard_complete_frame(ring);
+
+        loop {
+            match self.state {
+                DecoderState::Hunting => {
+                    if ring.peek_at(0)? == SYNC_BYTE {
+                        self.in_garbage = false;
+                        self.state = DecoderState::Header;
+                    } else {
+                        ring.pop();
+                        if !self.in_garbage {
+                            // count each run of garbage only once
+                            self.in_garbage = true;
+                            self.counters.resyncs = self.counters.resyncs.saturating_add(1);
+                        }
+                    }
+                },
+                DecoderState::Header => {
+                    let mut header = [0u8; HEADER_LENGTH];
+                    if !ring.peek_fill(&mut header) {
+                        return None;
+                    }
+                    if !self.check_header(&header, SIZE) {
+                        self.resync(ring);
+                    }
+                },
+                DecoderState::Body { total_length } => {
+                    if ring.len() < total_length {
+                        return None;
+                    }
+
+                    let (crc_data, received) = {
+                        let frame = &ring.make_contiguous()[..total_length];
+                        (crc8_ccitt(&frame[HEADER_LENGTH..total_length-1]), frame[total_length-1])
+                    };
+                    self.state = DecoderState::Hunting;
+                    if crc_data == received {
+                        self.complete_length = total_length;
+                        return Some(&ring.make_contiguous()[..total_length]);
+                    }
+
+                    // the sync byte might have been a false positive; rescan from the next byte
+                    self.counters.data_crc_failures = self.counters.data_crc_failures.saturating_add(1);
+                    self.last_error = Some(ParseError::new(total_length-1, ParseErrorKind::DataCrcMismatch {
+                        calculated: crc_data,
+                        received,
+                    }));
+                    self.resync(ring);
+                },
+                DecoderState::Skipping { remaining } => {
+                    let discarded = ring.discard(remaining);
+                    if discarded < remaining {
+                        self.state = DecoderState::Skipping { remaining: remaining - discarded };
+                        return None;
+                    }
+                    self.state = DecoderState::Hunting;
+                },
+            }
+        }
+    }
+
+    /// Examines the bytes collected in the ring buffer. Returns the next frame with valid checksums,
+    /// decoded in place, once all of its bytes have arrived.
+    ///
+    /// Also returns `None` if the frame cannot be decoded; the reason can be obtained using
+    /// [Esp3Decoder::take_error] and the following frames by polling again. The same rules as for
+    /// [Esp3Decoder::poll_frame] apply to the ring buffer.
+    pub fn poll_packet<'r, const SIZE: usize>(&mut self, ring: &'r mut RingBuffer<u8, SIZE>) -> Option<Esp3PacketRef<'r>> {
+        let frame = self.poll_frame(ring)?;
+        self.decode(frame)
+    }
+
+    /// Decodes a frame returned by [Esp3Decoder::poll_frame] in place. Updates the counters and,
+    /// if the frame cannot be decoded, the last error accordingly.
+    pub fn decode<'f>(&mut self, frame: &'f [u8]) -> Option<Esp3PacketRef<'f>> {
+        match Esp3PacketRef::parse(frame) {
+            Ok(packet) => {
+                self.counters.packets = self.counters.packets.saturating_add(1);
+                Some(packet)
+            },
+            Err(error) => {
+                self.counters.undecodable_frames = self.counters.undecodable_frames.saturating_add(1);
+                self.last_error = Some(error);
+                None
+            },
+        }
+    }
+
+    /// Discards a partially received frame along with all the bytes collected in the ring buffer,
+    /// e.g. because no further bytes have arrived within the 100 ms mandated by the ESP3
+    /// specification. Returns whether a partial frame was discarded.
+    pub fn abandon_frame<const SIZE: usize>(&mut self, ring: &mut RingBuffer<u8, SIZE>) -> bool {
+        self.discard_complete_frame(ring);
+        match self.state {
+            DecoderState::Hunting => false,
+            DecoderState::Skipping { remaining } => {
+                // already accounted for as an oversized frame
+                ring.discard(remaining);
+                self.state = DecoderState::Hunting;
+                false
+            },
+            DecoderState::Header|DecoderState::Body { .. } => {
+                self.counters.truncated_frames = self.counters.truncated_frames.saturating_add(1);
+                ring.discard(ring.len());
+                self.state = DecoderState::Hunting;
+                true
+            },
+        }
+    }
+
+    fn discard_complete_frame<const SIZE: usize>(&mut self, ring: &mut RingBuffer<u8, SIZE>) {
+        ring.discard(self.complete_length);
+        self.complete_length = 0;
+    }
+
+    /// Checks the header at the front of the ring buffer and decides how to continue. Returns
+    /// `false` if the header is invalid.
+    fn check_header(&mut self, header: &[u8; HEADER_LENGTH], capacity: usize) -> bool {
+        let crc_header = crc8_ccitt(&header[1..HEADER_LENGTH-1]);
+        if crc_header != header[HEADER_LENGTH-1] {
+            self.counters.header_crc_failures = self.counters.header_crc_failures.saturating_add(1);
+            self.last_error = Some(ParseError::new(HEADER_LENGTH-1, ParseErrorKind::HeaderCrcMismatch {
+                calculated: crc_header,
+                received: header[HEADER_LENGTH-1],
+            }));
+            return false;
+        }
+
+        let data_length = usize::from(u16::from_be_bytes(header[1..3].try_into().unwrap()));
+        let opt_length = usize::from(header[3]);
+        let total_length = HEADER_LENGTH + data_length + opt_length + FOOTER_LENGTH;
+        let maximum = MAX_DATA_LENGTH
+            .min(capacity.saturating_sub(HEADER_LENGTH + opt_length + FOOTER_LENGTH));
+        if data_length > maximum {
+            // skip the rest of the frame instead of hunting through its contents
+            self.counters.oversized_frames = self.counters.oversized_frames.saturating_add(1);
+            self.last_error = Some(ParseError::new(1, ParseErrorKind::DataTooLong {
+                length: data_length,
+                maximum,
+            }));
+            self.state = DecoderState::Skipping { remaining: total_length };
+        } else {
+            self.state = DecoderState::Body { total_length };
+        }
+        true
+    }
+
+    fn resync<const SIZE: usize>(&mut self, ring: &mut RingBuffer<u8, SIZE>) {
+        // the sync byte was a false positive; another one might be hiding in the rest of the frame
+        ring.pop();
+        self.state = DecoderState::Hunting;
+        self.in_garbage = true;
+        self.counters.resyncs = self.counters.resyncs.saturating_add(1);
+    }
+}
+impl Default for Esp3Decoder {
+    fn default() -> Self { Self::new() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Esp3Decoder;
+    use crate::esp3::{CommandData, Esp3Packet, Esp3PacketRef, MAX_ESP3_PACKET_LENGTH};
+    use crate::esp3::error::ParseErrorKind;
+    use crate::ring_buffer::RingBuffer;
+
+    type Esp3Buffer = RingBuffer<u8, MAX_ESP3_PACKET_LENGTH>;
+
+    fn version_packet() -> Vec<u8> {
+        Esp3Packet::CommonCommand(CommandData::CoRdVersion)
+            .to_packet().unwrap()
+            .as_slice().to_vec()
+    }
+
+    fn reset_packet() -> Vec<u8> {
+        Esp3Packet::CommonCommand(CommandData::CoWrReset)
+            .to_packet().unwrap()
+            .as_slice().to_vec()
+    }
+
+    fn push_all<const SIZE: usize>(ring: &mut RingBuffer<u8, SIZE>, bytes: &[u8]) {
+        for b in bytes {
+            assert!(ring.push(*b));
+        }
+    }
+
+    /// Polls the decoder until no further frames are available. Returns the frames of the packets
+    /// that were decoded.
+    fn poll_all<const SIZE: usize>(decoder: &mut Esp3Decoder, ring: &mut RingBuffer<u8, SIZE>) -> Vec<Vec<u8>> {
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.poll_frame(ring) {
+            if decoder.decode(frame).is_some() {
+                frames.push(frame.to_vec());
+            }
+        }
+        frames
+    }
+
+    /// Pushes the bytes into the ring buffer one at a time, polling the decoder after each one.
+    /// Returns the frames of the packets that were decoded.
+    fn decode_stream<const SIZE: usize>(decoder: &mut Esp3Decoder, ring: &mut RingBuffer<u8, SIZE>, bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut frames = Vec::new();
+        for b in bytes {
+            assert!(ring.push(*b));
+            frames.extend(poll_all(decoder, ring));
+        }
+        frames
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_single_packet() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+        let packet = version_packet();
+        let (last, rest) = packet.split_last().unwrap();
+        for b in rest {
+            assert!(ring.push(*b));
+            assert_eq!(decoder.poll_frame(&mut ring), None);
+        }
+        assert!(ring.push(*last));
+        assert_eq!(decoder.poll_frame(&mut ring), Some(packet.as_slice()));
+        assert_eq!(decoder.counters().packets, 0);
+
+        // the frame is only removed from the buffer when polling again
+        assert_eq!(ring.len(), packet.len());
+        assert_eq!(decoder.poll_frame(&mut ring), None);
+        assert_eq!(ring.len(), 0);
+
+        push_all(&mut ring, &packet);
+        let decoded = decoder.poll_packet(&mut ring).unwrap();
+        assert_eq!(decoded, Esp3PacketRef::CommonCommand { command_code: 0x03, data: &[], optional_data: &[] });
+        assert_eq!(decoded.into_owned(), Some(Esp3Packet::CommonCommand(CommandData::CoRdVersion)));
+        assert_eq!(decoder.counters().packets, 1);
+        assert_eq!(decoder.counters().resyncs, 0);
+    }
+
+    #[test]
+    fn test_chunks_with_garbage() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+        let mut stream = vec![0x00, 0x12, 0x34];
+        stream.extend_from_slice(&version_packet());
+        stream.extend_from_slice(&[0xAB, 0xCD]);
+        stream.extend_from_slice(&reset_packet());
+
+        let (first_chunk, second_chunk) = stream.split_at(7);
+        push_all(&mut ring, first_chunk);
+        let mut frames = poll_all(&mut decoder, &mut ring);
+        assert_eq!(frames.len(), 0);
+        push_all(&mut ring, second_chunk);
+        frames.extend(poll_all(&mut decoder, &mut ring));
+        assert_eq!(frames, vec![version_packet(), reset_packet()]);
+        assert_eq!(decoder.counters().packets, 2);
+        assert_eq!(decoder.counters().resyncs, 2);
+        assert_eq!(ring.len(), 0);
+    }
+
+    #[test]
+    fn test_header_crc_resync() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+
+        // a sync byte followed by a broken header which hides the start of a real packet
+        let mut stream = vec![0x55, 0x00];
+        stream.extend_from_slice(&version_packet());
+
+        let frames = decode_stream(&mut decoder, &mut ring, &stream);
+        assert_eq!(frames, vec![version_packet()]);
+        assert_eq!(decoder.counters().header_crc_failures, 1);
+        assert_eq!(decoder.counters().resyncs, 1);
+    }
+
+    #[test]
+    fn test_data_crc_failure() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+        let mut broken = version_packet();
+        let last_index = broken.len() - 1;
+        broken[last_index] ^= 0xFF;
+
+        let mut stream = broken.clone();
+        stream.extend_from_slice(&reset_packet());
+
+        let frames = decode_stream(&mut decoder, &mut ring, &stream);
+        assert_eq!(frames, vec![reset_packet()]);
+        assert_eq!(decoder.counters().data_crc_failures, 1);
+        assert_eq!(decoder.counters().packets, 1);
+        assert_eq!(
+            decoder.take_error().map(|e| (e.offset, e.kind)),
+            Some((last_index, ParseErrorKind::DataCrcMismatch {
+                calculated: broken[last_index] ^ 0xFF,
+                received: broken[last_index],
+            })),
+        );
+        assert_eq!(decoder.take_error(), None);
+    }
+
+    #[test]
+    fn test_data_crc_resync() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+
+        // a false sync byte whose valid-looking header claims the real packet as its data
+        let reset = reset_packet();
+        let data_length = u16::try_from(reset.len() + 2).unwrap();
+        let mut stream = vec![0x55];
+        stream.extend_from_slice(&data_length.to_be_bytes());
+        stream.push(0x00); // optional length
+        stream.push(0x05); // packet type
+        let crc_header = crate::crc8::crc8_ccitt(&stream[1..5]);
+        stream.push(crc_header);
+        stream.extend_from_slice(&reset);
+        stream.extend_from_slice(&[0x00, 0x00]);
+        let crc_data = crate::crc8::crc8_ccitt(&stream[6..]);
+        stream.push(crc_data ^ 0xFF);
+
+        let frames = decode_stream(&mut decoder, &mut ring, &stream);
+        assert_eq!(frames, vec![reset]);
+        assert_eq!(decoder.counters().data_crc_failures, 1);
+        assert_eq!(decoder.counters().packets, 1);
+        assert_eq!(ring.len(), 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "full_esp3_packet"))]
+    fn test_oversized_frame_skipped() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+
+        let data_length = u16::try_from(crate::esp3::MAX_DATA_LENGTH + 1).unwrap();
+        let mut stream = vec![0x55];
+        stream.extend_from_slice(&data_length.to_be_bytes());
+        stream.push(0x00); // optional length
+        stream.push(0x01); // packet type
+        let crc_header = crate::crc8::crc8_ccitt(&stream[1..5]);
+        stream.push(crc_header);
+        // data full of false sync bytes, then the data CRC
+        stream.extend(core::iter::repeat_n(0x55, usize::from(data_length) + 1));
+        stream.extend_from_slice(&reset_packet());
+
+        let frames = decode_stream(&mut decoder, &mut ring, &stream);
+        assert_eq!(frames, vec![reset_packet()]);
+        assert_eq!(decoder.counters().oversized_frames, 1);
+        assert_eq!(decoder.counters().header_crc_failures, 0);
+    }
+
+    #[test]
+    fn test_frame_exceeding_buffer() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: RingBuffer<u8, 32> = RingBuffer::new();
+
+        let mut stream = vec![0x55, 0x00, 30, 0x00, 0x01];
+        let crc_header = crate::crc8::crc8_ccitt(&stream[1..5]);
+        stream.push(crc_header);
+        stream.extend(core::iter::repeat_n(0x55, 31));
+        stream.extend_from_slice(&reset_packet());
+
+        let frames = decode_stream(&mut decoder, &mut ring, &stream);
+        assert_eq!(frames, vec![reset_packet()]);
+        assert_eq!(decoder.counters().oversized_frames, 1);
+        assert_eq!(
+            decoder.take_error().map(|e| e.kind),
+            Some(ParseErrorKind::DataTooLong { length: 30, maximum: 32 - 7 }),
+        );
+    }
+
+    #[test]
+    fn test_abandon_frame() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+        let packet = version_packet();
+        push_all(&mut ring, &packet[0..7]);
+        assert_eq!(decoder.poll_frame(&mut ring), None);
+        assert!(decoder.is_mid_frame());
+        assert!(decoder.abandon_frame(&mut ring));
+        assert!(!decoder.is_mid_frame());
+        assert_eq!(ring.len(), 0);
+        assert!(!decoder.abandon_frame(&mut ring));
+        assert_eq!(decoder.counters().truncated_frames, 1);
+
+        let frames = decode_stream(&mut decoder, &mut ring, &packet);
+        assert_eq!(frames.len(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_borrowed_packet() {
+        let mut decoder = Esp3Decoder::new();
+        let mut ring: Esp3Buffer = RingBuffer::new();
+
+        // RadioErp1 with an RPS telegram and partial optional data
+        let mut frame = vec![0x55, 0x00, 0x07, 0x02, 0x01, 0x00];
+        frame[5] = crate::crc8::crc8_ccitt(&frame[1..5]);
+        frame.extend_from_slice(&[0xF6, 0x50, 0xFE, 0xF6, 0x1A, 0x2B, 0x30, 0x01, 0xFF]);
+        frame.push(crate::crc8::crc8_ccitt(&frame[6..]));
+
+        let (last, rest) = frame.split_last().unwrap();
+        for b in rest {
+            assert!(ring.push(*b));
+            assert_eq!(decoder.poll_packet(&mut ring), None);
+        }
+        assert!(ring.push(*last));
+
+        let packet = decoder.poll_packet(&mut ring).unwrap();
+        assert_eq!(
+            packet,
+            Esp3PacketRef::RadioErp1 {
+                radio_telegram: &frame[6..13],
+                opt_sub_telegram_number: Some(0x01),
+                opt_destination_id: None,
+                opt_dbm: None,
+                opt_security_level: None,
+            },
+        );
+        assert_eq!(packet.into_owned(), Esp3Packet::from_slice(&frame));
+        assert_eq!(decoder.counters().packets, 1);
+    }
+}
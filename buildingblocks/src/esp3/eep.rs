@@ -0,0 +1,4469 @@
+//! EnOcean Equipment Profiles (EEPs).
+//!
+//! Generated by `eepxml2rust`; do not edit. Each FUNC is only compiled if its feature (e.g.
+//! `eep_a5_20`) is enabled; the feature of each RORG (e.g. `eep_a5`) enables all of its FUNCs.
+
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+use crate::esp3::eep_payload::{EepDecodeError, EepDirection, EepPayload};
+
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+macro_rules! implement_horner {
+    ($name:ident, $type:ty, $zero:expr, $one:expr, $two:expr $(, $max_bits:expr)?) => {
+        fn $name(&self, lowest_bit_index: usize, bit_count: usize) -> Option<$type> {
+            $(assert!(bit_count <= $max_bits);)?
+            let mut value = $zero;
+            let mut factor = $one;
+            for i in 0..bit_count {
+                if self.bit_is_set(lowest_bit_index + bit_count - (i + 1))? {
+                    value += factor;
+                }
+                if i < bit_count-1 {
+                    factor *= $two;
+                }
+            }
+            Some(value)
+        }
+    };
+}
+
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+trait BitTwiddling {
+    /// Returns whether the bit at the given index is set.
+    fn bit_is_set(&self, bit_index: usize) -> Option<bool>;
+
+    #[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+    fn bool_from_bits(&self, bit_index: usize, bit_count: usize) -> Option<bool> {
+        assert!(bit_count <= 1);
+        if bit_count == 1 {
+            self.bit_is_set(bit_index)
+        } else {
+            None
+        }
+    }
+    #[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+    implement_horner!(u8_from_bits, u8, 0, 1, 2, 8);
+    #[cfg(feature = "eep_d2_01")]
+    implement_horner!(u64_from_bits, u64, 0, 1, 2, 64);
+}
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+impl BitTwiddling for &[u8] {
+    // this implementation assumes that bytes are in little-endian order,
+    // i.e. from least to most significant;
+    // the bits within a byte are also considered to be little-endian
+    // (index 0 is LSb of self[0], index 7 is MSb of self[0], index 8 is LSb of self[1], etc.)
+
+    fn bit_is_set(&self, bit_index: usize) -> Option<bool> {
+        let byte_index = bit_index / 8;
+        let bit_in_byte_index = bit_index % 8;
+        if byte_index >= self.len() {
+            return None;
+        }
+
+        Some(self[byte_index] & (1 << bit_in_byte_index) != 0)
+    }
+}
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+trait BitTwiddlingMut {
+    /// Sets or clears the bit at the given index.
+    fn set_bit(&mut self, bit_index: usize, value: bool);
+
+    fn u128_to_bits(&mut self, lowest_bit_index: usize, bit_count: usize, value: u128) {
+        assert!(bit_count <= 128);
+        for i in 0..bit_count {
+            self.set_bit(lowest_bit_index + bit_count - (i + 1), value & (1 << i) != 0);
+        }
+    }
+}
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+impl BitTwiddlingMut for [u8] {
+    // same bit order as the BitTwiddling implementation for &[u8]
+
+    fn set_bit(&mut self, bit_index: usize, value: bool) {
+        let byte_index = bit_index / 8;
+        let bit_in_byte_index = bit_index % 8;
+        if value {
+            self[byte_index] |= 1 << bit_in_byte_index;
+        } else {
+            self[byte_index] &= !(1 << bit_in_byte_index);
+        }
+    }
+}
+
+
+#[cfg(all(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"), feature = "eep_float"))]
+fn range_scale(mut value: f64, min_range: f64, max_range: f64, min_scale: f64, max_scale: f64) -> f64 {
+    let bottom_range = min_range.min(max_range);
+    let top_range = min_range.max(max_range);
+    if value < bottom_range {
+        value = bottom_range;
+    }
+    if value > top_range {
+        value = top_range;
+    }
+
+    let value_zeroed = value - min_range;
+    let value_zeroed_scaled = value_zeroed * (max_scale - min_scale) / (max_range - min_range);
+    value_zeroed_scaled + min_scale
+}
+
+/// Rounds a (range-scaled) value to the nearest raw value.
+#[cfg(all(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"), feature = "eep_float"))]
+fn round_to_raw(value: f64) -> u128 {
+    if value <= 0.0 {
+        0
+    } else {
+        (value + 0.5) as u128
+    }
+}
+
+/// Divides two integers, rounding to the nearest integer (halves away from zero).
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+fn div_round(dividend: i64, divisor: i64) -> i64 {
+    let (dividend, divisor) = if divisor < 0 { (-dividend, -divisor) } else { (dividend, divisor) };
+    if dividend < 0 {
+        (dividend - divisor / 2) / divisor
+    } else {
+        (dividend + divisor / 2) / divisor
+    }
+}
+
+/// A variable with a raw and a scaled value.
+#[cfg(feature = "eep_float")]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct ScaledVariable {
+    pub raw: f64,
+    pub scaled: f64,
+}
+
+/// Keeps track of the cases of a type whose conditions the data fulfills.
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CaseMatcher<T> {
+    opt_matched: Option<T>,
+    ambiguous: bool,
+}
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+impl<T> CaseMatcher<T> {
+    pub const fn new() -> Self {
+        Self { opt_matched: None, ambiguous: false }
+    }
+
+    /// Records a case whose conditions the data fulfills.
+    pub fn push(&mut self, case: T) {
+        if self.opt_matched.is_some() {
+            self.ambiguous = true;
+        } else {
+            self.opt_matched = Some(case);
+        }
+    }
+
+    /// Returns the case whose conditions the data fulfills, or an error if there is no such case
+    /// or more than one.
+    pub fn finish(self) -> Result<T, EepDecodeError> {
+        if self.ambiguous {
+            Err(EepDecodeError::AmbiguousCase)
+        } else {
+            self.opt_matched.ok_or(EepDecodeError::NoCaseMatched)
+        }
+    }
+}
+
+
+/// 4BS Telegram (A5)
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20"))]
+#[allow(non_snake_case)]
+pub mod rorgA5 {
+    #[allow(unused)] use super::{BitTwiddling, BitTwiddlingMut, CaseMatcher, EepDecodeError, EepDirection, div_round};
+    #[cfg(feature = "eep_float")]
+    #[allow(unused)] use super::{range_scale, round_to_raw, ScaledVariable};
+    /// Temperature Sensors (A5-02)
+    #[cfg(feature = "eep_a5_02")]
+    pub mod func02 {
+        #[allow(unused)] use super::{BitTwiddling, BitTwiddlingMut, EepDecodeError, EepDirection, div_round};
+        #[cfg(feature = "eep_float")]
+        #[allow(unused)] use super::{range_scale, round_to_raw, ScaledVariable};
+        /// Temperature Sensor Range 0°C to +40°C (A5-02-05)
+        #[derive(Clone, Copy)]
+        pub struct Type05<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type05<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                true
+            }
+
+            /// Get the raw Temperature value.
+            pub fn get_temperature_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(16, 8)
+            }
+            /// Get the Temperature value in units of °C.
+            #[cfg(feature = "eep_float")]
+            pub fn get_temperature(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_temperature_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 255.0, 0.0, 0.0, 40.0),
+                })
+            }
+
+            /// Get the Temperature value in units of 0.01 °C, using integer arithmetic.
+            pub fn get_temperature_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_temperature_raw()?).clamp(0, 255);
+                let value = div_round((raw_value - 255) * -800, 51);
+                Some(value as i32)
+            }
+
+            /// Get the raw LRN Bit value.
+            pub fn get_lrn_bit_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(28, 1)
+            }
+            /// Get the LRN Bit value.
+            pub fn get_lrn_bit(&self) -> Option<Type05PropLrnBit> {
+                let raw_value = self.get_lrn_bit_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type05<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type05");
+                #[cfg(feature = "eep_float")]
+                debug.field("temperature", &self.get_temperature());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("temperature", &self.get_temperature_fixed());
+                debug.field("lrn_bit", &self.get_lrn_bit());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Temperature Sensor Range 0°C to +40°C (A5-02-05)
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type05Builder {
+            reversed_bytes: [u8; 4],
+        }
+        impl Type05Builder {
+            /// Creates a new builder for a data telegram with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x00, 0x00, 0x00, 0x10] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 4] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 4] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type05<'_> {
+                Type05::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Temperature value.
+            pub fn set_temperature_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(16, 8, raw_value as u128);
+                self
+            }
+            /// Set the Temperature value in units of °C. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_temperature(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 40.0, 255.0, 0.0);
+                self.reversed_bytes.u128_to_bits(16, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Temperature value in units of 0.01 °C, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_temperature_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 4000);
+                let raw_value = 255 + div_round(value * 51, -800);
+                self.reversed_bytes.u128_to_bits(16, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw LRN Bit value.
+            pub fn set_lrn_bit_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(28, 1, raw_value as u128);
+                self
+            }
+            /// Set the LRN Bit value.
+            pub fn set_lrn_bit(self, value: Type05PropLrnBit) -> Self {
+                self.set_lrn_bit_raw(value.into())
+            }
+        }
+        impl Default for Type05Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type05PropLrnBit {
+            TeachInTelegram,
+            DataTelegram,
+            _Other(bool),
+        }
+        impl From<bool> for Type05PropLrnBit {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::TeachInTelegram,
+                    true => Self::DataTelegram,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type05PropLrnBit> for bool {
+            fn from(enum_value: Type05PropLrnBit) -> Self {
+                match enum_value {
+                    Type05PropLrnBit::TeachInTelegram => false,
+                    Type05PropLrnBit::DataTelegram => true,
+                    Type05PropLrnBit::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type05PropLrnBit {
+            fn eq(&self, other: &Type05PropLrnBit) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type05PropLrnBit {}
+        impl ::core::cmp::PartialOrd for Type05PropLrnBit {
+            fn partial_cmp(&self, other: &Type05PropLrnBit) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type05PropLrnBit {
+            fn cmp(&self, other: &Type05PropLrnBit) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+    }
+
+    #[cfg(feature = "eep_a5_02")]
+    #[derive(Clone, Copy, Debug)]
+    pub enum Func02<'b> {
+        Type05(func02::Type05<'b>),
+    }
+    #[cfg(feature = "eep_a5_02")]
+    impl<'b> Func02<'b> {
+        /// Returns the case of the type whose conditions the data fulfills.
+        pub fn type05_from_reversed_bytes(reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            let mut matcher = CaseMatcher::new();
+            let case = func02::Type05::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type05(case));
+            }
+            matcher.finish()
+        }
+
+        pub fn from_reversed_bytes(type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            match type_code {
+                0x05 => Self::type05_from_reversed_bytes(reversed_bytes, opt_direction),
+                _ => Err(EepDecodeError::UnknownProfile),
+            }
+        }
+    }
+    /// Room Operating Panel (A5-10)
+    #[cfg(feature = "eep_a5_10")]
+    pub mod func10 {
+        #[allow(unused)] use super::{BitTwiddling, BitTwiddlingMut, EepDecodeError, EepDirection, div_round};
+        #[cfg(feature = "eep_float")]
+        #[allow(unused)] use super::{range_scale, round_to_raw, ScaledVariable};
+        /// Temperature Sensor, Set Point Control (A5-10-03)
+        #[derive(Clone, Copy)]
+        pub struct Type03<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type03<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                true
+            }
+
+            /// Get the raw Set point value.
+            pub fn get_set_point_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(8, 8)
+            }
+            /// Get the Set point value.
+            #[cfg(feature = "eep_float")]
+            pub fn get_set_point(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_set_point_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 0.0, 255.0, 0.0, 255.0),
+                })
+            }
+
+            /// Get the Set point value in units of 0.01, using integer arithmetic.
+            pub fn get_set_point_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_set_point_raw()?).clamp(0, 255);
+                let value = raw_value * 100;
+                Some(value as i32)
+            }
+
+            /// Get the raw Temperature value.
+            pub fn get_temperature_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(16, 8)
+            }
+            /// Get the Temperature value in units of °C.
+            #[cfg(feature = "eep_float")]
+            pub fn get_temperature(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_temperature_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 255.0, 0.0, 0.0, 40.0),
+                })
+            }
+
+            /// Get the Temperature value in units of 0.01 °C, using integer arithmetic.
+            pub fn get_temperature_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_temperature_raw()?).clamp(0, 255);
+                let value = div_round((raw_value - 255) * -800, 51);
+                Some(value as i32)
+            }
+
+            /// Get the raw LRN Bit value.
+            pub fn get_lrn_bit_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(28, 1)
+            }
+            /// Get the LRN Bit value.
+            pub fn get_lrn_bit(&self) -> Option<Type03PropLrnBit> {
+                let raw_value = self.get_lrn_bit_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type03<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type03");
+                #[cfg(feature = "eep_float")]
+                debug.field("set_point", &self.get_set_point());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("set_point", &self.get_set_point_fixed());
+                #[cfg(feature = "eep_float")]
+                debug.field("temperature", &self.get_temperature());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("temperature", &self.get_temperature_fixed());
+                debug.field("lrn_bit", &self.get_lrn_bit());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Temperature Sensor, Set Point Control (A5-10-03)
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type03Builder {
+            reversed_bytes: [u8; 4],
+        }
+        impl Type03Builder {
+            /// Creates a new builder for a data telegram with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x00, 0x00, 0x00, 0x10] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 4] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 4] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type03<'_> {
+                Type03::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Set point value.
+            pub fn set_set_point_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(8, 8, raw_value as u128);
+                self
+            }
+            /// Set the Set point value. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_set_point(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 255.0, 0.0, 255.0);
+                self.reversed_bytes.u128_to_bits(8, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Set point value in units of 0.01, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_set_point_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 25500);
+                let raw_value = div_round(value, 100);
+                self.reversed_bytes.u128_to_bits(8, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw Temperature value.
+            pub fn set_temperature_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(16, 8, raw_value as u128);
+                self
+            }
+            /// Set the Temperature value in units of °C. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_temperature(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 40.0, 255.0, 0.0);
+                self.reversed_bytes.u128_to_bits(16, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Temperature value in units of 0.01 °C, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_temperature_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 4000);
+                let raw_value = 255 + div_round(value * 51, -800);
+                self.reversed_bytes.u128_to_bits(16, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw LRN Bit value.
+            pub fn set_lrn_bit_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(28, 1, raw_value as u128);
+                self
+            }
+            /// Set the LRN Bit value.
+            pub fn set_lrn_bit(self, value: Type03PropLrnBit) -> Self {
+                self.set_lrn_bit_raw(value.into())
+            }
+        }
+        impl Default for Type03Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type03PropLrnBit {
+            TeachInTelegram,
+            DataTelegram,
+            _Other(bool),
+        }
+        impl From<bool> for Type03PropLrnBit {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::TeachInTelegram,
+                    true => Self::DataTelegram,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type03PropLrnBit> for bool {
+            fn from(enum_value: Type03PropLrnBit) -> Self {
+                match enum_value {
+                    Type03PropLrnBit::TeachInTelegram => false,
+                    Type03PropLrnBit::DataTelegram => true,
+                    Type03PropLrnBit::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type03PropLrnBit {
+            fn eq(&self, other: &Type03PropLrnBit) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type03PropLrnBit {}
+        impl ::core::cmp::PartialOrd for Type03PropLrnBit {
+            fn partial_cmp(&self, other: &Type03PropLrnBit) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type03PropLrnBit {
+            fn cmp(&self, other: &Type03PropLrnBit) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+    }
+
+    #[cfg(feature = "eep_a5_10")]
+    #[derive(Clone, Copy, Debug)]
+    pub enum Func10<'b> {
+        Type03(func10::Type03<'b>),
+    }
+    #[cfg(feature = "eep_a5_10")]
+    impl<'b> Func10<'b> {
+        /// Returns the case of the type whose conditions the data fulfills.
+        pub fn type03_from_reversed_bytes(reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            let mut matcher = CaseMatcher::new();
+            let case = func10::Type03::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type03(case));
+            }
+            matcher.finish()
+        }
+
+        pub fn from_reversed_bytes(type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            match type_code {
+                0x03 => Self::type03_from_reversed_bytes(reversed_bytes, opt_direction),
+                _ => Err(EepDecodeError::UnknownProfile),
+            }
+        }
+    }
+    /// HVAC Components (A5-20)
+    #[cfg(feature = "eep_a5_20")]
+    pub mod func20 {
+        #[allow(unused)] use super::{BitTwiddling, BitTwiddlingMut, EepDecodeError, EepDirection, div_round};
+        #[cfg(feature = "eep_float")]
+        #[allow(unused)] use super::{range_scale, round_to_raw, ScaledVariable};
+        /// Battery Powered Actuator (BI-DIR) (A5-20-01), case 0
+        #[derive(Clone, Copy)]
+        pub struct Type01Case0<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type01Case0<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, opt_direction: Option<EepDirection>) -> bool {
+                opt_direction.map(u8::from) == Some(1)
+            }
+
+            /// Get the raw Current Value value.
+            pub fn get_current_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(0, 8)
+            }
+            /// Get the Current Value value in units of %.
+            #[cfg(feature = "eep_float")]
+            pub fn get_current_value(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_current_value_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 0.0, 100.0, 0.0, 100.0),
+                })
+            }
+
+            /// Get the Current Value value in units of 0.01 %, using integer arithmetic.
+            pub fn get_current_value_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_current_value_raw()?).clamp(0, 100);
+                let value = raw_value * 100;
+                Some(value as i32)
+            }
+
+            /// Get the raw Service On value.
+            pub fn get_service_on_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(8, 1)
+            }
+            /// Get the Service On value.
+            pub fn get_service_on(&self) -> Option<Type01Case0PropServiceOn> {
+                let raw_value = self.get_service_on_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Energy input enabled value.
+            pub fn get_energy_input_enabled_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(9, 1)
+            }
+            /// Get the Energy input enabled value.
+            pub fn get_energy_input_enabled(&self) -> Option<Type01Case0PropEnergyInputEnabled> {
+                let raw_value = self.get_energy_input_enabled_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Energy storage sufficiently charged value.
+            pub fn get_energy_storage_sufficiently_charged_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(10, 1)
+            }
+            /// Get the Energy storage sufficiently charged value.
+            pub fn get_energy_storage_sufficiently_charged(&self) -> Option<Type01Case0PropEnergyStorageSufficientlyCharged> {
+                let raw_value = self.get_energy_storage_sufficiently_charged_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Battery capacity; change battery next days value.
+            pub fn get_battery_capacity_change_battery_next_days_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(11, 1)
+            }
+            /// Get the Battery capacity; change battery next days value.
+            pub fn get_battery_capacity_change_battery_next_days(&self) -> Option<Type01Case0PropBatteryCapacityChangeBatteryNextDays> {
+                let raw_value = self.get_battery_capacity_change_battery_next_days_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Contact, cover open value.
+            pub fn get_contact_cover_open_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(12, 1)
+            }
+            /// Get the Contact, cover open value.
+            pub fn get_contact_cover_open(&self) -> Option<Type01Case0PropContactCoverOpen> {
+                let raw_value = self.get_contact_cover_open_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Failure temperature sensor, out of range value.
+            pub fn get_failure_temperature_sensor_out_of_range_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(13, 1)
+            }
+            /// Get the Failure temperature sensor, out of range value.
+            pub fn get_failure_temperature_sensor_out_of_range(&self) -> Option<Type01Case0PropFailureTemperatureSensorOutOfRange> {
+                let raw_value = self.get_failure_temperature_sensor_out_of_range_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Detection, window open value.
+            pub fn get_detection_window_open_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(14, 1)
+            }
+            /// Get the Detection, window open value.
+            pub fn get_detection_window_open(&self) -> Option<Type01Case0PropDetectionWindowOpen> {
+                let raw_value = self.get_detection_window_open_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Actuator obstructed value.
+            pub fn get_actuator_obstructed_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(15, 1)
+            }
+            /// Get the Actuator obstructed value.
+            pub fn get_actuator_obstructed(&self) -> Option<Type01Case0PropActuatorObstructed> {
+                let raw_value = self.get_actuator_obstructed_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Temperature value.
+            pub fn get_temperature_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(16, 8)
+            }
+            /// Get the Temperature value in units of °C.
+            #[cfg(feature = "eep_float")]
+            pub fn get_temperature(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_temperature_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 0.0, 255.0, 0.0, 40.0),
+                })
+            }
+
+            /// Get the Temperature value in units of 0.01 °C, using integer arithmetic.
+            pub fn get_temperature_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_temperature_raw()?).clamp(0, 255);
+                let value = div_round(raw_value * 800, 51);
+                Some(value as i32)
+            }
+
+            /// Get the raw LRN Bit value.
+            pub fn get_lrn_bit_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(28, 1)
+            }
+            /// Get the LRN Bit value.
+            pub fn get_lrn_bit(&self) -> Option<Type01Case0PropLrnBit> {
+                let raw_value = self.get_lrn_bit_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type01Case0<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type01Case0");
+                #[cfg(feature = "eep_float")]
+                debug.field("current_value", &self.get_current_value());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("current_value", &self.get_current_value_fixed());
+                debug.field("service_on", &self.get_service_on());
+                debug.field("energy_input_enabled", &self.get_energy_input_enabled());
+                debug.field("energy_storage_sufficiently_charged", &self.get_energy_storage_sufficiently_charged());
+                debug.field("battery_capacity_change_battery_next_days", &self.get_battery_capacity_change_battery_next_days());
+                debug.field("contact_cover_open", &self.get_contact_cover_open());
+                debug.field("failure_temperature_sensor_out_of_range", &self.get_failure_temperature_sensor_out_of_range());
+                debug.field("detection_window_open", &self.get_detection_window_open());
+                debug.field("actuator_obstructed", &self.get_actuator_obstructed());
+                #[cfg(feature = "eep_float")]
+                debug.field("temperature", &self.get_temperature());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("temperature", &self.get_temperature_fixed());
+                debug.field("lrn_bit", &self.get_lrn_bit());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Battery Powered Actuator (BI-DIR) (A5-20-01), case 0
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type01Case0Builder {
+            reversed_bytes: [u8; 4],
+        }
+        impl Type01Case0Builder {
+            /// Creates a new builder for a data telegram with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x00, 0x00, 0x00, 0x10] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 4] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 4] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type01Case0<'_> {
+                Type01Case0::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Current Value value.
+            pub fn set_current_value_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(0, 8, raw_value as u128);
+                self
+            }
+            /// Set the Current Value value in units of %. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_current_value(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 100.0, 0.0, 100.0);
+                self.reversed_bytes.u128_to_bits(0, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Current Value value in units of 0.01 %, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_current_value_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 10000);
+                let raw_value = div_round(value, 100);
+                self.reversed_bytes.u128_to_bits(0, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw Service On value.
+            pub fn set_service_on_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(8, 1, raw_value as u128);
+                self
+            }
+            /// Set the Service On value.
+            pub fn set_service_on(self, value: Type01Case0PropServiceOn) -> Self {
+                self.set_service_on_raw(value.into())
+            }
+
+            /// Set the raw Energy input enabled value.
+            pub fn set_energy_input_enabled_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(9, 1, raw_value as u128);
+                self
+            }
+            /// Set the Energy input enabled value.
+            pub fn set_energy_input_enabled(self, value: Type01Case0PropEnergyInputEnabled) -> Self {
+                self.set_energy_input_enabled_raw(value.into())
+            }
+
+            /// Set the raw Energy storage sufficiently charged value.
+            pub fn set_energy_storage_sufficiently_charged_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(10, 1, raw_value as u128);
+                self
+            }
+            /// Set the Energy storage sufficiently charged value.
+            pub fn set_energy_storage_sufficiently_charged(self, value: Type01Case0PropEnergyStorageSufficientlyCharged) -> Self {
+                self.set_energy_storage_sufficiently_charged_raw(value.into())
+            }
+
+            /// Set the raw Battery capacity; change battery next days value.
+            pub fn set_battery_capacity_change_battery_next_days_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(11, 1, raw_value as u128);
+                self
+            }
+            /// Set the Battery capacity; change battery next days value.
+            pub fn set_battery_capacity_change_battery_next_days(self, value: Type01Case0PropBatteryCapacityChangeBatteryNextDays) -> Self {
+                self.set_battery_capacity_change_battery_next_days_raw(value.into())
+            }
+
+            /// Set the raw Contact, cover open value.
+            pub fn set_contact_cover_open_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(12, 1, raw_value as u128);
+                self
+            }
+            /// Set the Contact, cover open value.
+            pub fn set_contact_cover_open(self, value: Type01Case0PropContactCoverOpen) -> Self {
+                self.set_contact_cover_open_raw(value.into())
+            }
+
+            /// Set the raw Failure temperature sensor, out of range value.
+            pub fn set_failure_temperature_sensor_out_of_range_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(13, 1, raw_value as u128);
+                self
+            }
+            /// Set the Failure temperature sensor, out of range value.
+            pub fn set_failure_temperature_sensor_out_of_range(self, value: Type01Case0PropFailureTemperatureSensorOutOfRange) -> Self {
+                self.set_failure_temperature_sensor_out_of_range_raw(value.into())
+            }
+
+            /// Set the raw Detection, window open value.
+            pub fn set_detection_window_open_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(14, 1, raw_value as u128);
+                self
+            }
+            /// Set the Detection, window open value.
+            pub fn set_detection_window_open(self, value: Type01Case0PropDetectionWindowOpen) -> Self {
+                self.set_detection_window_open_raw(value.into())
+            }
+
+            /// Set the raw Actuator obstructed value.
+            pub fn set_actuator_obstructed_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(15, 1, raw_value as u128);
+                self
+            }
+            /// Set the Actuator obstructed value.
+            pub fn set_actuator_obstructed(self, value: Type01Case0PropActuatorObstructed) -> Self {
+                self.set_actuator_obstructed_raw(value.into())
+            }
+
+            /// Set the raw Temperature value.
+            pub fn set_temperature_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(16, 8, raw_value as u128);
+                self
+            }
+            /// Set the Temperature value in units of °C. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_temperature(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 40.0, 0.0, 255.0);
+                self.reversed_bytes.u128_to_bits(16, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Temperature value in units of 0.01 °C, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_temperature_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 4000);
+                let raw_value = div_round(value * 51, 800);
+                self.reversed_bytes.u128_to_bits(16, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw LRN Bit value.
+            pub fn set_lrn_bit_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(28, 1, raw_value as u128);
+                self
+            }
+            /// Set the LRN Bit value.
+            pub fn set_lrn_bit(self, value: Type01Case0PropLrnBit) -> Self {
+                self.set_lrn_bit_raw(value.into())
+            }
+        }
+        impl Default for Type01Case0Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropServiceOn {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropServiceOn {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropServiceOn> for bool {
+            fn from(enum_value: Type01Case0PropServiceOn) -> Self {
+                match enum_value {
+                    Type01Case0PropServiceOn::False => false,
+                    Type01Case0PropServiceOn::True => true,
+                    Type01Case0PropServiceOn::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropServiceOn {
+            fn eq(&self, other: &Type01Case0PropServiceOn) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropServiceOn {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropServiceOn {
+            fn partial_cmp(&self, other: &Type01Case0PropServiceOn) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropServiceOn {
+            fn cmp(&self, other: &Type01Case0PropServiceOn) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropEnergyInputEnabled {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropEnergyInputEnabled {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropEnergyInputEnabled> for bool {
+            fn from(enum_value: Type01Case0PropEnergyInputEnabled) -> Self {
+                match enum_value {
+                    Type01Case0PropEnergyInputEnabled::False => false,
+                    Type01Case0PropEnergyInputEnabled::True => true,
+                    Type01Case0PropEnergyInputEnabled::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropEnergyInputEnabled {
+            fn eq(&self, other: &Type01Case0PropEnergyInputEnabled) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropEnergyInputEnabled {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropEnergyInputEnabled {
+            fn partial_cmp(&self, other: &Type01Case0PropEnergyInputEnabled) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropEnergyInputEnabled {
+            fn cmp(&self, other: &Type01Case0PropEnergyInputEnabled) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropEnergyStorageSufficientlyCharged {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropEnergyStorageSufficientlyCharged {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropEnergyStorageSufficientlyCharged> for bool {
+            fn from(enum_value: Type01Case0PropEnergyStorageSufficientlyCharged) -> Self {
+                match enum_value {
+                    Type01Case0PropEnergyStorageSufficientlyCharged::False => false,
+                    Type01Case0PropEnergyStorageSufficientlyCharged::True => true,
+                    Type01Case0PropEnergyStorageSufficientlyCharged::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropEnergyStorageSufficientlyCharged {
+            fn eq(&self, other: &Type01Case0PropEnergyStorageSufficientlyCharged) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropEnergyStorageSufficientlyCharged {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropEnergyStorageSufficientlyCharged {
+            fn partial_cmp(&self, other: &Type01Case0PropEnergyStorageSufficientlyCharged) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropEnergyStorageSufficientlyCharged {
+            fn cmp(&self, other: &Type01Case0PropEnergyStorageSufficientlyCharged) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropBatteryCapacityChangeBatteryNextDays {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropBatteryCapacityChangeBatteryNextDays {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropBatteryCapacityChangeBatteryNextDays> for bool {
+            fn from(enum_value: Type01Case0PropBatteryCapacityChangeBatteryNextDays) -> Self {
+                match enum_value {
+                    Type01Case0PropBatteryCapacityChangeBatteryNextDays::False => false,
+                    Type01Case0PropBatteryCapacityChangeBatteryNextDays::True => true,
+                    Type01Case0PropBatteryCapacityChangeBatteryNextDays::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropBatteryCapacityChangeBatteryNextDays {
+            fn eq(&self, other: &Type01Case0PropBatteryCapacityChangeBatteryNextDays) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropBatteryCapacityChangeBatteryNextDays {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropBatteryCapacityChangeBatteryNextDays {
+            fn partial_cmp(&self, other: &Type01Case0PropBatteryCapacityChangeBatteryNextDays) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropBatteryCapacityChangeBatteryNextDays {
+            fn cmp(&self, other: &Type01Case0PropBatteryCapacityChangeBatteryNextDays) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropContactCoverOpen {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropContactCoverOpen {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropContactCoverOpen> for bool {
+            fn from(enum_value: Type01Case0PropContactCoverOpen) -> Self {
+                match enum_value {
+                    Type01Case0PropContactCoverOpen::False => false,
+                    Type01Case0PropContactCoverOpen::True => true,
+                    Type01Case0PropContactCoverOpen::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropContactCoverOpen {
+            fn eq(&self, other: &Type01Case0PropContactCoverOpen) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropContactCoverOpen {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropContactCoverOpen {
+            fn partial_cmp(&self, other: &Type01Case0PropContactCoverOpen) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropContactCoverOpen {
+            fn cmp(&self, other: &Type01Case0PropContactCoverOpen) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropFailureTemperatureSensorOutOfRange {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropFailureTemperatureSensorOutOfRange {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropFailureTemperatureSensorOutOfRange> for bool {
+            fn from(enum_value: Type01Case0PropFailureTemperatureSensorOutOfRange) -> Self {
+                match enum_value {
+                    Type01Case0PropFailureTemperatureSensorOutOfRange::False => false,
+                    Type01Case0PropFailureTemperatureSensorOutOfRange::True => true,
+                    Type01Case0PropFailureTemperatureSensorOutOfRange::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropFailureTemperatureSensorOutOfRange {
+            fn eq(&self, other: &Type01Case0PropFailureTemperatureSensorOutOfRange) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropFailureTemperatureSensorOutOfRange {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropFailureTemperatureSensorOutOfRange {
+            fn partial_cmp(&self, other: &Type01Case0PropFailureTemperatureSensorOutOfRange) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropFailureTemperatureSensorOutOfRange {
+            fn cmp(&self, other: &Type01Case0PropFailureTemperatureSensorOutOfRange) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropDetectionWindowOpen {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropDetectionWindowOpen {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropDetectionWindowOpen> for bool {
+            fn from(enum_value: Type01Case0PropDetectionWindowOpen) -> Self {
+                match enum_value {
+                    Type01Case0PropDetectionWindowOpen::False => false,
+                    Type01Case0PropDetectionWindowOpen::True => true,
+                    Type01Case0PropDetectionWindowOpen::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropDetectionWindowOpen {
+            fn eq(&self, other: &Type01Case0PropDetectionWindowOpen) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropDetectionWindowOpen {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropDetectionWindowOpen {
+            fn partial_cmp(&self, other: &Type01Case0PropDetectionWindowOpen) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropDetectionWindowOpen {
+            fn cmp(&self, other: &Type01Case0PropDetectionWindowOpen) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropActuatorObstructed {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropActuatorObstructed {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropActuatorObstructed> for bool {
+            fn from(enum_value: Type01Case0PropActuatorObstructed) -> Self {
+                match enum_value {
+                    Type01Case0PropActuatorObstructed::False => false,
+                    Type01Case0PropActuatorObstructed::True => true,
+                    Type01Case0PropActuatorObstructed::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropActuatorObstructed {
+            fn eq(&self, other: &Type01Case0PropActuatorObstructed) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropActuatorObstructed {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropActuatorObstructed {
+            fn partial_cmp(&self, other: &Type01Case0PropActuatorObstructed) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropActuatorObstructed {
+            fn cmp(&self, other: &Type01Case0PropActuatorObstructed) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropLrnBit {
+            TeachInTelegram,
+            DataTelegram,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case0PropLrnBit {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::TeachInTelegram,
+                    true => Self::DataTelegram,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropLrnBit> for bool {
+            fn from(enum_value: Type01Case0PropLrnBit) -> Self {
+                match enum_value {
+                    Type01Case0PropLrnBit::TeachInTelegram => false,
+                    Type01Case0PropLrnBit::DataTelegram => true,
+                    Type01Case0PropLrnBit::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropLrnBit {
+            fn eq(&self, other: &Type01Case0PropLrnBit) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropLrnBit {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropLrnBit {
+            fn partial_cmp(&self, other: &Type01Case0PropLrnBit) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropLrnBit {
+            fn cmp(&self, other: &Type01Case0PropLrnBit) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        /// Battery Powered Actuator (BI-DIR) (A5-20-01), case 1
+        #[derive(Clone, Copy)]
+        pub struct Type01Case1<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type01Case1<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, opt_direction: Option<EepDirection>) -> bool {
+                opt_direction.map(u8::from) == Some(2)
+            }
+
+            /// Get the raw Valve position or Temperature Setpoint value.
+            pub fn get_valve_position_or_temperature_setpoint_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(0, 8)
+            }
+            /// Get the Valve position or Temperature Setpoint value.
+            #[cfg(feature = "eep_float")]
+            pub fn get_valve_position_or_temperature_setpoint(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_valve_position_or_temperature_setpoint_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 0.0, 255.0, 0.0, 255.0),
+                })
+            }
+
+            /// Get the Valve position or Temperature Setpoint value in units of 0.01, using integer arithmetic.
+            pub fn get_valve_position_or_temperature_setpoint_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_valve_position_or_temperature_setpoint_raw()?).clamp(0, 255);
+                let value = raw_value * 100;
+                Some(value as i32)
+            }
+
+            /// Get the raw Temperature from RCU value.
+            pub fn get_temperature_from_rcu_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(8, 8)
+            }
+            /// Get the Temperature from RCU value in units of °C.
+            #[cfg(feature = "eep_float")]
+            pub fn get_temperature_from_rcu(&self) -> Option<ScaledVariable> {
+                let raw_value = self.get_temperature_from_rcu_raw()? as f64;
+                Some(ScaledVariable {
+                    raw: raw_value,
+                    scaled: range_scale(raw_value, 255.0, 0.0, 0.0, 40.0),
+                })
+            }
+
+            /// Get the Temperature from RCU value in units of 0.01 °C, using integer arithmetic.
+            pub fn get_temperature_from_rcu_fixed(&self) -> Option<i32> {
+                let raw_value = i64::from(self.get_temperature_from_rcu_raw()?).clamp(0, 255);
+                let value = div_round((raw_value - 255) * -800, 51);
+                Some(value as i32)
+            }
+
+            /// Get the raw Run init sequence value.
+            pub fn get_run_init_sequence_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(16, 1)
+            }
+            /// Get the Run init sequence value.
+            pub fn get_run_init_sequence(&self) -> Option<Type01Case1PropRunInitSequence> {
+                let raw_value = self.get_run_init_sequence_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Lift set value.
+            pub fn get_lift_set_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(17, 1)
+            }
+            /// Get the Lift set value.
+            pub fn get_lift_set(&self) -> Option<Type01Case1PropLiftSet> {
+                let raw_value = self.get_lift_set_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Valve open / maintenance value.
+            pub fn get_valve_open_maintenance_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(18, 1)
+            }
+            /// Get the Valve open / maintenance value.
+            pub fn get_valve_open_maintenance(&self) -> Option<Type01Case1PropValveOpenMaintenance> {
+                let raw_value = self.get_valve_open_maintenance_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Valve closed value.
+            pub fn get_valve_closed_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(19, 1)
+            }
+            /// Get the Valve closed value.
+            pub fn get_valve_closed(&self) -> Option<Type01Case1PropValveClosed> {
+                let raw_value = self.get_valve_closed_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Summer bit, Reduction of energy consumption value.
+            pub fn get_summer_bit_reduction_of_energy_consumption_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(20, 1)
+            }
+            /// Get the Summer bit, Reduction of energy consumption value.
+            pub fn get_summer_bit_reduction_of_energy_consumption(&self) -> Option<Type01Case1PropSummerBitReductionOfEnergyConsumption> {
+                let raw_value = self.get_summer_bit_reduction_of_energy_consumption_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Set point selection value.
+            pub fn get_set_point_selection_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(21, 1)
+            }
+            /// Get the Set point selection value.
+            pub fn get_set_point_selection(&self) -> Option<Type01Case1PropSetPointSelection> {
+                let raw_value = self.get_set_point_selection_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Set point inverse value.
+            pub fn get_set_point_inverse_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(22, 1)
+            }
+            /// Get the Set point inverse value.
+            pub fn get_set_point_inverse(&self) -> Option<Type01Case1PropSetPointInverse> {
+                let raw_value = self.get_set_point_inverse_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Select function value.
+            pub fn get_select_function_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(23, 1)
+            }
+            /// Get the Select function value.
+            pub fn get_select_function(&self) -> Option<Type01Case1PropSelectFunction> {
+                let raw_value = self.get_select_function_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw LRN Bit value.
+            pub fn get_lrn_bit_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(28, 1)
+            }
+            /// Get the LRN Bit value.
+            pub fn get_lrn_bit(&self) -> Option<Type01Case1PropLrnBit> {
+                let raw_value = self.get_lrn_bit_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type01Case1<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type01Case1");
+                #[cfg(feature = "eep_float")]
+                debug.field("valve_position_or_temperature_setpoint", &self.get_valve_position_or_temperature_setpoint());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("valve_position_or_temperature_setpoint", &self.get_valve_position_or_temperature_setpoint_fixed());
+                #[cfg(feature = "eep_float")]
+                debug.field("temperature_from_rcu", &self.get_temperature_from_rcu());
+                #[cfg(not(feature = "eep_float"))]
+                debug.field("temperature_from_rcu", &self.get_temperature_from_rcu_fixed());
+                debug.field("run_init_sequence", &self.get_run_init_sequence());
+                debug.field("lift_set", &self.get_lift_set());
+                debug.field("valve_open_maintenance", &self.get_valve_open_maintenance());
+                debug.field("valve_closed", &self.get_valve_closed());
+                debug.field("summer_bit_reduction_of_energy_consumption", &self.get_summer_bit_reduction_of_energy_consumption());
+                debug.field("set_point_selection", &self.get_set_point_selection());
+                debug.field("set_point_inverse", &self.get_set_point_inverse());
+                debug.field("select_function", &self.get_select_function());
+                debug.field("lrn_bit", &self.get_lrn_bit());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Battery Powered Actuator (BI-DIR) (A5-20-01), case 1
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type01Case1Builder {
+            reversed_bytes: [u8; 4],
+        }
+        impl Type01Case1Builder {
+            /// Creates a new builder for a data telegram with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x00, 0x00, 0x00, 0x10] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 4] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 4] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type01Case1<'_> {
+                Type01Case1::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Valve position or Temperature Setpoint value.
+            pub fn set_valve_position_or_temperature_setpoint_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(0, 8, raw_value as u128);
+                self
+            }
+            /// Set the Valve position or Temperature Setpoint value. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_valve_position_or_temperature_setpoint(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 255.0, 0.0, 255.0);
+                self.reversed_bytes.u128_to_bits(0, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Valve position or Temperature Setpoint value in units of 0.01, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_valve_position_or_temperature_setpoint_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 25500);
+                let raw_value = div_round(value, 100);
+                self.reversed_bytes.u128_to_bits(0, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw Temperature from RCU value.
+            pub fn set_temperature_from_rcu_raw(mut self, raw_value: u8) -> Self {
+                self.reversed_bytes.u128_to_bits(8, 8, raw_value as u128);
+                self
+            }
+            /// Set the Temperature from RCU value in units of °C. Values outside the valid range are clamped.
+            #[cfg(feature = "eep_float")]
+            pub fn set_temperature_from_rcu(mut self, value: f64) -> Self {
+                let raw_value = range_scale(value, 0.0, 40.0, 255.0, 0.0);
+                self.reversed_bytes.u128_to_bits(8, 8, round_to_raw(raw_value));
+                self
+            }
+
+            /// Set the Temperature from RCU value in units of 0.01 °C, using integer arithmetic. Values outside the valid range are clamped.
+            pub fn set_temperature_from_rcu_fixed(mut self, value: i32) -> Self {
+                let value = i64::from(value).clamp(0, 4000);
+                let raw_value = 255 + div_round(value * 51, -800);
+                self.reversed_bytes.u128_to_bits(8, 8, raw_value as u128);
+                self
+            }
+
+            /// Set the raw Run init sequence value.
+            pub fn set_run_init_sequence_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(16, 1, raw_value as u128);
+                self
+            }
+            /// Set the Run init sequence value.
+            pub fn set_run_init_sequence(self, value: Type01Case1PropRunInitSequence) -> Self {
+                self.set_run_init_sequence_raw(value.into())
+            }
+
+            /// Set the raw Lift set value.
+            pub fn set_lift_set_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(17, 1, raw_value as u128);
+                self
+            }
+            /// Set the Lift set value.
+            pub fn set_lift_set(self, value: Type01Case1PropLiftSet) -> Self {
+                self.set_lift_set_raw(value.into())
+            }
+
+            /// Set the raw Valve open / maintenance value.
+            pub fn set_valve_open_maintenance_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(18, 1, raw_value as u128);
+                self
+            }
+            /// Set the Valve open / maintenance value.
+            pub fn set_valve_open_maintenance(self, value: Type01Case1PropValveOpenMaintenance) -> Self {
+                self.set_valve_open_maintenance_raw(value.into())
+            }
+
+            /// Set the raw Valve closed value.
+            pub fn set_valve_closed_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(19, 1, raw_value as u128);
+                self
+            }
+            /// Set the Valve closed value.
+            pub fn set_valve_closed(self, value: Type01Case1PropValveClosed) -> Self {
+                self.set_valve_closed_raw(value.into())
+            }
+
+            /// Set the raw Summer bit, Reduction of energy consumption value.
+            pub fn set_summer_bit_reduction_of_energy_consumption_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(20, 1, raw_value as u128);
+                self
+            }
+            /// Set the Summer bit, Reduction of energy consumption value.
+            pub fn set_summer_bit_reduction_of_energy_consumption(self, value: Type01Case1PropSummerBitReductionOfEnergyConsumption) -> Self {
+                self.set_summer_bit_reduction_of_energy_consumption_raw(value.into())
+            }
+
+            /// Set the raw Set point selection value.
+            pub fn set_set_point_selection_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(21, 1, raw_value as u128);
+                self
+            }
+            /// Set the Set point selection value.
+            pub fn set_set_point_selection(self, value: Type01Case1PropSetPointSelection) -> Self {
+                self.set_set_point_selection_raw(value.into())
+            }
+
+            /// Set the raw Set point inverse value.
+            pub fn set_set_point_inverse_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(22, 1, raw_value as u128);
+                self
+            }
+            /// Set the Set point inverse value.
+            pub fn set_set_point_inverse(self, value: Type01Case1PropSetPointInverse) -> Self {
+                self.set_set_point_inverse_raw(value.into())
+            }
+
+            /// Set the raw Select function value.
+            pub fn set_select_function_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(23, 1, raw_value as u128);
+                self
+            }
+            /// Set the Select function value.
+            pub fn set_select_function(self, value: Type01Case1PropSelectFunction) -> Self {
+                self.set_select_function_raw(value.into())
+            }
+
+            /// Set the raw LRN Bit value.
+            pub fn set_lrn_bit_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(28, 1, raw_value as u128);
+                self
+            }
+            /// Set the LRN Bit value.
+            pub fn set_lrn_bit(self, value: Type01Case1PropLrnBit) -> Self {
+                self.set_lrn_bit_raw(value.into())
+            }
+        }
+        impl Default for Type01Case1Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropRunInitSequence {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropRunInitSequence {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropRunInitSequence> for bool {
+            fn from(enum_value: Type01Case1PropRunInitSequence) -> Self {
+                match enum_value {
+                    Type01Case1PropRunInitSequence::False => false,
+                    Type01Case1PropRunInitSequence::True => true,
+                    Type01Case1PropRunInitSequence::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropRunInitSequence {
+            fn eq(&self, other: &Type01Case1PropRunInitSequence) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropRunInitSequence {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropRunInitSequence {
+            fn partial_cmp(&self, other: &Type01Case1PropRunInitSequence) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropRunInitSequence {
+            fn cmp(&self, other: &Type01Case1PropRunInitSequence) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropLiftSet {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropLiftSet {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropLiftSet> for bool {
+            fn from(enum_value: Type01Case1PropLiftSet) -> Self {
+                match enum_value {
+                    Type01Case1PropLiftSet::False => false,
+                    Type01Case1PropLiftSet::True => true,
+                    Type01Case1PropLiftSet::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropLiftSet {
+            fn eq(&self, other: &Type01Case1PropLiftSet) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropLiftSet {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropLiftSet {
+            fn partial_cmp(&self, other: &Type01Case1PropLiftSet) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropLiftSet {
+            fn cmp(&self, other: &Type01Case1PropLiftSet) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropValveOpenMaintenance {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropValveOpenMaintenance {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropValveOpenMaintenance> for bool {
+            fn from(enum_value: Type01Case1PropValveOpenMaintenance) -> Self {
+                match enum_value {
+                    Type01Case1PropValveOpenMaintenance::False => false,
+                    Type01Case1PropValveOpenMaintenance::True => true,
+                    Type01Case1PropValveOpenMaintenance::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropValveOpenMaintenance {
+            fn eq(&self, other: &Type01Case1PropValveOpenMaintenance) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropValveOpenMaintenance {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropValveOpenMaintenance {
+            fn partial_cmp(&self, other: &Type01Case1PropValveOpenMaintenance) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropValveOpenMaintenance {
+            fn cmp(&self, other: &Type01Case1PropValveOpenMaintenance) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropValveClosed {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropValveClosed {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropValveClosed> for bool {
+            fn from(enum_value: Type01Case1PropValveClosed) -> Self {
+                match enum_value {
+                    Type01Case1PropValveClosed::False => false,
+                    Type01Case1PropValveClosed::True => true,
+                    Type01Case1PropValveClosed::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropValveClosed {
+            fn eq(&self, other: &Type01Case1PropValveClosed) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropValveClosed {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropValveClosed {
+            fn partial_cmp(&self, other: &Type01Case1PropValveClosed) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropValveClosed {
+            fn cmp(&self, other: &Type01Case1PropValveClosed) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropSummerBitReductionOfEnergyConsumption {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropSummerBitReductionOfEnergyConsumption {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropSummerBitReductionOfEnergyConsumption> for bool {
+            fn from(enum_value: Type01Case1PropSummerBitReductionOfEnergyConsumption) -> Self {
+                match enum_value {
+                    Type01Case1PropSummerBitReductionOfEnergyConsumption::False => false,
+                    Type01Case1PropSummerBitReductionOfEnergyConsumption::True => true,
+                    Type01Case1PropSummerBitReductionOfEnergyConsumption::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropSummerBitReductionOfEnergyConsumption {
+            fn eq(&self, other: &Type01Case1PropSummerBitReductionOfEnergyConsumption) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropSummerBitReductionOfEnergyConsumption {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropSummerBitReductionOfEnergyConsumption {
+            fn partial_cmp(&self, other: &Type01Case1PropSummerBitReductionOfEnergyConsumption) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropSummerBitReductionOfEnergyConsumption {
+            fn cmp(&self, other: &Type01Case1PropSummerBitReductionOfEnergyConsumption) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropSetPointSelection {
+            ValvePosition0Minus100,
+            TemperatureSetPoint0Minus40DegreesC,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropSetPointSelection {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::ValvePosition0Minus100,
+                    true => Self::TemperatureSetPoint0Minus40DegreesC,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropSetPointSelection> for bool {
+            fn from(enum_value: Type01Case1PropSetPointSelection) -> Self {
+                match enum_value {
+                    Type01Case1PropSetPointSelection::ValvePosition0Minus100 => false,
+                    Type01Case1PropSetPointSelection::TemperatureSetPoint0Minus40DegreesC => true,
+                    Type01Case1PropSetPointSelection::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropSetPointSelection {
+            fn eq(&self, other: &Type01Case1PropSetPointSelection) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropSetPointSelection {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropSetPointSelection {
+            fn partial_cmp(&self, other: &Type01Case1PropSetPointSelection) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropSetPointSelection {
+            fn cmp(&self, other: &Type01Case1PropSetPointSelection) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropSetPointInverse {
+            False,
+            True,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropSetPointInverse {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::False,
+                    true => Self::True,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropSetPointInverse> for bool {
+            fn from(enum_value: Type01Case1PropSetPointInverse) -> Self {
+                match enum_value {
+                    Type01Case1PropSetPointInverse::False => false,
+                    Type01Case1PropSetPointInverse::True => true,
+                    Type01Case1PropSetPointInverse::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropSetPointInverse {
+            fn eq(&self, other: &Type01Case1PropSetPointInverse) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropSetPointInverse {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropSetPointInverse {
+            fn partial_cmp(&self, other: &Type01Case1PropSetPointInverse) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropSetPointInverse {
+            fn cmp(&self, other: &Type01Case1PropSetPointInverse) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropSelectFunction {
+            Rcu,
+            ServiceOn,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropSelectFunction {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::Rcu,
+                    true => Self::ServiceOn,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropSelectFunction> for bool {
+            fn from(enum_value: Type01Case1PropSelectFunction) -> Self {
+                match enum_value {
+                    Type01Case1PropSelectFunction::Rcu => false,
+                    Type01Case1PropSelectFunction::ServiceOn => true,
+                    Type01Case1PropSelectFunction::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropSelectFunction {
+            fn eq(&self, other: &Type01Case1PropSelectFunction) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropSelectFunction {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropSelectFunction {
+            fn partial_cmp(&self, other: &Type01Case1PropSelectFunction) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropSelectFunction {
+            fn cmp(&self, other: &Type01Case1PropSelectFunction) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropLrnBit {
+            TeachInTelegram,
+            DataTelegram,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case1PropLrnBit {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::TeachInTelegram,
+                    true => Self::DataTelegram,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropLrnBit> for bool {
+            fn from(enum_value: Type01Case1PropLrnBit) -> Self {
+                match enum_value {
+                    Type01Case1PropLrnBit::TeachInTelegram => false,
+                    Type01Case1PropLrnBit::DataTelegram => true,
+                    Type01Case1PropLrnBit::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropLrnBit {
+            fn eq(&self, other: &Type01Case1PropLrnBit) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropLrnBit {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropLrnBit {
+            fn partial_cmp(&self, other: &Type01Case1PropLrnBit) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropLrnBit {
+            fn cmp(&self, other: &Type01Case1PropLrnBit) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+    }
+
+    #[cfg(feature = "eep_a5_20")]
+    #[derive(Clone, Copy, Debug)]
+    pub enum Func20<'b> {
+        Type01Case0(func20::Type01Case0<'b>),
+        Type01Case1(func20::Type01Case1<'b>),
+    }
+    #[cfg(feature = "eep_a5_20")]
+    impl<'b> Func20<'b> {
+        /// Returns the case of the type whose conditions the data fulfills.
+        pub fn type01_from_reversed_bytes(reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            let mut matcher = CaseMatcher::new();
+            let case = func20::Type01Case0::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type01Case0(case));
+            }
+            let case = func20::Type01Case1::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type01Case1(case));
+            }
+            matcher.finish()
+        }
+
+        pub fn from_reversed_bytes(type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            match type_code {
+                0x01 => Self::type01_from_reversed_bytes(reversed_bytes, opt_direction),
+                _ => Err(EepDecodeError::UnknownProfile),
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20"))]
+#[derive(Clone, Copy, Debug)]
+pub enum RorgA5<'b> {
+    #[cfg(feature = "eep_a5_02")]
+    Func02(rorgA5::Func02<'b>),
+    #[cfg(feature = "eep_a5_10")]
+    Func10(rorgA5::Func10<'b>),
+    #[cfg(feature = "eep_a5_20")]
+    Func20(rorgA5::Func20<'b>),
+}
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20"))]
+impl<'b> RorgA5<'b> {
+    pub fn from_reversed_bytes(func_code: u8, type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+        match func_code {
+            #[cfg(feature = "eep_a5_02")]
+            0x02 => rorgA5::Func02::from_reversed_bytes(type_code, reversed_bytes, opt_direction)
+                .map(Self::Func02),
+            #[cfg(feature = "eep_a5_10")]
+            0x10 => rorgA5::Func10::from_reversed_bytes(type_code, reversed_bytes, opt_direction)
+                .map(Self::Func10),
+            #[cfg(feature = "eep_a5_20")]
+            0x20 => rorgA5::Func20::from_reversed_bytes(type_code, reversed_bytes, opt_direction)
+                .map(Self::Func20),
+            _ => Err(EepDecodeError::UnknownProfile),
+        }
+    }
+}
+/// VLD Telegram (D2)
+#[cfg(feature = "eep_d2_01")]
+#[allow(non_snake_case)]
+pub mod rorgD2 {
+    #[allow(unused)] use super::{BitTwiddling, BitTwiddlingMut, CaseMatcher, EepDecodeError, EepDirection, div_round};
+    #[cfg(feature = "eep_float")]
+    #[allow(unused)] use super::{range_scale, round_to_raw, ScaledVariable};
+    /// Electronic switches and dimmers with Energy Measurement and Local Control (D2-01)
+    #[cfg(feature = "eep_d2_01")]
+    pub mod func01 {
+        #[allow(unused)] use super::{BitTwiddling, BitTwiddlingMut, EepDecodeError, EepDirection, div_round};
+        #[cfg(feature = "eep_float")]
+        #[allow(unused)] use super::{range_scale, round_to_raw, ScaledVariable};
+        /// Electronic switch with Local Control (D2-01-01), case 0
+        #[derive(Clone, Copy)]
+        pub struct Type01Case0<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type01Case0<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                self.reversed_bytes.u64_from_bits(4, 4) == Some(1)
+            }
+
+            /// Get the raw Dim value value.
+            pub fn get_dim_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(0, 3)
+            }
+            /// Get the Dim value value.
+            pub fn get_dim_value(&self) -> Option<Type01Case0PropDimValue> {
+                let raw_value = self.get_dim_value_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Command ID value.
+            pub fn get_command_id_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(4, 4)
+            }
+            /// Get the Command ID value.
+            pub fn get_command_id(&self) -> Option<Type01Case0PropCommandId> {
+                let raw_value = self.get_command_id_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw I/O channel value.
+            pub fn get_i_o_channel_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(11, 5)
+            }
+            /// Get the I/O channel value.
+            pub fn get_i_o_channel(&self) -> Option<Type01Case0PropIOChannel> {
+                let raw_value = self.get_i_o_channel_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Output value value.
+            pub fn get_output_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(17, 7)
+            }
+            /// Get the Output value value.
+            pub fn get_output_value(&self) -> Option<Type01Case0PropOutputValue> {
+                let raw_value = self.get_output_value_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type01Case0<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type01Case0");
+                debug.field("dim_value", &self.get_dim_value());
+                debug.field("command_id", &self.get_command_id());
+                debug.field("i_o_channel", &self.get_i_o_channel());
+                debug.field("output_value", &self.get_output_value());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Electronic switch with Local Control (D2-01-01), case 0
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type01Case0Builder {
+            reversed_bytes: [u8; 3],
+        }
+        impl Type01Case0Builder {
+            /// Creates a new builder with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x80, 0x00, 0x00] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 3] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 3] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type01Case0<'_> {
+                Type01Case0::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Dim value value.
+            ///
+            /// The value must not exceed 7.
+            pub fn set_dim_value_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 7, "raw value of Dim value out of range");
+                self.reversed_bytes.u128_to_bits(0, 3, raw_value as u128);
+                self
+            }
+            /// Set the Dim value value.
+            pub fn set_dim_value(self, value: Type01Case0PropDimValue) -> Self {
+                self.set_dim_value_raw(value.into())
+            }
+
+            /// Set the raw Command ID value.
+            ///
+            /// The value must not exceed 15.
+            pub fn set_command_id_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 15, "raw value of Command ID out of range");
+                self.reversed_bytes.u128_to_bits(4, 4, raw_value as u128);
+                self
+            }
+            /// Set the Command ID value.
+            pub fn set_command_id(self, value: Type01Case0PropCommandId) -> Self {
+                self.set_command_id_raw(value.into())
+            }
+
+            /// Set the raw I/O channel value.
+            ///
+            /// The value must not exceed 31.
+            pub fn set_i_o_channel_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 31, "raw value of I/O channel out of range");
+                self.reversed_bytes.u128_to_bits(11, 5, raw_value as u128);
+                self
+            }
+            /// Set the I/O channel value.
+            pub fn set_i_o_channel(self, value: Type01Case0PropIOChannel) -> Self {
+                self.set_i_o_channel_raw(value.into())
+            }
+
+            /// Set the raw Output value value.
+            ///
+            /// The value must not exceed 127.
+            pub fn set_output_value_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 127, "raw value of Output value out of range");
+                self.reversed_bytes.u128_to_bits(17, 7, raw_value as u128);
+                self
+            }
+            /// Set the Output value value.
+            pub fn set_output_value(self, value: Type01Case0PropOutputValue) -> Self {
+                self.set_output_value_raw(value.into())
+            }
+        }
+        impl Default for Type01Case0Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropDimValue {
+            SwitchToNewOutputValue,
+            DimToNewOutputValueDimTimer1,
+            DimToNewOutputValueDimTimer2,
+            DimToNewOutputValueDimTimer3,
+            StopDimming,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case0PropDimValue {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::SwitchToNewOutputValue,
+                    1 => Self::DimToNewOutputValueDimTimer1,
+                    2 => Self::DimToNewOutputValueDimTimer2,
+                    3 => Self::DimToNewOutputValueDimTimer3,
+                    4 => Self::StopDimming,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropDimValue> for u8 {
+            fn from(enum_value: Type01Case0PropDimValue) -> Self {
+                match enum_value {
+                    Type01Case0PropDimValue::SwitchToNewOutputValue => 0,
+                    Type01Case0PropDimValue::DimToNewOutputValueDimTimer1 => 1,
+                    Type01Case0PropDimValue::DimToNewOutputValueDimTimer2 => 2,
+                    Type01Case0PropDimValue::DimToNewOutputValueDimTimer3 => 3,
+                    Type01Case0PropDimValue::StopDimming => 4,
+                    Type01Case0PropDimValue::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropDimValue {
+            fn eq(&self, other: &Type01Case0PropDimValue) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropDimValue {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropDimValue {
+            fn partial_cmp(&self, other: &Type01Case0PropDimValue) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropDimValue {
+            fn cmp(&self, other: &Type01Case0PropDimValue) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropCommandId {
+            Id01,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case0PropCommandId {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    1 => Self::Id01,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropCommandId> for u8 {
+            fn from(enum_value: Type01Case0PropCommandId) -> Self {
+                match enum_value {
+                    Type01Case0PropCommandId::Id01 => 1,
+                    Type01Case0PropCommandId::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropCommandId {
+            fn eq(&self, other: &Type01Case0PropCommandId) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropCommandId {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropCommandId {
+            fn partial_cmp(&self, other: &Type01Case0PropCommandId) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropCommandId {
+            fn cmp(&self, other: &Type01Case0PropCommandId) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropIOChannel {
+            OutputChannel {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            AllOutputChannelsSupportedByTheDevice,
+            InputChannel,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case0PropIOChannel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0..=29 => Self::OutputChannel {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 0.0, 29.0, 0.0, 29.0),
+                    },
+                    30 => Self::AllOutputChannelsSupportedByTheDevice,
+                    31 => Self::InputChannel,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropIOChannel> for u8 {
+            fn from(enum_value: Type01Case0PropIOChannel) -> Self {
+                match enum_value {
+                    Type01Case0PropIOChannel::OutputChannel { raw, .. } => raw,
+                    Type01Case0PropIOChannel::AllOutputChannelsSupportedByTheDevice => 30,
+                    Type01Case0PropIOChannel::InputChannel => 31,
+                    Type01Case0PropIOChannel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropIOChannel {
+            fn eq(&self, other: &Type01Case0PropIOChannel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropIOChannel {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropIOChannel {
+            fn partial_cmp(&self, other: &Type01Case0PropIOChannel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropIOChannel {
+            fn cmp(&self, other: &Type01Case0PropIOChannel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case0PropOutputValue {
+            OutputValue0OrOff,
+            OutputValue1To100OrOn {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            OutputValueNotValidOrNotSet,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case0PropOutputValue {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::OutputValue0OrOff,
+                    1..=100 => Self::OutputValue1To100OrOn {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 1.0, 100.0, 1.0, 100.0),
+                    },
+                    127 => Self::OutputValueNotValidOrNotSet,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case0PropOutputValue> for u8 {
+            fn from(enum_value: Type01Case0PropOutputValue) -> Self {
+                match enum_value {
+                    Type01Case0PropOutputValue::OutputValue0OrOff => 0,
+                    Type01Case0PropOutputValue::OutputValue1To100OrOn { raw, .. } => raw,
+                    Type01Case0PropOutputValue::OutputValueNotValidOrNotSet => 127,
+                    Type01Case0PropOutputValue::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case0PropOutputValue {
+            fn eq(&self, other: &Type01Case0PropOutputValue) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case0PropOutputValue {}
+        impl ::core::cmp::PartialOrd for Type01Case0PropOutputValue {
+            fn partial_cmp(&self, other: &Type01Case0PropOutputValue) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case0PropOutputValue {
+            fn cmp(&self, other: &Type01Case0PropOutputValue) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        /// Electronic switch with Local Control (D2-01-01), case 1
+        #[derive(Clone, Copy)]
+        pub struct Type01Case1<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type01Case1<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                self.reversed_bytes.u64_from_bits(4, 4) == Some(3)
+            }
+
+            /// Get the raw Command ID value.
+            pub fn get_command_id_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(4, 4)
+            }
+            /// Get the Command ID value.
+            pub fn get_command_id(&self) -> Option<Type01Case1PropCommandId> {
+                let raw_value = self.get_command_id_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw I/O channel value.
+            pub fn get_i_o_channel_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(11, 5)
+            }
+            /// Get the I/O channel value.
+            pub fn get_i_o_channel(&self) -> Option<Type01Case1PropIOChannel> {
+                let raw_value = self.get_i_o_channel_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type01Case1<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type01Case1");
+                debug.field("command_id", &self.get_command_id());
+                debug.field("i_o_channel", &self.get_i_o_channel());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Electronic switch with Local Control (D2-01-01), case 1
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type01Case1Builder {
+            reversed_bytes: [u8; 2],
+        }
+        impl Type01Case1Builder {
+            /// Creates a new builder with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0xC0, 0x00] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 2] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 2] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type01Case1<'_> {
+                Type01Case1::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Command ID value.
+            ///
+            /// The value must not exceed 15.
+            pub fn set_command_id_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 15, "raw value of Command ID out of range");
+                self.reversed_bytes.u128_to_bits(4, 4, raw_value as u128);
+                self
+            }
+            /// Set the Command ID value.
+            pub fn set_command_id(self, value: Type01Case1PropCommandId) -> Self {
+                self.set_command_id_raw(value.into())
+            }
+
+            /// Set the raw I/O channel value.
+            ///
+            /// The value must not exceed 31.
+            pub fn set_i_o_channel_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 31, "raw value of I/O channel out of range");
+                self.reversed_bytes.u128_to_bits(11, 5, raw_value as u128);
+                self
+            }
+            /// Set the I/O channel value.
+            pub fn set_i_o_channel(self, value: Type01Case1PropIOChannel) -> Self {
+                self.set_i_o_channel_raw(value.into())
+            }
+        }
+        impl Default for Type01Case1Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropCommandId {
+            Id03,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case1PropCommandId {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    3 => Self::Id03,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropCommandId> for u8 {
+            fn from(enum_value: Type01Case1PropCommandId) -> Self {
+                match enum_value {
+                    Type01Case1PropCommandId::Id03 => 3,
+                    Type01Case1PropCommandId::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropCommandId {
+            fn eq(&self, other: &Type01Case1PropCommandId) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropCommandId {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropCommandId {
+            fn partial_cmp(&self, other: &Type01Case1PropCommandId) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropCommandId {
+            fn cmp(&self, other: &Type01Case1PropCommandId) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case1PropIOChannel {
+            OutputChannel {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            AllOutputChannelsSupportedByTheDevice,
+            InputChannel,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case1PropIOChannel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0..=29 => Self::OutputChannel {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 0.0, 29.0, 0.0, 29.0),
+                    },
+                    30 => Self::AllOutputChannelsSupportedByTheDevice,
+                    31 => Self::InputChannel,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case1PropIOChannel> for u8 {
+            fn from(enum_value: Type01Case1PropIOChannel) -> Self {
+                match enum_value {
+                    Type01Case1PropIOChannel::OutputChannel { raw, .. } => raw,
+                    Type01Case1PropIOChannel::AllOutputChannelsSupportedByTheDevice => 30,
+                    Type01Case1PropIOChannel::InputChannel => 31,
+                    Type01Case1PropIOChannel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case1PropIOChannel {
+            fn eq(&self, other: &Type01Case1PropIOChannel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case1PropIOChannel {}
+        impl ::core::cmp::PartialOrd for Type01Case1PropIOChannel {
+            fn partial_cmp(&self, other: &Type01Case1PropIOChannel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case1PropIOChannel {
+            fn cmp(&self, other: &Type01Case1PropIOChannel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        /// Electronic switch with Local Control (D2-01-01), case 2
+        #[derive(Clone, Copy)]
+        pub struct Type01Case2<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type01Case2<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                self.reversed_bytes.u64_from_bits(4, 4) == Some(4)
+            }
+
+            /// Get the raw Power Failure value.
+            pub fn get_power_failure_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(0, 1)
+            }
+            /// Get the Power Failure value.
+            pub fn get_power_failure(&self) -> Option<Type01Case2PropPowerFailure> {
+                let raw_value = self.get_power_failure_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Power Failure Detection value.
+            pub fn get_power_failure_detection_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(1, 1)
+            }
+            /// Get the Power Failure Detection value.
+            pub fn get_power_failure_detection(&self) -> Option<Type01Case2PropPowerFailureDetection> {
+                let raw_value = self.get_power_failure_detection_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Command ID value.
+            pub fn get_command_id_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(4, 4)
+            }
+            /// Get the Command ID value.
+            pub fn get_command_id(&self) -> Option<Type01Case2PropCommandId> {
+                let raw_value = self.get_command_id_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Over current switch off value.
+            pub fn get_over_current_switch_off_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(8, 1)
+            }
+            /// Get the Over current switch off value.
+            pub fn get_over_current_switch_off(&self) -> Option<Type01Case2PropOverCurrentSwitchOff> {
+                let raw_value = self.get_over_current_switch_off_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Error level value.
+            pub fn get_error_level_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(9, 2)
+            }
+            /// Get the Error level value.
+            pub fn get_error_level(&self) -> Option<Type01Case2PropErrorLevel> {
+                let raw_value = self.get_error_level_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw I/O channel value.
+            pub fn get_i_o_channel_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(11, 5)
+            }
+            /// Get the I/O channel value.
+            pub fn get_i_o_channel(&self) -> Option<Type01Case2PropIOChannel> {
+                let raw_value = self.get_i_o_channel_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Local control value.
+            pub fn get_local_control_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(16, 1)
+            }
+            /// Get the Local control value.
+            pub fn get_local_control(&self) -> Option<Type01Case2PropLocalControl> {
+                let raw_value = self.get_local_control_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Output value value.
+            pub fn get_output_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(17, 7)
+            }
+            /// Get the Output value value.
+            pub fn get_output_value(&self) -> Option<Type01Case2PropOutputValue> {
+                let raw_value = self.get_output_value_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type01Case2<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type01Case2");
+                debug.field("power_failure", &self.get_power_failure());
+                debug.field("power_failure_detection", &self.get_power_failure_detection());
+                debug.field("command_id", &self.get_command_id());
+                debug.field("over_current_switch_off", &self.get_over_current_switch_off());
+                debug.field("error_level", &self.get_error_level());
+                debug.field("i_o_channel", &self.get_i_o_channel());
+                debug.field("local_control", &self.get_local_control());
+                debug.field("output_value", &self.get_output_value());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Electronic switch with Local Control (D2-01-01), case 2
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type01Case2Builder {
+            reversed_bytes: [u8; 3],
+        }
+        impl Type01Case2Builder {
+            /// Creates a new builder with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x20, 0x00, 0x00] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 3] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 3] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type01Case2<'_> {
+                Type01Case2::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Power Failure value.
+            pub fn set_power_failure_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(0, 1, raw_value as u128);
+                self
+            }
+            /// Set the Power Failure value.
+            pub fn set_power_failure(self, value: Type01Case2PropPowerFailure) -> Self {
+                self.set_power_failure_raw(value.into())
+            }
+
+            /// Set the raw Power Failure Detection value.
+            pub fn set_power_failure_detection_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(1, 1, raw_value as u128);
+                self
+            }
+            /// Set the Power Failure Detection value.
+            pub fn set_power_failure_detection(self, value: Type01Case2PropPowerFailureDetection) -> Self {
+                self.set_power_failure_detection_raw(value.into())
+            }
+
+            /// Set the raw Command ID value.
+            ///
+            /// The value must not exceed 15.
+            pub fn set_command_id_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 15, "raw value of Command ID out of range");
+                self.reversed_bytes.u128_to_bits(4, 4, raw_value as u128);
+                self
+            }
+            /// Set the Command ID value.
+            pub fn set_command_id(self, value: Type01Case2PropCommandId) -> Self {
+                self.set_command_id_raw(value.into())
+            }
+
+            /// Set the raw Over current switch off value.
+            pub fn set_over_current_switch_off_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(8, 1, raw_value as u128);
+                self
+            }
+            /// Set the Over current switch off value.
+            pub fn set_over_current_switch_off(self, value: Type01Case2PropOverCurrentSwitchOff) -> Self {
+                self.set_over_current_switch_off_raw(value.into())
+            }
+
+            /// Set the raw Error level value.
+            ///
+            /// The value must not exceed 3.
+            pub fn set_error_level_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 3, "raw value of Error level out of range");
+                self.reversed_bytes.u128_to_bits(9, 2, raw_value as u128);
+                self
+            }
+            /// Set the Error level value.
+            pub fn set_error_level(self, value: Type01Case2PropErrorLevel) -> Self {
+                self.set_error_level_raw(value.into())
+            }
+
+            /// Set the raw I/O channel value.
+            ///
+            /// The value must not exceed 31.
+            pub fn set_i_o_channel_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 31, "raw value of I/O channel out of range");
+                self.reversed_bytes.u128_to_bits(11, 5, raw_value as u128);
+                self
+            }
+            /// Set the I/O channel value.
+            pub fn set_i_o_channel(self, value: Type01Case2PropIOChannel) -> Self {
+                self.set_i_o_channel_raw(value.into())
+            }
+
+            /// Set the raw Local control value.
+            pub fn set_local_control_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(16, 1, raw_value as u128);
+                self
+            }
+            /// Set the Local control value.
+            pub fn set_local_control(self, value: Type01Case2PropLocalControl) -> Self {
+                self.set_local_control_raw(value.into())
+            }
+
+            /// Set the raw Output value value.
+            ///
+            /// The value must not exceed 127.
+            pub fn set_output_value_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 127, "raw value of Output value out of range");
+                self.reversed_bytes.u128_to_bits(17, 7, raw_value as u128);
+                self
+            }
+            /// Set the Output value value.
+            pub fn set_output_value(self, value: Type01Case2PropOutputValue) -> Self {
+                self.set_output_value_raw(value.into())
+            }
+        }
+        impl Default for Type01Case2Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropPowerFailure {
+            PowerFailureDisabled,
+            PowerFailureEnabled,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case2PropPowerFailure {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::PowerFailureDisabled,
+                    true => Self::PowerFailureEnabled,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropPowerFailure> for bool {
+            fn from(enum_value: Type01Case2PropPowerFailure) -> Self {
+                match enum_value {
+                    Type01Case2PropPowerFailure::PowerFailureDisabled => false,
+                    Type01Case2PropPowerFailure::PowerFailureEnabled => true,
+                    Type01Case2PropPowerFailure::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropPowerFailure {
+            fn eq(&self, other: &Type01Case2PropPowerFailure) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropPowerFailure {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropPowerFailure {
+            fn partial_cmp(&self, other: &Type01Case2PropPowerFailure) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropPowerFailure {
+            fn cmp(&self, other: &Type01Case2PropPowerFailure) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropPowerFailureDetection {
+            PowerFailureNotDetected,
+            PowerFailureDetected,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case2PropPowerFailureDetection {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::PowerFailureNotDetected,
+                    true => Self::PowerFailureDetected,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropPowerFailureDetection> for bool {
+            fn from(enum_value: Type01Case2PropPowerFailureDetection) -> Self {
+                match enum_value {
+                    Type01Case2PropPowerFailureDetection::PowerFailureNotDetected => false,
+                    Type01Case2PropPowerFailureDetection::PowerFailureDetected => true,
+                    Type01Case2PropPowerFailureDetection::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropPowerFailureDetection {
+            fn eq(&self, other: &Type01Case2PropPowerFailureDetection) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropPowerFailureDetection {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropPowerFailureDetection {
+            fn partial_cmp(&self, other: &Type01Case2PropPowerFailureDetection) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropPowerFailureDetection {
+            fn cmp(&self, other: &Type01Case2PropPowerFailureDetection) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropCommandId {
+            Id04,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case2PropCommandId {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    4 => Self::Id04,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropCommandId> for u8 {
+            fn from(enum_value: Type01Case2PropCommandId) -> Self {
+                match enum_value {
+                    Type01Case2PropCommandId::Id04 => 4,
+                    Type01Case2PropCommandId::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropCommandId {
+            fn eq(&self, other: &Type01Case2PropCommandId) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropCommandId {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropCommandId {
+            fn partial_cmp(&self, other: &Type01Case2PropCommandId) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropCommandId {
+            fn cmp(&self, other: &Type01Case2PropCommandId) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropOverCurrentSwitchOff {
+            OverCurrentSwitchOffReady,
+            OverCurrentSwitchOffExecuted,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case2PropOverCurrentSwitchOff {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::OverCurrentSwitchOffReady,
+                    true => Self::OverCurrentSwitchOffExecuted,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropOverCurrentSwitchOff> for bool {
+            fn from(enum_value: Type01Case2PropOverCurrentSwitchOff) -> Self {
+                match enum_value {
+                    Type01Case2PropOverCurrentSwitchOff::OverCurrentSwitchOffReady => false,
+                    Type01Case2PropOverCurrentSwitchOff::OverCurrentSwitchOffExecuted => true,
+                    Type01Case2PropOverCurrentSwitchOff::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropOverCurrentSwitchOff {
+            fn eq(&self, other: &Type01Case2PropOverCurrentSwitchOff) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropOverCurrentSwitchOff {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropOverCurrentSwitchOff {
+            fn partial_cmp(&self, other: &Type01Case2PropOverCurrentSwitchOff) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropOverCurrentSwitchOff {
+            fn cmp(&self, other: &Type01Case2PropOverCurrentSwitchOff) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropErrorLevel {
+            ErrorLevel0HardwareOk,
+            ErrorLevel1HardwareWarning,
+            ErrorLevel2HardwareFailure,
+            ErrorLevelNotSupported,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case2PropErrorLevel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::ErrorLevel0HardwareOk,
+                    1 => Self::ErrorLevel1HardwareWarning,
+                    2 => Self::ErrorLevel2HardwareFailure,
+                    3 => Self::ErrorLevelNotSupported,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropErrorLevel> for u8 {
+            fn from(enum_value: Type01Case2PropErrorLevel) -> Self {
+                match enum_value {
+                    Type01Case2PropErrorLevel::ErrorLevel0HardwareOk => 0,
+                    Type01Case2PropErrorLevel::ErrorLevel1HardwareWarning => 1,
+                    Type01Case2PropErrorLevel::ErrorLevel2HardwareFailure => 2,
+                    Type01Case2PropErrorLevel::ErrorLevelNotSupported => 3,
+                    Type01Case2PropErrorLevel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropErrorLevel {
+            fn eq(&self, other: &Type01Case2PropErrorLevel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropErrorLevel {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropErrorLevel {
+            fn partial_cmp(&self, other: &Type01Case2PropErrorLevel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropErrorLevel {
+            fn cmp(&self, other: &Type01Case2PropErrorLevel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropIOChannel {
+            OutputChannel {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            NotUsed,
+            InputChannel,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case2PropIOChannel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0..=29 => Self::OutputChannel {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 0.0, 29.0, 0.0, 29.0),
+                    },
+                    30 => Self::NotUsed,
+                    31 => Self::InputChannel,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropIOChannel> for u8 {
+            fn from(enum_value: Type01Case2PropIOChannel) -> Self {
+                match enum_value {
+                    Type01Case2PropIOChannel::OutputChannel { raw, .. } => raw,
+                    Type01Case2PropIOChannel::NotUsed => 30,
+                    Type01Case2PropIOChannel::InputChannel => 31,
+                    Type01Case2PropIOChannel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropIOChannel {
+            fn eq(&self, other: &Type01Case2PropIOChannel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropIOChannel {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropIOChannel {
+            fn partial_cmp(&self, other: &Type01Case2PropIOChannel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropIOChannel {
+            fn cmp(&self, other: &Type01Case2PropIOChannel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropLocalControl {
+            LocalControlDisabledNotSupported,
+            LocalControlEnabled,
+            _Other(bool),
+        }
+        impl From<bool> for Type01Case2PropLocalControl {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::LocalControlDisabledNotSupported,
+                    true => Self::LocalControlEnabled,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropLocalControl> for bool {
+            fn from(enum_value: Type01Case2PropLocalControl) -> Self {
+                match enum_value {
+                    Type01Case2PropLocalControl::LocalControlDisabledNotSupported => false,
+                    Type01Case2PropLocalControl::LocalControlEnabled => true,
+                    Type01Case2PropLocalControl::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropLocalControl {
+            fn eq(&self, other: &Type01Case2PropLocalControl) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropLocalControl {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropLocalControl {
+            fn partial_cmp(&self, other: &Type01Case2PropLocalControl) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropLocalControl {
+            fn cmp(&self, other: &Type01Case2PropLocalControl) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type01Case2PropOutputValue {
+            OutputValue0OrOff,
+            OutputValue1To100OrOn {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            OutputValueNotValidOrNotSet,
+            _Other(u8),
+        }
+        impl From<u8> for Type01Case2PropOutputValue {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::OutputValue0OrOff,
+                    1..=100 => Self::OutputValue1To100OrOn {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 1.0, 100.0, 1.0, 100.0),
+                    },
+                    127 => Self::OutputValueNotValidOrNotSet,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type01Case2PropOutputValue> for u8 {
+            fn from(enum_value: Type01Case2PropOutputValue) -> Self {
+                match enum_value {
+                    Type01Case2PropOutputValue::OutputValue0OrOff => 0,
+                    Type01Case2PropOutputValue::OutputValue1To100OrOn { raw, .. } => raw,
+                    Type01Case2PropOutputValue::OutputValueNotValidOrNotSet => 127,
+                    Type01Case2PropOutputValue::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type01Case2PropOutputValue {
+            fn eq(&self, other: &Type01Case2PropOutputValue) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type01Case2PropOutputValue {}
+        impl ::core::cmp::PartialOrd for Type01Case2PropOutputValue {
+            fn partial_cmp(&self, other: &Type01Case2PropOutputValue) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type01Case2PropOutputValue {
+            fn cmp(&self, other: &Type01Case2PropOutputValue) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        /// Slot-in module with 2 channels (D2-01-12), case 0
+        #[derive(Clone, Copy)]
+        pub struct Type12Case0<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type12Case0<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                self.reversed_bytes.u64_from_bits(4, 4) == Some(1)
+            }
+
+            /// Get the raw Dim value value.
+            pub fn get_dim_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(0, 3)
+            }
+            /// Get the Dim value value.
+            pub fn get_dim_value(&self) -> Option<Type12Case0PropDimValue> {
+                let raw_value = self.get_dim_value_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Command ID value.
+            pub fn get_command_id_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(4, 4)
+            }
+            /// Get the Command ID value.
+            pub fn get_command_id(&self) -> Option<Type12Case0PropCommandId> {
+                let raw_value = self.get_command_id_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw I/O channel value.
+            pub fn get_i_o_channel_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(11, 5)
+            }
+            /// Get the I/O channel value.
+            pub fn get_i_o_channel(&self) -> Option<Type12Case0PropIOChannel> {
+                let raw_value = self.get_i_o_channel_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Output value value.
+            pub fn get_output_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(17, 7)
+            }
+            /// Get the Output value value.
+            pub fn get_output_value(&self) -> Option<Type12Case0PropOutputValue> {
+                let raw_value = self.get_output_value_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type12Case0<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type12Case0");
+                debug.field("dim_value", &self.get_dim_value());
+                debug.field("command_id", &self.get_command_id());
+                debug.field("i_o_channel", &self.get_i_o_channel());
+                debug.field("output_value", &self.get_output_value());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Slot-in module with 2 channels (D2-01-12), case 0
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type12Case0Builder {
+            reversed_bytes: [u8; 3],
+        }
+        impl Type12Case0Builder {
+            /// Creates a new builder with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x80, 0x00, 0x00] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 3] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 3] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type12Case0<'_> {
+                Type12Case0::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Dim value value.
+            ///
+            /// The value must not exceed 7.
+            pub fn set_dim_value_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 7, "raw value of Dim value out of range");
+                self.reversed_bytes.u128_to_bits(0, 3, raw_value as u128);
+                self
+            }
+            /// Set the Dim value value.
+            pub fn set_dim_value(self, value: Type12Case0PropDimValue) -> Self {
+                self.set_dim_value_raw(value.into())
+            }
+
+            /// Set the raw Command ID value.
+            ///
+            /// The value must not exceed 15.
+            pub fn set_command_id_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 15, "raw value of Command ID out of range");
+                self.reversed_bytes.u128_to_bits(4, 4, raw_value as u128);
+                self
+            }
+            /// Set the Command ID value.
+            pub fn set_command_id(self, value: Type12Case0PropCommandId) -> Self {
+                self.set_command_id_raw(value.into())
+            }
+
+            /// Set the raw I/O channel value.
+            ///
+            /// The value must not exceed 31.
+            pub fn set_i_o_channel_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 31, "raw value of I/O channel out of range");
+                self.reversed_bytes.u128_to_bits(11, 5, raw_value as u128);
+                self
+            }
+            /// Set the I/O channel value.
+            pub fn set_i_o_channel(self, value: Type12Case0PropIOChannel) -> Self {
+                self.set_i_o_channel_raw(value.into())
+            }
+
+            /// Set the raw Output value value.
+            ///
+            /// The value must not exceed 127.
+            pub fn set_output_value_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 127, "raw value of Output value out of range");
+                self.reversed_bytes.u128_to_bits(17, 7, raw_value as u128);
+                self
+            }
+            /// Set the Output value value.
+            pub fn set_output_value(self, value: Type12Case0PropOutputValue) -> Self {
+                self.set_output_value_raw(value.into())
+            }
+        }
+        impl Default for Type12Case0Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case0PropDimValue {
+            SwitchToNewOutputValue,
+            DimToNewOutputValueDimTimer1,
+            DimToNewOutputValueDimTimer2,
+            DimToNewOutputValueDimTimer3,
+            StopDimming,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case0PropDimValue {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::SwitchToNewOutputValue,
+                    1 => Self::DimToNewOutputValueDimTimer1,
+                    2 => Self::DimToNewOutputValueDimTimer2,
+                    3 => Self::DimToNewOutputValueDimTimer3,
+                    4 => Self::StopDimming,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case0PropDimValue> for u8 {
+            fn from(enum_value: Type12Case0PropDimValue) -> Self {
+                match enum_value {
+                    Type12Case0PropDimValue::SwitchToNewOutputValue => 0,
+                    Type12Case0PropDimValue::DimToNewOutputValueDimTimer1 => 1,
+                    Type12Case0PropDimValue::DimToNewOutputValueDimTimer2 => 2,
+                    Type12Case0PropDimValue::DimToNewOutputValueDimTimer3 => 3,
+                    Type12Case0PropDimValue::StopDimming => 4,
+                    Type12Case0PropDimValue::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case0PropDimValue {
+            fn eq(&self, other: &Type12Case0PropDimValue) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case0PropDimValue {}
+        impl ::core::cmp::PartialOrd for Type12Case0PropDimValue {
+            fn partial_cmp(&self, other: &Type12Case0PropDimValue) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case0PropDimValue {
+            fn cmp(&self, other: &Type12Case0PropDimValue) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case0PropCommandId {
+            Id01,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case0PropCommandId {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    1 => Self::Id01,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case0PropCommandId> for u8 {
+            fn from(enum_value: Type12Case0PropCommandId) -> Self {
+                match enum_value {
+                    Type12Case0PropCommandId::Id01 => 1,
+                    Type12Case0PropCommandId::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case0PropCommandId {
+            fn eq(&self, other: &Type12Case0PropCommandId) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case0PropCommandId {}
+        impl ::core::cmp::PartialOrd for Type12Case0PropCommandId {
+            fn partial_cmp(&self, other: &Type12Case0PropCommandId) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case0PropCommandId {
+            fn cmp(&self, other: &Type12Case0PropCommandId) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case0PropIOChannel {
+            OutputChannel {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            AllOutputChannelsSupportedByTheDevice,
+            InputChannel,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case0PropIOChannel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0..=29 => Self::OutputChannel {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 0.0, 29.0, 0.0, 29.0),
+                    },
+                    30 => Self::AllOutputChannelsSupportedByTheDevice,
+                    31 => Self::InputChannel,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case0PropIOChannel> for u8 {
+            fn from(enum_value: Type12Case0PropIOChannel) -> Self {
+                match enum_value {
+                    Type12Case0PropIOChannel::OutputChannel { raw, .. } => raw,
+                    Type12Case0PropIOChannel::AllOutputChannelsSupportedByTheDevice => 30,
+                    Type12Case0PropIOChannel::InputChannel => 31,
+                    Type12Case0PropIOChannel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case0PropIOChannel {
+            fn eq(&self, other: &Type12Case0PropIOChannel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case0PropIOChannel {}
+        impl ::core::cmp::PartialOrd for Type12Case0PropIOChannel {
+            fn partial_cmp(&self, other: &Type12Case0PropIOChannel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case0PropIOChannel {
+            fn cmp(&self, other: &Type12Case0PropIOChannel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case0PropOutputValue {
+            OutputValue0OrOff,
+            OutputValue1To100OrOn {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            OutputValueNotValidOrNotSet,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case0PropOutputValue {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::OutputValue0OrOff,
+                    1..=100 => Self::OutputValue1To100OrOn {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 1.0, 100.0, 1.0, 100.0),
+                    },
+                    127 => Self::OutputValueNotValidOrNotSet,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case0PropOutputValue> for u8 {
+            fn from(enum_value: Type12Case0PropOutputValue) -> Self {
+                match enum_value {
+                    Type12Case0PropOutputValue::OutputValue0OrOff => 0,
+                    Type12Case0PropOutputValue::OutputValue1To100OrOn { raw, .. } => raw,
+                    Type12Case0PropOutputValue::OutputValueNotValidOrNotSet => 127,
+                    Type12Case0PropOutputValue::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case0PropOutputValue {
+            fn eq(&self, other: &Type12Case0PropOutputValue) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case0PropOutputValue {}
+        impl ::core::cmp::PartialOrd for Type12Case0PropOutputValue {
+            fn partial_cmp(&self, other: &Type12Case0PropOutputValue) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case0PropOutputValue {
+            fn cmp(&self, other: &Type12Case0PropOutputValue) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        /// Slot-in module with 2 channels (D2-01-12), case 1
+        #[derive(Clone, Copy)]
+        pub struct Type12Case1<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type12Case1<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                self.reversed_bytes.u64_from_bits(4, 4) == Some(3)
+            }
+
+            /// Get the raw Command ID value.
+            pub fn get_command_id_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(4, 4)
+            }
+            /// Get the Command ID value.
+            pub fn get_command_id(&self) -> Option<Type12Case1PropCommandId> {
+                let raw_value = self.get_command_id_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw I/O channel value.
+            pub fn get_i_o_channel_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(11, 5)
+            }
+            /// Get the I/O channel value.
+            pub fn get_i_o_channel(&self) -> Option<Type12Case1PropIOChannel> {
+                let raw_value = self.get_i_o_channel_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type12Case1<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type12Case1");
+                debug.field("command_id", &self.get_command_id());
+                debug.field("i_o_channel", &self.get_i_o_channel());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Slot-in module with 2 channels (D2-01-12), case 1
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type12Case1Builder {
+            reversed_bytes: [u8; 2],
+        }
+        impl Type12Case1Builder {
+            /// Creates a new builder with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0xC0, 0x00] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 2] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 2] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type12Case1<'_> {
+                Type12Case1::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Command ID value.
+            ///
+            /// The value must not exceed 15.
+            pub fn set_command_id_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 15, "raw value of Command ID out of range");
+                self.reversed_bytes.u128_to_bits(4, 4, raw_value as u128);
+                self
+            }
+            /// Set the Command ID value.
+            pub fn set_command_id(self, value: Type12Case1PropCommandId) -> Self {
+                self.set_command_id_raw(value.into())
+            }
+
+            /// Set the raw I/O channel value.
+            ///
+            /// The value must not exceed 31.
+            pub fn set_i_o_channel_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 31, "raw value of I/O channel out of range");
+                self.reversed_bytes.u128_to_bits(11, 5, raw_value as u128);
+                self
+            }
+            /// Set the I/O channel value.
+            pub fn set_i_o_channel(self, value: Type12Case1PropIOChannel) -> Self {
+                self.set_i_o_channel_raw(value.into())
+            }
+        }
+        impl Default for Type12Case1Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case1PropCommandId {
+            Id03,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case1PropCommandId {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    3 => Self::Id03,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case1PropCommandId> for u8 {
+            fn from(enum_value: Type12Case1PropCommandId) -> Self {
+                match enum_value {
+                    Type12Case1PropCommandId::Id03 => 3,
+                    Type12Case1PropCommandId::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case1PropCommandId {
+            fn eq(&self, other: &Type12Case1PropCommandId) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case1PropCommandId {}
+        impl ::core::cmp::PartialOrd for Type12Case1PropCommandId {
+            fn partial_cmp(&self, other: &Type12Case1PropCommandId) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case1PropCommandId {
+            fn cmp(&self, other: &Type12Case1PropCommandId) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case1PropIOChannel {
+            OutputChannel {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            AllOutputChannelsSupportedByTheDevice,
+            InputChannel,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case1PropIOChannel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0..=29 => Self::OutputChannel {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 0.0, 29.0, 0.0, 29.0),
+                    },
+                    30 => Self::AllOutputChannelsSupportedByTheDevice,
+                    31 => Self::InputChannel,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case1PropIOChannel> for u8 {
+            fn from(enum_value: Type12Case1PropIOChannel) -> Self {
+                match enum_value {
+                    Type12Case1PropIOChannel::OutputChannel { raw, .. } => raw,
+                    Type12Case1PropIOChannel::AllOutputChannelsSupportedByTheDevice => 30,
+                    Type12Case1PropIOChannel::InputChannel => 31,
+                    Type12Case1PropIOChannel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case1PropIOChannel {
+            fn eq(&self, other: &Type12Case1PropIOChannel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case1PropIOChannel {}
+        impl ::core::cmp::PartialOrd for Type12Case1PropIOChannel {
+            fn partial_cmp(&self, other: &Type12Case1PropIOChannel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case1PropIOChannel {
+            fn cmp(&self, other: &Type12Case1PropIOChannel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        /// Slot-in module with 2 channels (D2-01-12), case 2
+        #[derive(Clone, Copy)]
+        pub struct Type12Case2<'b> {
+            reversed_bytes: &'b [u8],
+        }
+        impl<'b> Type12Case2<'b> {
+            pub fn new(reversed_bytes: &'b [u8]) -> Self {
+                Self { reversed_bytes }
+            }
+
+            /// Whether the data fulfills the conditions under which this case applies.
+            pub fn matches_condition(&self, _opt_direction: Option<EepDirection>) -> bool {
+                self.reversed_bytes.u64_from_bits(4, 4) == Some(4)
+            }
+
+            /// Get the raw Power Failure value.
+            pub fn get_power_failure_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(0, 1)
+            }
+            /// Get the Power Failure value.
+            pub fn get_power_failure(&self) -> Option<Type12Case2PropPowerFailure> {
+                let raw_value = self.get_power_failure_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Power Failure Detection value.
+            pub fn get_power_failure_detection_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(1, 1)
+            }
+            /// Get the Power Failure Detection value.
+            pub fn get_power_failure_detection(&self) -> Option<Type12Case2PropPowerFailureDetection> {
+                let raw_value = self.get_power_failure_detection_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Command ID value.
+            pub fn get_command_id_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(4, 4)
+            }
+            /// Get the Command ID value.
+            pub fn get_command_id(&self) -> Option<Type12Case2PropCommandId> {
+                let raw_value = self.get_command_id_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Over current switch off value.
+            pub fn get_over_current_switch_off_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(8, 1)
+            }
+            /// Get the Over current switch off value.
+            pub fn get_over_current_switch_off(&self) -> Option<Type12Case2PropOverCurrentSwitchOff> {
+                let raw_value = self.get_over_current_switch_off_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Error level value.
+            pub fn get_error_level_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(9, 2)
+            }
+            /// Get the Error level value.
+            pub fn get_error_level(&self) -> Option<Type12Case2PropErrorLevel> {
+                let raw_value = self.get_error_level_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw I/O channel value.
+            pub fn get_i_o_channel_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(11, 5)
+            }
+            /// Get the I/O channel value.
+            pub fn get_i_o_channel(&self) -> Option<Type12Case2PropIOChannel> {
+                let raw_value = self.get_i_o_channel_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Local control value.
+            pub fn get_local_control_raw(&self) -> Option<bool> {
+                self.reversed_bytes.bool_from_bits(16, 1)
+            }
+            /// Get the Local control value.
+            pub fn get_local_control(&self) -> Option<Type12Case2PropLocalControl> {
+                let raw_value = self.get_local_control_raw()?;
+                Some(raw_value.into())
+            }
+
+            /// Get the raw Output value value.
+            pub fn get_output_value_raw(&self) -> Option<u8> {
+                self.reversed_bytes.u8_from_bits(17, 7)
+            }
+            /// Get the Output value value.
+            pub fn get_output_value(&self) -> Option<Type12Case2PropOutputValue> {
+                let raw_value = self.get_output_value_raw()?;
+                Some(raw_value.into())
+            }
+        }
+        impl<'b> ::core::fmt::Debug for Type12Case2<'b> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let mut debug = f.debug_struct("Type12Case2");
+                debug.field("power_failure", &self.get_power_failure());
+                debug.field("power_failure_detection", &self.get_power_failure_detection());
+                debug.field("command_id", &self.get_command_id());
+                debug.field("over_current_switch_off", &self.get_over_current_switch_off());
+                debug.field("error_level", &self.get_error_level());
+                debug.field("i_o_channel", &self.get_i_o_channel());
+                debug.field("local_control", &self.get_local_control());
+                debug.field("output_value", &self.get_output_value());
+                debug.finish()
+            }
+        }
+
+        /// Builder for Slot-in module with 2 channels (D2-01-12), case 2
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Type12Case2Builder {
+            reversed_bytes: [u8; 3],
+        }
+        impl Type12Case2Builder {
+            /// Creates a new builder with the bits checked by the conditions of this case set accordingly and all other bits cleared.
+            pub const fn new() -> Self {
+                Self { reversed_bytes: [0x20, 0x00, 0x00] }
+            }
+
+            /// Get the data built so far, with the bits of each byte reversed.
+            pub fn to_reversed_bytes(&self) -> [u8; 3] {
+                self.reversed_bytes
+            }
+
+            /// Get the data built so far, in the bit order in which it is transmitted.
+            pub fn to_bytes(&self) -> [u8; 3] {
+                let mut bytes = self.reversed_bytes;
+                for b in &mut bytes {
+                    *b = b.reverse_bits();
+                }
+                bytes
+            }
+
+            /// Get a view on the data built so far.
+            pub fn as_view(&self) -> Type12Case2<'_> {
+                Type12Case2::new(&self.reversed_bytes)
+            }
+
+            /// Set the raw Power Failure value.
+            pub fn set_power_failure_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(0, 1, raw_value as u128);
+                self
+            }
+            /// Set the Power Failure value.
+            pub fn set_power_failure(self, value: Type12Case2PropPowerFailure) -> Self {
+                self.set_power_failure_raw(value.into())
+            }
+
+            /// Set the raw Power Failure Detection value.
+            pub fn set_power_failure_detection_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(1, 1, raw_value as u128);
+                self
+            }
+            /// Set the Power Failure Detection value.
+            pub fn set_power_failure_detection(self, value: Type12Case2PropPowerFailureDetection) -> Self {
+                self.set_power_failure_detection_raw(value.into())
+            }
+
+            /// Set the raw Command ID value.
+            ///
+            /// The value must not exceed 15.
+            pub fn set_command_id_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 15, "raw value of Command ID out of range");
+                self.reversed_bytes.u128_to_bits(4, 4, raw_value as u128);
+                self
+            }
+            /// Set the Command ID value.
+            pub fn set_command_id(self, value: Type12Case2PropCommandId) -> Self {
+                self.set_command_id_raw(value.into())
+            }
+
+            /// Set the raw Over current switch off value.
+            pub fn set_over_current_switch_off_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(8, 1, raw_value as u128);
+                self
+            }
+            /// Set the Over current switch off value.
+            pub fn set_over_current_switch_off(self, value: Type12Case2PropOverCurrentSwitchOff) -> Self {
+                self.set_over_current_switch_off_raw(value.into())
+            }
+
+            /// Set the raw Error level value.
+            ///
+            /// The value must not exceed 3.
+            pub fn set_error_level_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 3, "raw value of Error level out of range");
+                self.reversed_bytes.u128_to_bits(9, 2, raw_value as u128);
+                self
+            }
+            /// Set the Error level value.
+            pub fn set_error_level(self, value: Type12Case2PropErrorLevel) -> Self {
+                self.set_error_level_raw(value.into())
+            }
+
+            /// Set the raw I/O channel value.
+            ///
+            /// The value must not exceed 31.
+            pub fn set_i_o_channel_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 31, "raw value of I/O channel out of range");
+                self.reversed_bytes.u128_to_bits(11, 5, raw_value as u128);
+                self
+            }
+            /// Set the I/O channel value.
+            pub fn set_i_o_channel(self, value: Type12Case2PropIOChannel) -> Self {
+                self.set_i_o_channel_raw(value.into())
+            }
+
+            /// Set the raw Local control value.
+            pub fn set_local_control_raw(mut self, raw_value: bool) -> Self {
+                self.reversed_bytes.u128_to_bits(16, 1, raw_value as u128);
+                self
+            }
+            /// Set the Local control value.
+            pub fn set_local_control(self, value: Type12Case2PropLocalControl) -> Self {
+                self.set_local_control_raw(value.into())
+            }
+
+            /// Set the raw Output value value.
+            ///
+            /// The value must not exceed 127.
+            pub fn set_output_value_raw(mut self, raw_value: u8) -> Self {
+                debug_assert!(raw_value <= 127, "raw value of Output value out of range");
+                self.reversed_bytes.u128_to_bits(17, 7, raw_value as u128);
+                self
+            }
+            /// Set the Output value value.
+            pub fn set_output_value(self, value: Type12Case2PropOutputValue) -> Self {
+                self.set_output_value_raw(value.into())
+            }
+        }
+        impl Default for Type12Case2Builder {
+            fn default() -> Self { Self::new() }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropPowerFailure {
+            PowerFailureDisabled,
+            PowerFailureEnabled,
+            _Other(bool),
+        }
+        impl From<bool> for Type12Case2PropPowerFailure {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::PowerFailureDisabled,
+                    true => Self::PowerFailureEnabled,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropPowerFailure> for bool {
+            fn from(enum_value: Type12Case2PropPowerFailure) -> Self {
+                match enum_value {
+                    Type12Case2PropPowerFailure::PowerFailureDisabled => false,
+                    Type12Case2PropPowerFailure::PowerFailureEnabled => true,
+                    Type12Case2PropPowerFailure::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropPowerFailure {
+            fn eq(&self, other: &Type12Case2PropPowerFailure) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropPowerFailure {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropPowerFailure {
+            fn partial_cmp(&self, other: &Type12Case2PropPowerFailure) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropPowerFailure {
+            fn cmp(&self, other: &Type12Case2PropPowerFailure) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropPowerFailureDetection {
+            PowerFailureNotDetected,
+            PowerFailureDetected,
+            _Other(bool),
+        }
+        impl From<bool> for Type12Case2PropPowerFailureDetection {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::PowerFailureNotDetected,
+                    true => Self::PowerFailureDetected,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropPowerFailureDetection> for bool {
+            fn from(enum_value: Type12Case2PropPowerFailureDetection) -> Self {
+                match enum_value {
+                    Type12Case2PropPowerFailureDetection::PowerFailureNotDetected => false,
+                    Type12Case2PropPowerFailureDetection::PowerFailureDetected => true,
+                    Type12Case2PropPowerFailureDetection::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropPowerFailureDetection {
+            fn eq(&self, other: &Type12Case2PropPowerFailureDetection) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropPowerFailureDetection {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropPowerFailureDetection {
+            fn partial_cmp(&self, other: &Type12Case2PropPowerFailureDetection) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropPowerFailureDetection {
+            fn cmp(&self, other: &Type12Case2PropPowerFailureDetection) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropCommandId {
+            Id04,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case2PropCommandId {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    4 => Self::Id04,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropCommandId> for u8 {
+            fn from(enum_value: Type12Case2PropCommandId) -> Self {
+                match enum_value {
+                    Type12Case2PropCommandId::Id04 => 4,
+                    Type12Case2PropCommandId::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropCommandId {
+            fn eq(&self, other: &Type12Case2PropCommandId) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropCommandId {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropCommandId {
+            fn partial_cmp(&self, other: &Type12Case2PropCommandId) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropCommandId {
+            fn cmp(&self, other: &Type12Case2PropCommandId) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropOverCurrentSwitchOff {
+            OverCurrentSwitchOffReady,
+            OverCurrentSwitchOffExecuted,
+            _Other(bool),
+        }
+        impl From<bool> for Type12Case2PropOverCurrentSwitchOff {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::OverCurrentSwitchOffReady,
+                    true => Self::OverCurrentSwitchOffExecuted,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropOverCurrentSwitchOff> for bool {
+            fn from(enum_value: Type12Case2PropOverCurrentSwitchOff) -> Self {
+                match enum_value {
+                    Type12Case2PropOverCurrentSwitchOff::OverCurrentSwitchOffReady => false,
+                    Type12Case2PropOverCurrentSwitchOff::OverCurrentSwitchOffExecuted => true,
+                    Type12Case2PropOverCurrentSwitchOff::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropOverCurrentSwitchOff {
+            fn eq(&self, other: &Type12Case2PropOverCurrentSwitchOff) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropOverCurrentSwitchOff {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropOverCurrentSwitchOff {
+            fn partial_cmp(&self, other: &Type12Case2PropOverCurrentSwitchOff) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropOverCurrentSwitchOff {
+            fn cmp(&self, other: &Type12Case2PropOverCurrentSwitchOff) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropErrorLevel {
+            ErrorLevel0HardwareOk,
+            ErrorLevel1HardwareWarning,
+            ErrorLevel2HardwareFailure,
+            ErrorLevelNotSupported,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case2PropErrorLevel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::ErrorLevel0HardwareOk,
+                    1 => Self::ErrorLevel1HardwareWarning,
+                    2 => Self::ErrorLevel2HardwareFailure,
+                    3 => Self::ErrorLevelNotSupported,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropErrorLevel> for u8 {
+            fn from(enum_value: Type12Case2PropErrorLevel) -> Self {
+                match enum_value {
+                    Type12Case2PropErrorLevel::ErrorLevel0HardwareOk => 0,
+                    Type12Case2PropErrorLevel::ErrorLevel1HardwareWarning => 1,
+                    Type12Case2PropErrorLevel::ErrorLevel2HardwareFailure => 2,
+                    Type12Case2PropErrorLevel::ErrorLevelNotSupported => 3,
+                    Type12Case2PropErrorLevel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropErrorLevel {
+            fn eq(&self, other: &Type12Case2PropErrorLevel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropErrorLevel {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropErrorLevel {
+            fn partial_cmp(&self, other: &Type12Case2PropErrorLevel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropErrorLevel {
+            fn cmp(&self, other: &Type12Case2PropErrorLevel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropIOChannel {
+            OutputChannel {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            NotUsed,
+            InputChannel,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case2PropIOChannel {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0..=29 => Self::OutputChannel {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 0.0, 29.0, 0.0, 29.0),
+                    },
+                    30 => Self::NotUsed,
+                    31 => Self::InputChannel,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropIOChannel> for u8 {
+            fn from(enum_value: Type12Case2PropIOChannel) -> Self {
+                match enum_value {
+                    Type12Case2PropIOChannel::OutputChannel { raw, .. } => raw,
+                    Type12Case2PropIOChannel::NotUsed => 30,
+                    Type12Case2PropIOChannel::InputChannel => 31,
+                    Type12Case2PropIOChannel::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropIOChannel {
+            fn eq(&self, other: &Type12Case2PropIOChannel) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropIOChannel {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropIOChannel {
+            fn partial_cmp(&self, other: &Type12Case2PropIOChannel) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropIOChannel {
+            fn cmp(&self, other: &Type12Case2PropIOChannel) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropLocalControl {
+            LocalControlDisabledNotSupported,
+            LocalControlEnabled,
+            _Other(bool),
+        }
+        impl From<bool> for Type12Case2PropLocalControl {
+            fn from(raw_value: bool) -> Self {
+                match raw_value {
+                    false => Self::LocalControlDisabledNotSupported,
+                    true => Self::LocalControlEnabled,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropLocalControl> for bool {
+            fn from(enum_value: Type12Case2PropLocalControl) -> Self {
+                match enum_value {
+                    Type12Case2PropLocalControl::LocalControlDisabledNotSupported => false,
+                    Type12Case2PropLocalControl::LocalControlEnabled => true,
+                    Type12Case2PropLocalControl::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropLocalControl {
+            fn eq(&self, other: &Type12Case2PropLocalControl) -> bool {
+                bool::from(*self).eq(&bool::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropLocalControl {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropLocalControl {
+            fn partial_cmp(&self, other: &Type12Case2PropLocalControl) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropLocalControl {
+            fn cmp(&self, other: &Type12Case2PropLocalControl) -> ::core::cmp::Ordering {
+                bool::from(*self).cmp(&bool::from(*other))
+            }
+        }
+        #[derive(Clone, Copy, Debug)]
+        #[allow(non_camel_case_types)]
+        pub enum Type12Case2PropOutputValue {
+            OutputValue0OrOff,
+            OutputValue1To100OrOn {
+                raw: u8,
+                #[cfg(feature = "eep_float")]
+                scaled: f64,
+            },
+            OutputValueNotValidOrNotSet,
+            _Other(u8),
+        }
+        impl From<u8> for Type12Case2PropOutputValue {
+            fn from(raw_value: u8) -> Self {
+                match raw_value {
+                    0 => Self::OutputValue0OrOff,
+                    1..=100 => Self::OutputValue1To100OrOn {
+                        raw: raw_value,
+                        #[cfg(feature = "eep_float")]
+                        scaled: range_scale(raw_value as f64, 1.0, 100.0, 1.0, 100.0),
+                    },
+                    127 => Self::OutputValueNotValidOrNotSet,
+                    #[allow(unreachable_patterns)] other => Self::_Other(other),
+                }
+            }
+        }
+        impl From<Type12Case2PropOutputValue> for u8 {
+            fn from(enum_value: Type12Case2PropOutputValue) -> Self {
+                match enum_value {
+                    Type12Case2PropOutputValue::OutputValue0OrOff => 0,
+                    Type12Case2PropOutputValue::OutputValue1To100OrOn { raw, .. } => raw,
+                    Type12Case2PropOutputValue::OutputValueNotValidOrNotSet => 127,
+                    Type12Case2PropOutputValue::_Other(other) => other,
+                }
+            }
+        }
+        impl ::core::cmp::PartialEq for Type12Case2PropOutputValue {
+            fn eq(&self, other: &Type12Case2PropOutputValue) -> bool {
+                u8::from(*self).eq(&u8::from(*other))
+            }
+        }
+        impl ::core::cmp::Eq for Type12Case2PropOutputValue {}
+        impl ::core::cmp::PartialOrd for Type12Case2PropOutputValue {
+            fn partial_cmp(&self, other: &Type12Case2PropOutputValue) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl ::core::cmp::Ord for Type12Case2PropOutputValue {
+            fn cmp(&self, other: &Type12Case2PropOutputValue) -> ::core::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+    }
+
+    #[cfg(feature = "eep_d2_01")]
+    #[derive(Clone, Copy, Debug)]
+    pub enum Func01<'b> {
+        Type01Case0(func01::Type01Case0<'b>),
+        Type01Case1(func01::Type01Case1<'b>),
+        Type01Case2(func01::Type01Case2<'b>),
+        Type12Case0(func01::Type12Case0<'b>),
+        Type12Case1(func01::Type12Case1<'b>),
+        Type12Case2(func01::Type12Case2<'b>),
+    }
+    #[cfg(feature = "eep_d2_01")]
+    impl<'b> Func01<'b> {
+        /// Returns the case of the type whose conditions the data fulfills.
+        pub fn type01_from_reversed_bytes(reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            let mut matcher = CaseMatcher::new();
+            let case = func01::Type01Case0::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type01Case0(case));
+            }
+            let case = func01::Type01Case1::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type01Case1(case));
+            }
+            let case = func01::Type01Case2::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type01Case2(case));
+            }
+            matcher.finish()
+        }
+        /// Returns the case of the type whose conditions the data fulfills.
+        pub fn type12_from_reversed_bytes(reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            let mut matcher = CaseMatcher::new();
+            let case = func01::Type12Case0::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type12Case0(case));
+            }
+            let case = func01::Type12Case1::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type12Case1(case));
+            }
+            let case = func01::Type12Case2::new(reversed_bytes);
+            if case.matches_condition(opt_direction) {
+                matcher.push(Self::Type12Case2(case));
+            }
+            matcher.finish()
+        }
+
+        pub fn from_reversed_bytes(type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+            match type_code {
+                0x01 => Self::type01_from_reversed_bytes(reversed_bytes, opt_direction),
+                0x12 => Self::type12_from_reversed_bytes(reversed_bytes, opt_direction),
+                _ => Err(EepDecodeError::UnknownProfile),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "eep_d2_01")]
+#[derive(Clone, Copy, Debug)]
+pub enum RorgD2<'b> {
+    #[cfg(feature = "eep_d2_01")]
+    Func01(rorgD2::Func01<'b>),
+}
+#[cfg(feature = "eep_d2_01")]
+impl<'b> RorgD2<'b> {
+    pub fn from_reversed_bytes(func_code: u8, type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+        match func_code {
+            #[cfg(feature = "eep_d2_01")]
+            0x01 => rorgD2::Func01::from_reversed_bytes(type_code, reversed_bytes, opt_direction)
+                .map(Self::Func01),
+            _ => Err(EepDecodeError::UnknownProfile),
+        }
+    }
+}
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+#[derive(Clone, Copy, Debug)]
+pub enum Eep<'b> {
+    #[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20"))]
+    RorgA5(RorgA5<'b>),
+    #[cfg(feature = "eep_d2_01")]
+    RorgD2(RorgD2<'b>),
+}
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+impl<'b> Eep<'b> {
+    /// Decodes the data using the given profile, returning the case whose conditions the data
+    /// fulfills.
+    ///
+    /// Cases that only apply to telegrams sent in a specific direction never match if no
+    /// direction is given.
+    pub fn from_reversed_bytes(rorg_code: u8, func_code: u8, type_code: u8, reversed_bytes: &'b [u8], opt_direction: Option<EepDirection>) -> Result<Self, EepDecodeError> {
+        match rorg_code {
+            #[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20"))]
+            0xA5 => RorgA5::from_reversed_bytes(func_code, type_code, reversed_bytes, opt_direction)
+                .map(Self::RorgA5),
+            #[cfg(feature = "eep_d2_01")]
+            0xD2 => RorgD2::from_reversed_bytes(func_code, type_code, reversed_bytes, opt_direction)
+                .map(Self::RorgD2),
+            _ => Err(EepDecodeError::UnknownProfile),
+        }
+    }
+}
+
+#[cfg(any(feature = "eep_a5_02", feature = "eep_a5_10", feature = "eep_a5_20", feature = "eep_d2_01"))]
+impl EepPayload {
+    /// Decodes the payload using its profile, returning the case whose conditions the payload
+    /// fulfills.
+    pub fn decode(&self) -> Result<Eep<'_>, EepDecodeError> {
+        let eep = self.eep();
+        Eep::from_reversed_bytes(eep.rorg, eep.func, eep.eep_type, self.reversed_bytes(), self.direction())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,403 @@
+//! Extraction of EnOcean Equipment Profile data from radio telegrams.
+//!
+//! The generated profiles in [`eep`](crate::esp3::eep) expect the data bytes of a telegram with
+//! the bits of each byte reversed. [`EepPayload`] takes care of this, rejects telegrams that do not
+//! match the expected profile and, if any profiles are enabled, decodes them via
+//! `EepPayload::decode` into the single case of the profile whose conditions the data fulfills.
+
+
+use core::fmt;
+
+use crate::esp3::erp::{ErpData, FourByteTeachInDetails, MAXIMUM_VLD_DATA_LENGTH};
+use crate::esp3::recom::LinkTableEntry;
+use crate::esp3::remote_man::RemoteManEep;
+use crate::max_array::MaxArray;
+
+
+/// The RORG value of four-byte telegrams, to which four-byte teach-in details refer.
+const FOUR_BYTE_RORG: u8 = 0xA5;
+
+
+/// Identifies an EnOcean Equipment Profile (e.g. A5-20-01).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EepId {
+    pub rorg: u8,
+    pub func: u8,
+    pub eep_type: u8,
+}
+impl EepId {
+    pub const fn new(rorg: u8, func: u8, eep_type: u8) -> Self {
+        Self {
+            rorg,
+            func,
+            eep_type,
+        }
+    }
+
+    /// The profile announced by a four-byte teach-in telegram.
+    pub fn from_teach_in_details(details: &FourByteTeachInDetails) -> Self {
+        Self::new(FOUR_BYTE_RORG, details.func_value, details.type_value)
+    }
+}
+impl fmt::Display for EepId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02X}-{:02X}-{:02X}", self.rorg, self.func, self.eep_type)
+    }
+}
+impl From<RemoteManEep> for EepId {
+    fn from(value: RemoteManEep) -> Self {
+        Self::new(value.rorg, value.func, value.eep_type)
+    }
+}
+impl From<&LinkTableEntry> for EepId {
+    fn from(value: &LinkTableEntry) -> Self {
+        Self::new(value.eep_rorg, value.eep_func, value.eep_type)
+    }
+}
+
+
+/// The direction in which a telegram is sent, as distinguished by some profiles.
+///
+/// The numbering follows _EnOcean Equipment Profiles_; the meaning of each direction is defined by
+/// the respective profile (e.g. for A5-20-01, direction 1 is from the actuator to the controller).
+#[derive(Copy, Clone, Debug)]
+#[from_to_repr::from_to_other(base_type = u8, derive_compare = "as_int")]
+pub enum EepDirection {
+    Direction1 = 1,
+    Direction2 = 2,
+    Other(u8),
+}
+
+
+/// The reason why the data of a telegram cannot be decoded using a profile.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EepPayloadError {
+    /// The telegram type does not carry profile data (e.g. a Smart Ack or remote management
+    /// telegram).
+    UnsupportedTelegram { rorg: u8 },
+
+    /// The RORG of the telegram differs from the RORG of the profile.
+    RorgMismatch { expected: u8, actual: u8 },
+
+    /// The LRN bit marks the telegram as a teach-in telegram, whose data does not follow the
+    /// profile.
+    TeachIn,
+}
+impl fmt::Display for EepPayloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedTelegram { rorg }
+                => write!(f, "telegram with RORG 0x{:02X} does not carry profile data", rorg),
+            Self::RorgMismatch { expected, actual }
+                => write!(f, "telegram has RORG 0x{:02X} but profile expects 0x{:02X}", actual, expected),
+            Self::TeachIn
+                => write!(f, "telegram is a teach-in telegram"),
+        }
+    }
+}
+
+
+/// The reason why a payload cannot be decoded into a case of its profile.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EepDecodeError {
+    /// The profile is not known or its feature is not enabled.
+    UnknownProfile,
+
+    /// The data fulfills the conditions of none of the cases of the profile.
+    NoCaseMatched,
+
+    /// The data fulfills the conditions of more than one case of the profile.
+    AmbiguousCase,
+}
+impl fmt::Display for EepDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownProfile
+                => write!(f, "profile unknown or not enabled"),
+            Self::NoCaseMatched
+                => write!(f, "no case of the profile matched"),
+            Self::AmbiguousCase
+                => write!(f, "more than one case of the profile matched"),
+        }
+    }
+}
+
+
+/// The data bytes of a telegram, prepared for decoding using a specific profile.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EepPayload {
+    eep: EepId,
+    opt_direction: Option<EepDirection>,
+    reversed_bytes: MaxArray<u8, MAXIMUM_VLD_DATA_LENGTH>,
+}
+impl EepPayload {
+    /// Extracts the data bytes of the given telegram to be decoded using the given profile.
+    ///
+    /// Only RPS, 1BS, 4BS and VLD telegrams carry profile data. Teach-in telegrams (1BS and 4BS
+    /// telegrams with the LRN bit cleared) are rejected.
+    pub fn new(telegram: &ErpData, eep: impl Into<EepId>) -> Result<Self, EepPayloadError> {
+        let eep = eep.into();
+        let rorg = telegram.rorg_value();
+        if rorg != eep.rorg {
+            return Err(EepPayloadError::RorgMismatch { expected: eep.rorg, actual: rorg });
+        }
+
+        let mut reversed_bytes = MaxArray::new();
+        match telegram {
+            ErpData::RepeatedSwitch(rps) => {
+                reversed_bytes.push(rps.data.reverse_bits()).unwrap();
+            },
+            ErpData::OneByte(ob) => {
+                if ob.is_teach_in() {
+                    return Err(EepPayloadError::TeachIn);
+                }
+                reversed_bytes.push(ob.data.reverse_bits()).unwrap();
+            },
+            ErpData::FourByte(fb) => {
+                if fb.is_teach_in() {
+                    return Err(EepPayloadError::TeachIn);
+                }
+                for b in fb.data.to_be_bytes() {
+                    reversed_bytes.push(b.reverse_bits()).unwrap();
+                }
+            },
+            ErpData::VariableLength(vld) => {
+                for b in vld.data.as_slice() {
+                    reversed_bytes.push(b.reverse_bits()).unwrap();
+                }
+            },
+            _ => return Err(EepPayloadError::UnsupportedTelegram { rorg }),
+        }
+
+        Ok(Self {
+            eep,
+            opt_direction: None,
+            reversed_bytes,
+        })
+    }
+
+    /// The profile using which the data is to be decoded.
+    pub fn eep(&self) -> EepId {
+        self.eep
+    }
+
+    /// Sets the direction in which the telegram has been sent, for profiles whose cases depend
+    /// on it.
+    pub fn with_direction(mut self, direction: EepDirection) -> Self {
+        self.opt_direction = Some(direction);
+        self
+    }
+
+    /// The direction in which the telegram has been sent, if known.
+    pub fn direction(&self) -> Option<EepDirection> {
+        self.opt_direction
+    }
+
+    /// The data bytes, each with its bits reversed, as expected by the generated profiles.
+    pub fn reversed_bytes(&self) -> &[u8] {
+        self.reversed_bytes.as_slice()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{EepId, EepPayload, EepPayloadError};
+    use crate::esp3::erp::{ErpData, FourByteTelegram, OneByteTelegram};
+
+    #[test]
+    fn test_four_byte_payload() {
+        let eep = EepId::new(0xA5, 0x20, 0x01);
+        assert_eq!(eep.to_string(), "A5-20-01");
+
+        let telegram = ErpData::FourByte(FourByteTelegram {
+            data: 0x2A_00_86_08,
+            sender: 0x0102_0304,
+            status: 0x00,
+        });
+        let payload = EepPayload::new(&telegram, eep).unwrap();
+        assert_eq!(payload.reversed_bytes(), &[0x54, 0x00, 0x61, 0x10]);
+
+        let teach_in = ErpData::FourByte(FourByteTelegram {
+            data: 0x80_08_49_80,
+            sender: 0x0102_0304,
+            status: 0x00,
+        });
+        assert_eq!(EepPayload::new(&teach_in, eep), Err(EepPayloadError::TeachIn));
+
+        let one_byte = ErpData::OneByte(OneByteTelegram {
+            data: 0x09,
+            sender: 0x0102_0304,
+            status: 0x00,
+        });
+        assert_eq!(
+            EepPayload::new(&one_byte, eep),
+            Err(EepPayloadError::RorgMismatch { expected: 0xA5, actual: 0xD5 }),
+        );
+    }
+    #[cfg(feature = "eep_a5_20")]
+    #[test]
+    fn test_decode() {
+        use super::{EepDecodeError, EepDirection};
+        use crate::esp3::eep::{Eep, RorgA5};
+        use crate::esp3::eep::rorgA5::Func20;
+
+        let telegram = ErpData::FourByte(FourByteTelegram {
+            data: 0x2A_00_86_08,
+            sender: 0x0102_0304,
+            status: 0x00,
+        });
+        let payload = EepPayload::new(&telegram, EepId::new(0xA5, 0x20, 0x01)).unwrap();
+
+        // A5-20-01 has a case per direction
+        assert_eq!(payload.decode().unwrap_err(), EepDecodeError::NoCaseMatched);
+        let from_actuator = payload.clone().with_direction(EepDirection::Direction1);
+        let decoded = from_actuator.decode().unwrap();
+        if let Eep::RorgA5(RorgA5::Func20(Func20::Type01Case0(case))) = decoded {
+            assert_eq!(case.get_current_value_raw(), Some(42));
+            // 134/255 * 40 °C = 21.02 °C
+            assert_eq!(case.get_temperature_fixed(), Some(2102));
+        } else {
+            panic!("unexpected case {:?}", decoded);
+        }
+        let to_actuator = payload.with_direction(EepDirection::Direction2);
+        let decoded = to_actuator.decode().unwrap();
+        assert!(matches!(decoded, Eep::RorgA5(RorgA5::Func20(Func20::Type01Case1(_)))));
+
+        assert_eq!(
+            EepPayload::new(&telegram, EepId::new(0xA5, 0x3F, 0x7F)).unwrap().decode().unwrap_err(),
+            EepDecodeError::UnknownProfile,
+        );
+    }
+    #[cfg(feature = "eep_d2_01")]
+    #[test]
+    fn test_case_selection() {
+        use super::EepDecodeError;
+        use crate::esp3::eep::CaseMatcher;
+        use crate::esp3::eep::rorgD2::Func01;
+
+        // D2-01-01 picks its case by the command ID in the lower nibble of the first byte (which
+        // is the upper nibble once the bits are reversed)
+        let mut reversed_bytes = [0x00; 6];
+        reversed_bytes[0] = 0x80;
+        assert!(matches!(Func01::type01_from_reversed_bytes(&reversed_bytes, None), Ok(Func01::Type01Case0(_))));
+        reversed_bytes[0] = 0xC0;
+        assert!(matches!(Func01::type01_from_reversed_bytes(&reversed_bytes, None), Ok(Func01::Type01Case1(_))));
+        reversed_bytes[0] = 0x20;
+        assert!(matches!(Func01::type01_from_reversed_bytes(&reversed_bytes, None), Ok(Func01::Type01Case2(_))));
+        reversed_bytes[0] = 0x40;
+        assert_eq!(Func01::type01_from_reversed_bytes(&reversed_bytes, None).unwrap_err(), EepDecodeError::NoCaseMatched);
+
+        // the vendored profiles have no overlapping cases, so check the matcher itself
+        let mut matcher = CaseMatcher::new();
+        assert_eq!(matcher.finish(), Err(EepDecodeError::NoCaseMatched));
+        matcher.push(0);
+        assert_eq!(matcher.finish(), Ok(0));
+        matcher.push(1);
+        assert_eq!(matcher.finish(), Err(EepDecodeError::AmbiguousCase));
+    }
+    #[cfg(feature = "eep_a5_20")]
+    #[test]
+    fn test_build_four_byte() {
+        use super::EepDirection;
+        use crate::esp3::eep::{Eep, RorgA5};
+        use crate::esp3::eep::rorgA5::{Func20, func20};
+
+        // the builder starts out with a data telegram
+        let builder = func20::Type01Case1Builder::new()
+            .set_valve_position_or_temperature_setpoint_raw(0x42)
+            .set_temperature_from_rcu_raw(0x99);
+        let telegram = ErpData::FourByte(FourByteTelegram {
+            data: u32::from_be_bytes(builder.to_bytes()),
+            sender: 0x0102_0304,
+            status: 0x00,
+        });
+        let payload = EepPayload::new(&telegram, EepId::new(0xA5, 0x20, 0x01)).unwrap()
+            .with_direction(EepDirection::Direction2);
+        let decoded = payload.decode().unwrap();
+        if let Eep::RorgA5(RorgA5::Func20(Func20::Type01Case1(case))) = decoded {
+            assert_eq!(case.get_valve_position_or_temperature_setpoint_raw(), Some(0x42));
+            assert_eq!(case.get_temperature_from_rcu_raw(), Some(0x99));
+            assert_eq!(case.get_lrn_bit_raw(), Some(true));
+        } else {
+            panic!("unexpected case {:?}", decoded);
+        }
+    }
+    #[cfg(feature = "eep_a5_20")]
+    #[test]
+    fn test_build_fixed_point() {
+        use crate::esp3::eep::rorgA5::func20::Type01Case1Builder;
+
+        fn set_point(value: i32) -> Option<u8> {
+            Type01Case1Builder::new().set_valve_position_or_temperature_setpoint_fixed(value)
+                .as_view().get_valve_position_or_temperature_setpoint_raw()
+        }
+        fn temperature(value: i32) -> Option<u8> {
+            Type01Case1Builder::new().set_temperature_from_rcu_fixed(value)
+                .as_view().get_temperature_from_rcu_raw()
+        }
+
+        // raw 0..=255 is a valve position in % or a set point, depending on SPS; in units of 0.01,
+        // rounding halves away from zero
+        assert_eq!(set_point(5049), Some(50));
+        assert_eq!(set_point(5050), Some(51));
+        assert_eq!(set_point(25500), Some(255));
+        assert_eq!(set_point(30000), Some(255));
+        assert_eq!(set_point(-5), Some(0));
+
+        // the scale is inverted: 0 °C is raw 255 and 40 °C is raw 0
+        assert_eq!(temperature(0), Some(255));
+        assert_eq!(temperature(4000), Some(0));
+        // 20 °C * 255/40 = 127.5 raw below 255
+        assert_eq!(temperature(2000), Some(127));
+        // 10 °C * 255/40 = 63.75 raw below 255
+        assert_eq!(temperature(1000), Some(191));
+        assert_eq!(temperature(-100), Some(255));
+        assert_eq!(temperature(5000), Some(0));
+
+        let builder = Type01Case1Builder::new().set_temperature_from_rcu_fixed(1000);
+        assert_eq!(builder.as_view().get_temperature_from_rcu_fixed(), Some(1004));
+    }
+    #[cfg(feature = "eep_d2_01")]
+    #[test]
+    fn test_build_variable_length() {
+        use crate::esp3::eep::{Eep, RorgD2};
+        use crate::esp3::eep::rorgD2::{Func01, func01};
+        use crate::esp3::erp::VariableLengthTelegram;
+
+        fn payload(bytes: &[u8]) -> EepPayload {
+            let telegram = ErpData::VariableLength(VariableLengthTelegram::new(bytes, 0x0102_0304, 0x00).unwrap());
+            EepPayload::new(&telegram, EepId::new(0xD2, 0x01, 0x01)).unwrap()
+        }
+        fn decode(payload: &EepPayload) -> Func01<'_> {
+            match payload.decode().unwrap() {
+                Eep::RorgD2(RorgD2::Func01(func)) => func,
+                #[allow(unreachable_patterns)] other => panic!("unexpected profile {:?}", other),
+            }
+        }
+
+        // the builders preset the command ID of their case
+        let set_output = func01::Type01Case0Builder::new()
+            .set_i_o_channel_raw(2)
+            .set_output_value_raw(100)
+            .to_bytes();
+        assert_eq!(set_output, [0x01, 0x02, 0x64]);
+        if let Func01::Type01Case0(case) = decode(&payload(&set_output)) {
+            assert_eq!(case.get_command_id_raw(), Some(0x1));
+            assert_eq!(case.get_i_o_channel_raw(), Some(2));
+            assert_eq!(case.get_output_value_raw(), Some(100));
+        } else {
+            panic!("unexpected case");
+        }
+
+        let query_status = func01::Type01Case1Builder::new()
+            .set_i_o_channel_raw(30)
+            .to_bytes();
+        if let Func01::Type01Case1(case) = decode(&payload(&query_status)) {
+            assert_eq!(case.get_command_id_raw(), Some(0x3));
+            assert_eq!(case.get_i_o_channel_raw(), Some(30));
+        } else {
+            panic!("unexpected case");
+        }
+    }
+}
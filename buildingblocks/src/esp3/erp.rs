@@ -5,8 +5,10 @@
 //! The most helpful document describing the common packet types is _EnOcean Equipment Profiles_.
 
 
-use crate::esp3::{MAX_DATA_LENGTH, OneByteBoolean};
+use crate::esp3::{MAX_DATA_LENGTH, OneByteBoolean, SmartAckConfirmCode};
+use crate::esp3::error::{ParseError, ParseErrorKind};
 use crate::max_array::MaxArray;
+use crate::max_array_ext::MaxArrayPushIntExt;
 
 
 /// The maximum length of variable data in a VLD ESP3 packet.
@@ -26,8 +28,33 @@ pub const MAXIMUM_MSC_DATA_LENGTH: usize = 13;
 /// Specified in _Signal Telegram_ (section 2).
 pub const MAXIMUM_SIG_DATA_LENGTH: usize = 13;
 
+/// The maximum length of the payload of a SEC or SEC_ENCAPS ESP3 packet.
+///
+/// The payload consists of the encrypted data (at most as long as VLD data), a rolling code of up to
+/// 4 bytes and a MAC of up to 4 bytes.
+pub const MAXIMUM_SEC_DATA_LENGTH: usize = MAXIMUM_VLD_DATA_LENGTH + 4 + 4;
+
+/// The maximum length of the data following the teach-in information byte of a SEC_TI ESP3 packet.
+///
+/// Consists of the security level format (1 byte), the rolling code (up to 4 bytes), the key
+/// (16 bytes) and, if the teach-in is protected by a pre-shared key, its MAC (4 bytes). Teach-ins
+/// that do not fit into a single telegram are split into two.
+pub const MAXIMUM_SEC_TI_DATA_LENGTH: usize = 1 + 4 + 16 + 4;
+
+/// The maximum length of the encapsulated data in an ADT ESP3 packet.
+///
+/// This is the length of the data of the largest telegram that can be encapsulated (VLD).
+pub const MAXIMUM_ADT_DATA_LENGTH: usize = MAXIMUM_VLD_DATA_LENGTH;
+
+/// The maximum length of the contents of an ERP telegram of a type known to this module, excluding
+/// the RORG value and the status byte.
+///
+/// The longest such telegrams are secure teach-ins (teach-in information, data and sender ID).
+pub const MAXIMUM_CONTENTS_LENGTH: usize = 1 + MAXIMUM_SEC_TI_DATA_LENGTH + 4;
+
 
 /// EnOcean Radio Protocol data in an ESP3 packet.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum ErpData {
     /// RPS (0xF6)
@@ -48,13 +75,6 @@ pub enum ErpData {
     /// UTE (0xD4)
     UniversalTeachIn(UniversalTeachInTelegram),
 
-    // the following telegrams might be implemented eventually
-    // but are currently considered out-of-scope
-
-    /*
-    /// MSC (0xD1)
-    ManufacturerSpecific(ManufacturerSpecificTelegram),
-
     /// ADT (0xA6)
     AddressingDestination(AddressingDestinationTelegram),
 
@@ -74,7 +94,17 @@ pub enum ErpData {
     Secure(SecureTelegram),
 
     /// SEC_ENCAPS (0x31)
-    SecureEncapsulated(SecureEncapsulatedTelegram),
+    SecureEncapsulated(SecureTelegram),
+
+    /// SEC_TI (0x35)
+    SecureTeachIn(SecureTeachInTelegram),
+
+    // the following telegrams might be implemented eventually
+    // but are currently considered out-of-scope
+
+    /*
+    /// MSC (0xD1)
+    ManufacturerSpecific(ManufacturerSpecificTelegram),
 
     /// SEC_MAN (0x34)
     MaintenanceSecurity(MaintenanceSecurityTelegram),
@@ -96,41 +126,343 @@ impl ErpData {
             Self::VariableLength(_) => 0xD2,
             Self::Signal(_) => 0xD0,
             Self::UniversalTeachIn(_) => 0xD4,
+            Self::AddressingDestination(_) => 0xA6,
+            Self::SmartAckLearnRequest(_) => 0xC6,
+            Self::SmartAckLearnAnswer(_) => 0xC7,
+            Self::SmartAckReclaim(_) => 0xA7,
+            Self::RemoteManagement(_) => 0xC5,
+            Self::Secure(_) => 0x30,
+            Self::SecureEncapsulated(_) => 0x31,
+            Self::SecureTeachIn(_) => 0x35,
             Self::Other { rorg, .. } => *rorg,
         }
     }
 
+    /// Returns the sender ID of this ERP telegram, or `None` if the type of the telegram is not
+    /// known to this module.
+    pub fn opt_sender(&self) -> Option<u32> {
+        match self {
+            Self::RepeatedSwitch(t) => Some(t.sender),
+            Self::OneByte(t) => Some(t.sender),
+            Self::FourByte(t) => Some(t.sender),
+            Self::VariableLength(t) => Some(t.sender),
+            Self::Signal(t) => Some(t.sender),
+            Self::UniversalTeachIn(t) => Some(t.sender),
+            Self::AddressingDestination(t) => Some(t.sender),
+            Self::SmartAckLearnRequest(t) => Some(t.sender),
+            Self::SmartAckLearnAnswer(t) => Some(t.sender),
+            Self::SmartAckReclaim(t) => Some(t.sender),
+            Self::RemoteManagement(t) => Some(t.sender),
+            Self::Secure(t) => Some(t.sender),
+            Self::SecureEncapsulated(t) => Some(t.sender),
+            Self::SecureTeachIn(t) => Some(t.sender),
+            Self::Other { .. } => None,
+        }
+    }
+
+    /// Provides access to the status byte of this ERP telegram, or `None` if the type of the
+    /// telegram is not known to this module.
+    pub fn as_status_byte(&self) -> Option<&dyn ErpStatusByte> {
+        match self {
+            Self::RepeatedSwitch(t) => Some(t),
+            Self::OneByte(t) => Some(t),
+            Self::FourByte(t) => Some(t),
+            Self::VariableLength(t) => Some(t),
+            Self::Signal(t) => Some(t),
+            Self::UniversalTeachIn(t) => Some(t),
+            Self::AddressingDestination(t) => Some(t),
+            Self::SmartAckLearnRequest(t) => Some(t),
+            Self::SmartAckLearnAnswer(t) => Some(t),
+            Self::SmartAckReclaim(t) => Some(t),
+            Self::RemoteManagement(t) => Some(t),
+            Self::Secure(t) => Some(t),
+            Self::SecureEncapsulated(t) => Some(t),
+            Self::SecureTeachIn(t) => Some(t),
+            Self::Other { .. } => None,
+        }
+    }
+
+    /// Provides mutable access to the status byte of this ERP telegram, or `None` if the type of
+    /// the telegram is not known to this module.
+    pub fn as_status_byte_mut(&mut self) -> Option<&mut dyn ErpStatusByte> {
+        match self {
+            Self::RepeatedSwitch(t) => Some(t),
+            Self::OneByte(t) => Some(t),
+            Self::FourByte(t) => Some(t),
+            Self::VariableLength(t) => Some(t),
+            Self::Signal(t) => Some(t),
+            Self::UniversalTeachIn(t) => Some(t),
+            Self::AddressingDestination(t) => Some(t),
+            Self::SmartAckLearnRequest(t) => Some(t),
+            Self::SmartAckLearnAnswer(t) => Some(t),
+            Self::SmartAckReclaim(t) => Some(t),
+            Self::RemoteManagement(t) => Some(t),
+            Self::Secure(t) => Some(t),
+            Self::SecureEncapsulated(t) => Some(t),
+            Self::SecureTeachIn(t) => Some(t),
+            Self::Other { .. } => None,
+        }
+    }
+
+    /// Creates a telegram of a type not otherwise known to this module.
+    ///
+    /// Returns `None` if the data does not fit into an ESP3 packet.
+    pub fn new_other(rorg: u8, data: &[u8]) -> Option<Self> {
+        if data.len() > MAX_DATA_LENGTH - 1 {
+            return None;
+        }
+
+        Some(Self::Other {
+            rorg,
+            data: MaxArray::from_iter_or_panic(
+                data.iter().copied().peekable(),
+            ),
+        })
+    }
+
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        if bytes.len() < 1 {
+        if bytes.is_empty() {
             None
         } else {
             Self::from_rorg_and_data(bytes[0], &bytes[1..])
         }
     }
 
+    /// Decodes a telegram, including the RORG value, reporting why decoding failed.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (rorg, data_bytes) = bytes.split_first()
+            .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+        Self::from_rorg_and_data(*rorg, data_bytes)
+            .ok_or(ParseError::new(1, ParseErrorKind::InvalidTelegram {
+                rorg: *rorg,
+                length: bytes.len(),
+            }))
+    }
+
+    /// Encodes this telegram, including the RORG value, into bytes as they appear in the
+    /// `radio_telegram` field of an [`Esp3Packet::RadioErp1`](crate::esp3::Esp3Packet::RadioErp1).
+    pub fn to_bytes(&self) -> MaxArray<u8, MAX_DATA_LENGTH> {
+        let mut ret = MaxArray::new();
+        ret.push(self.rorg_value()).unwrap();
+        match self {
+            Self::RepeatedSwitch(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::OneByte(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::FourByte(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::VariableLength(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::Signal(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::UniversalTeachIn(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::AddressingDestination(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::SmartAckLearnRequest(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::SmartAckLearnAnswer(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::SmartAckReclaim(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::RemoteManagement(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::Secure(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::SecureEncapsulated(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::SecureTeachIn(t) => push_all(&mut ret, t.to_bytes().as_slice()),
+            Self::Other { data, .. } => push_all(&mut ret, data.as_slice()),
+        }
+        ret
+    }
+
     pub fn from_rorg_and_data(rorg: u8, data_bytes: &[u8]) -> Option<Self> {
+        ErpDataRef::from_rorg_and_data(rorg, data_bytes)?
+            .into_owned()
+    }
+}
+
+/// A compact copy of the contents of an ERP telegram of a type known to this module, i.e. its RORG
+/// value, data and sender ID, without the status byte.
+///
+/// Copies of a telegram that only differ in their repeater count have the same contents. Unlike
+/// [`ErpData`], this does not reserve space for telegrams of unknown types; it stays small even if
+/// [`MAX_DATA_LENGTH`] is large.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ErpContents {
+    rorg: u8,
+    bytes: MaxArray<u8, MAXIMUM_CONTENTS_LENGTH>,
+}
+impl ErpContents {
+    /// Copies the contents of the given telegram. Returns `None` if the type of the telegram is not
+    /// known to this module.
+    pub fn new(telegram: &ErpData) -> Option<Self> {
+        let mut bytes = MaxArray::new();
+        let mut push_contents = |encoded: &[u8]| {
+            // the status byte comes last
+            push_all(&mut bytes, &encoded[..encoded.len()-1]);
+        };
+        match telegram {
+            ErpData::RepeatedSwitch(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::OneByte(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::FourByte(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::VariableLength(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::Signal(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::UniversalTeachIn(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::AddressingDestination(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::SmartAckLearnRequest(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::SmartAckLearnAnswer(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::SmartAckReclaim(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::RemoteManagement(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::Secure(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::SecureEncapsulated(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::SecureTeachIn(t) => push_contents(t.to_bytes().as_slice()),
+            ErpData::Other { .. } => return None,
+        }
+        Some(Self {
+            rorg: telegram.rorg_value(),
+            bytes,
+        })
+    }
+
+    /// Returns the RORG value of the telegram.
+    pub fn rorg_value(&self) -> u8 {
+        self.rorg
+    }
+
+    /// Restores the telegram, giving it the given status byte.
+    pub fn to_telegram(&self, status: u8) -> Option<ErpData> {
+        let mut data_bytes: MaxArray<u8, {MAXIMUM_CONTENTS_LENGTH + 1}> = MaxArray::new();
+        push_all(&mut data_bytes, self.bytes.as_slice());
+        data_bytes.push(status).unwrap();
+        ErpData::from_rorg_and_data(self.rorg, data_bytes.as_slice())
+    }
+}
+
+/// EnOcean Radio Protocol data, borrowed from the buffer it was decoded from.
+///
+/// Unlike [`ErpData`], telegrams of unknown types are not copied; this keeps the value small even
+/// if [`MAX_DATA_LENGTH`] is large.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ErpDataRef<'a> {
+    RepeatedSwitch(RepeatedSwitchTelegram),
+    OneByte(OneByteTelegram),
+    FourByte(FourByteTelegram),
+    VariableLength(VariableLengthTelegram),
+    Signal(SignalTelegram),
+    UniversalTeachIn(UniversalTeachInTelegram),
+    AddressingDestination(AddressingDestinationTelegram),
+    SmartAckLearnRequest(SmartAckLearnRequestTelegram),
+    SmartAckLearnAnswer(SmartAckLearnAnswerTelegram),
+    SmartAckReclaim(SmartAckReclaimTelegram),
+    RemoteManagement(RemoteManagementTelegram),
+    Secure(SecureTelegram),
+    SecureEncapsulated(SecureTelegram),
+    SecureTeachIn(SecureTeachInTelegram),
+
+    /// Another type of telegram.
+    Other {
+        rorg: u8,
+        data: &'a [u8],
+    },
+}
+impl<'a> ErpDataRef<'a> {
+    /// Returns the RORG value for this ERP telegram.
+    pub fn rorg_value(&self) -> u8 {
+        match self {
+            Self::RepeatedSwitch(_) => 0xF6,
+            Self::OneByte(_) => 0xD5,
+            Self::FourByte(_) => 0xA5,
+            Self::VariableLength(_) => 0xD2,
+            Self::Signal(_) => 0xD0,
+            Self::UniversalTeachIn(_) => 0xD4,
+            Self::AddressingDestination(_) => 0xA6,
+            Self::SmartAckLearnRequest(_) => 0xC6,
+            Self::SmartAckLearnAnswer(_) => 0xC7,
+            Self::SmartAckReclaim(_) => 0xA7,
+            Self::RemoteManagement(_) => 0xC5,
+            Self::Secure(_) => 0x30,
+            Self::SecureEncapsulated(_) => 0x31,
+            Self::SecureTeachIn(_) => 0x35,
+            Self::Other { rorg, .. } => *rorg,
+        }
+    }
+
+    pub fn from_slice(bytes: &'a [u8]) -> Option<Self> {
+        Self::parse(bytes).ok()
+    }
+
+    /// Decodes a telegram, including the RORG value, reporting why decoding failed.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, ParseError> {
+        let (rorg, data_bytes) = bytes.split_first()
+            .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+        Self::from_rorg_and_data(*rorg, data_bytes)
+            .ok_or(ParseError::new(1, ParseErrorKind::InvalidTelegram {
+                rorg: *rorg,
+                length: bytes.len(),
+            }))
+    }
+
+    pub fn from_rorg_and_data(rorg: u8, data_bytes: &'a [u8]) -> Option<Self> {
         // match the RORG value
         match rorg {
-            0xF6 => RepeatedSwitchTelegram::from_slice(&data_bytes)
+            0xF6 => RepeatedSwitchTelegram::from_slice(data_bytes)
                 .map(Self::RepeatedSwitch),
-            0xD5 => OneByteTelegram::from_slice(&data_bytes)
+            0xD5 => OneByteTelegram::from_slice(data_bytes)
                 .map(Self::OneByte),
-            0xA5 => FourByteTelegram::from_slice(&data_bytes)
+            0xA5 => FourByteTelegram::from_slice(data_bytes)
                 .map(Self::FourByte),
-            0xD2 => VariableLengthTelegram::from_slice(&data_bytes)
+            0xD2 => VariableLengthTelegram::from_slice(data_bytes)
                 .map(Self::VariableLength),
-            0xD0 => SignalTelegram::from_slice(&data_bytes)
+            0xD0 => SignalTelegram::from_slice(data_bytes)
                 .map(Self::Signal),
-            0xD4 => UniversalTeachInTelegram::from_slice(&data_bytes)
+            0xD4 => UniversalTeachInTelegram::from_slice(data_bytes)
                 .map(Self::UniversalTeachIn),
+            0xA6 => AddressingDestinationTelegram::from_slice(data_bytes)
+                .map(Self::AddressingDestination),
+            0xC6 => SmartAckLearnRequestTelegram::from_slice(data_bytes)
+                .map(Self::SmartAckLearnRequest),
+            0xC7 => SmartAckLearnAnswerTelegram::from_slice(data_bytes)
+                .map(Self::SmartAckLearnAnswer),
+            0xA7 => SmartAckReclaimTelegram::from_slice(data_bytes)
+                .map(Self::SmartAckReclaim),
+            0xC5 => RemoteManagementTelegram::from_slice(data_bytes)
+                .map(Self::RemoteManagement),
+            0x30 => SecureTelegram::from_slice(data_bytes)
+                .map(Self::Secure),
+            0x31 => SecureTelegram::from_slice(data_bytes)
+                .map(Self::SecureEncapsulated),
+            0x35 => SecureTeachInTelegram::from_slice(data_bytes)
+                .map(Self::SecureTeachIn),
             other => Some(Self::Other {
                 rorg: other,
-                data: MaxArray::from_iter_or_panic(
-                    data_bytes.iter().map(|b| *b).peekable(),
-                ),
+                data: data_bytes,
             }),
         }
     }
+
+    /// Copies this telegram into an [`ErpData`].
+    ///
+    /// Returns `None` if the data of a telegram of an unknown type does not fit into an ESP3
+    /// packet.
+    pub fn into_owned(self) -> Option<ErpData> {
+        match self {
+            Self::RepeatedSwitch(t) => Some(ErpData::RepeatedSwitch(t)),
+            Self::OneByte(t) => Some(ErpData::OneByte(t)),
+            Self::FourByte(t) => Some(ErpData::FourByte(t)),
+            Self::VariableLength(t) => Some(ErpData::VariableLength(t)),
+            Self::Signal(t) => Some(ErpData::Signal(t)),
+            Self::UniversalTeachIn(t) => Some(ErpData::UniversalTeachIn(t)),
+            Self::AddressingDestination(t) => Some(ErpData::AddressingDestination(t)),
+            Self::SmartAckLearnRequest(t) => Some(ErpData::SmartAckLearnRequest(t)),
+            Self::SmartAckLearnAnswer(t) => Some(ErpData::SmartAckLearnAnswer(t)),
+            Self::SmartAckReclaim(t) => Some(ErpData::SmartAckReclaim(t)),
+            Self::RemoteManagement(t) => Some(ErpData::RemoteManagement(t)),
+            Self::Secure(t) => Some(ErpData::Secure(t)),
+            Self::SecureEncapsulated(t) => Some(ErpData::SecureEncapsulated(t)),
+            Self::SecureTeachIn(t) => Some(ErpData::SecureTeachIn(t)),
+            Self::Other { rorg, data } => ErpData::new_other(rorg, data),
+        }
+    }
+}
+
+
+
+/// Appends all bytes from the slice to the array.
+///
+/// Panics if the array cannot hold all the bytes.
+fn push_all<const MAX_SIZE: usize>(array: &mut MaxArray<u8, MAX_SIZE>, bytes: &[u8]) {
+    for b in bytes {
+        array.push(*b).unwrap();
+    }
 }
 
 
@@ -139,6 +471,17 @@ pub trait ErpStatusByte {
     /// The value of the status byte contained in this ERP telegram.
     fn status_byte(&self) -> u8;
 
+    /// Replaces the value of the status byte contained in this ERP telegram.
+    fn set_status_byte(&mut self, status: u8);
+
+    /// Sets how often this telegram has been repeated, leaving the other bits of the status byte
+    /// untouched.
+    fn set_repeater_count(&mut self, repeater_count: RepeaterCount) {
+        let count_bits = u8::from(repeater_count) & 0b1111;
+        let status = (self.status_byte() & 0b1111_0000) | count_bits;
+        self.set_status_byte(status);
+    }
+
     /// Indicates whether this telegram has been or should be repeated.
     fn repeater_count(&self) -> RepeaterCount {
         (self.status_byte() & 0b1111).into()
@@ -188,9 +531,19 @@ impl RepeatedSwitchTelegram {
             status: bytes[5],
         })
     }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 6> {
+        let mut ret = MaxArray::new();
+        ret.push(self.data).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
 }
 impl ErpStatusByte for RepeatedSwitchTelegram {
     fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
 }
 
 /// One-Byte Telegram (1BS, 0xD5)
@@ -218,9 +571,19 @@ impl OneByteTelegram {
             status: bytes[5],
         })
     }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 6> {
+        let mut ret = MaxArray::new();
+        ret.push(self.data).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
 }
 impl ErpStatusByte for OneByteTelegram {
     fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
 }
 
 /// Four-Byte Telegram (4BS, 0xA5)
@@ -283,9 +646,19 @@ impl FourByteTelegram {
             status: bytes[8],
         })
     }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 9> {
+        let mut ret = MaxArray::new();
+        ret.push_u32_be(self.data).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
 }
 impl ErpStatusByte for FourByteTelegram {
     fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
 }
 
 /// Variable-Length Data Telegram (VLD, 0xD2)
@@ -296,6 +669,23 @@ pub struct VariableLengthTelegram {
     pub status: u8,
 }
 impl VariableLengthTelegram {
+    /// Creates a new variable-length telegram with the given data.
+    ///
+    /// Returns `None` if the data is empty or longer than [`MAXIMUM_VLD_DATA_LENGTH`].
+    pub fn new(data: &[u8], sender: u32, status: u8) -> Option<Self> {
+        if data.is_empty() || data.len() > MAXIMUM_VLD_DATA_LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            data: MaxArray::from_iter_or_panic(
+                data.iter().copied().peekable()
+            ),
+            sender,
+            status,
+        })
+    }
+
     /// Attempts to assemble this telegram from the given slice.
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
         // at least one byte is required
@@ -305,15 +695,25 @@ impl VariableLengthTelegram {
 
         Some(Self {
             data: MaxArray::from_iter_or_panic(
-                bytes[0..bytes.len()-5].iter().map(|b| *b).peekable()
+                bytes[0..bytes.len()-5].iter().copied().peekable()
             ),
             sender: u32::from_be_bytes(bytes[bytes.len()-5..bytes.len()-1].try_into().unwrap()),
             status: bytes[bytes.len()-1],
         })
     }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, {MAXIMUM_VLD_DATA_LENGTH + 5}> {
+        let mut ret = MaxArray::new();
+        push_all(&mut ret, self.data.as_slice());
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
 }
 impl ErpStatusByte for VariableLengthTelegram {
     fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
 }
 
 
@@ -392,9 +792,20 @@ impl SignalTelegram {
             status,
         })
     }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, {MAXIMUM_SIG_DATA_LENGTH + 6}> {
+        let mut ret = MaxArray::new();
+        ret.push(self.data.message_id()).unwrap();
+        self.data.push_data(&mut ret);
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
 }
 impl ErpStatusByte for SignalTelegram {
     fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
 }
 
 
@@ -445,6 +856,128 @@ pub enum SignalData {
     }
 }
 impl SignalData {
+    /// Creates signal data with a message ID not otherwise known to this module.
+    ///
+    /// Returns `None` if the data is longer than [`MAXIMUM_SIG_DATA_LENGTH`].
+    pub fn new_other(code: u8, data: &[u8]) -> Option<Self> {
+        if data.len() > MAXIMUM_SIG_DATA_LENGTH {
+            return None;
+        }
+
+        Some(Self::Other {
+            code,
+            data: MaxArray::from_iter_or_panic(
+                data.iter().copied().peekable()
+            ),
+        })
+    }
+
+    /// Returns the message ID of this signal.
+    pub fn message_id(&self) -> u8 {
+        match self {
+            Self::SmartAckMailboxEmpty => 0x01,
+            Self::SmartAckMailboxDoesNotExist => 0x02,
+            Self::SmartAckReset => 0x03,
+            Self::TriggerStatusMessage(_) => 0x04,
+            Self::LastUnicastAcknowledge => 0x05,
+            Self::EnergyPercentage(_) => 0x06,
+            Self::Revision { .. } => 0x07,
+            Self::Heartbeat => 0x08,
+            Self::RxWindowOpen => 0x09,
+            Self::RxChannelQuality { .. } => 0x0A,
+            Self::DutyCycleAvailable(_) => 0x0B,
+            Self::ConfigChanged => 0x0C,
+            Self::HarvesterEnergyQuality(_) => 0x0D,
+            Self::TxOff => 0x0E,
+            Self::TxOn => 0x0F,
+            Self::BackupBatteryStatus(_) => 0x10,
+            Self::LearnModeStatus { .. } => 0x11,
+            Self::ProductId { .. } => 0x12,
+            Self::Other { code, .. } => *code,
+        }
+    }
+
+    /// Appends the data of this signal (without the message ID) to the given array.
+    ///
+    /// Panics if the array cannot hold the data.
+    fn push_data<const MAX_SIZE: usize>(&self, ret: &mut MaxArray<u8, MAX_SIZE>) {
+        match self {
+            Self::SmartAckMailboxEmpty
+                | Self::SmartAckMailboxDoesNotExist
+                | Self::SmartAckReset
+                | Self::LastUnicastAcknowledge
+                | Self::Heartbeat
+                | Self::RxWindowOpen
+                | Self::ConfigChanged
+                | Self::TxOff
+                | Self::TxOn => {},
+            Self::TriggerStatusMessage(status_type) => {
+                ret.push_any(*status_type).unwrap();
+            },
+            Self::EnergyPercentage(percentage) => {
+                ret.push(*percentage).unwrap();
+            },
+            Self::Revision { software_version, hardware_version } => {
+                ret.push_u32_be(*software_version).unwrap();
+                ret.push_u32_be(*hardware_version).unwrap();
+            },
+            Self::RxChannelQuality {
+                request_sender,
+                worst_dbm,
+                best_dbm,
+                subtelegram_count,
+                max_repeater_level,
+            } => {
+                ret.push_u32_be(*request_sender).unwrap();
+                ret.push(worst_dbm.wrapping_neg() as u8).unwrap();
+                ret.push(best_dbm.wrapping_neg() as u8).unwrap();
+                ret.push(
+                    ((*subtelegram_count & 0xF) << 4)
+                    | (*max_repeater_level & 0xF)
+                ).unwrap();
+            },
+            Self::DutyCycleAvailable(available) => {
+                ret.push_any(*available).unwrap();
+            },
+            Self::HarvesterEnergyQuality(quality) => {
+                ret.push_any(*quality).unwrap();
+            },
+            Self::BackupBatteryStatus(status) => {
+                ret.push_any(*status).unwrap();
+            },
+            Self::LearnModeStatus {
+                link_table_full,
+                teach_request_message_reception_enabled,
+                learn_mode_type,
+                teach_result,
+                remaining_timeout_10s,
+                device_id,
+                device_eep,
+            } => {
+                let mut flags = 0;
+                if *link_table_full {
+                    flags |= 0b1000_0000;
+                }
+                if *teach_request_message_reception_enabled {
+                    flags |= 0b0100_0000;
+                }
+                flags |= (u8::from(*learn_mode_type) & 0b11) << 4;
+                flags |= u8::from(*teach_result) & 0b1111;
+                ret.push(flags).unwrap();
+                ret.push(*remaining_timeout_10s).unwrap();
+                ret.push_u32_be(*device_id).unwrap();
+                push_all(ret, device_eep);
+            },
+            Self::ProductId { manufacturer_id, product_reference } => {
+                ret.push_u16_be(*manufacturer_id).unwrap();
+                ret.push_u32_be(*product_reference).unwrap();
+            },
+            Self::Other { data, .. } => {
+                push_all(ret, data.as_slice());
+            },
+        }
+    }
+
     /// Assembles signal data from a message ID and a slice of optional data.
     pub fn from_id_and_slice(message_id: u8, signal_data: &[u8]) -> Option<Self> {
         match message_id {
@@ -565,7 +1098,7 @@ impl SignalData {
                     None
                 } else {
                     let data = MaxArray::from_iter_or_panic(
-                        signal_data.iter().map(|b| *b).peekable()
+                        signal_data.iter().copied().peekable()
                     );
                     Some(Self::Other {
                         code: other,
@@ -676,9 +1209,19 @@ impl UniversalTeachInTelegram {
             status: bytes[11],
         })
     }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 12> {
+        let mut ret = MaxArray::new();
+        push_all(&mut ret, &self.data.to_bytes());
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
 }
 impl ErpStatusByte for UniversalTeachInTelegram {
     fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
 }
 
 /// Data in a Universal Teach-In (UTE) telegram.
@@ -720,6 +1263,45 @@ impl UteData {
             }),
         }
     }
+
+    /// Encodes this UTE data into bytes.
+    pub fn to_bytes(&self) -> [u8; 7] {
+        let (bidirectional_eep, expects_teach_in_response, status, command, data) = match self {
+            Self::Request(req) => (
+                req.bidirectional_eep,
+                req.expects_teach_in_response,
+                u8::from(req.request_type),
+                0x00,
+                req.to_ute_data(),
+            ),
+            Self::Response(resp) => (
+                resp.bidirectional_eep,
+                false,
+                u8::from(resp.response_type),
+                0x01,
+                resp.to_ute_data(),
+            ),
+            Self::Other { bidirectional_eep, expects_teach_in_response, status, command, data } => (
+                *bidirectional_eep,
+                *expects_teach_in_response,
+                *status,
+                *command,
+                *data,
+            ),
+        };
+
+        let mut ret = [0u8; 7];
+        if bidirectional_eep {
+            ret[0] |= 0b1000_0000;
+        }
+        if expects_teach_in_response {
+            ret[0] |= 0b0100_0000;
+        }
+        ret[0] |= (status & 0b11) << 4;
+        ret[0] |= command & 0b1111;
+        ret[1..7].copy_from_slice(&data);
+        ret
+    }
 }
 
 
@@ -761,6 +1343,19 @@ impl UteRequest {
             eep_rorg,
         })
     }
+
+    /// Encodes the data bytes of this message (without the first byte of the UTE data).
+    pub fn to_ute_data(&self) -> [u8; 6] {
+        let manufacturer_bytes = self.manufacturer.to_le_bytes();
+        [
+            self.teach_in_channel,
+            manufacturer_bytes[0],
+            manufacturer_bytes[1],
+            self.eep_type,
+            self.eep_func,
+            self.eep_rorg,
+        ]
+    }
 }
 
 /// The type of Universal Teach-In (UTE) request.
@@ -809,6 +1404,19 @@ impl UteResponse {
             eep_rorg,
         })
     }
+
+    /// Encodes the data bytes of this message (without the first byte of the UTE data).
+    pub fn to_ute_data(&self) -> [u8; 6] {
+        let manufacturer_bytes = self.manufacturer.to_le_bytes();
+        [
+            self.teach_in_channel,
+            manufacturer_bytes[0],
+            manufacturer_bytes[1],
+            self.eep_type,
+            self.eep_func,
+            self.eep_rorg,
+        ]
+    }
 }
 
 /// The type of Universal Teach-In (UTE) response.
@@ -821,3 +1429,630 @@ pub enum UteResponseType {
     UnsupportedEep = 0b11,
     Other(u8),
 }
+
+
+/// Addressed Destination Telegram (ADT, 0xA6)
+///
+/// Encapsulates another telegram and adds the ID of the device to which it is addressed.
+///
+/// [`ErpData::parse`] does not decapsulate the contained telegram; use
+/// [`decapsulate`](Self::decapsulate) to obtain it, e.g. before decoding it using an EEP.
+///
+/// Defined in _EnOcean Equipment Profiles_ (section 3.1.7).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AddressingDestinationTelegram {
+    /// The RORG value of the encapsulated telegram.
+    pub inner_rorg: u8,
+
+    /// The data of the encapsulated telegram, without sender ID and status byte.
+    pub inner_data: MaxArray<u8, MAXIMUM_ADT_DATA_LENGTH>,
+
+    pub destination: u32,
+    pub sender: u32,
+    pub status: u8,
+}
+impl AddressingDestinationTelegram {
+    /// Encapsulates the given telegram, addressing it to the device with the given ID.
+    ///
+    /// Returns `None` if the telegram is itself an ADT or if its data is too long to be
+    /// encapsulated.
+    pub fn encapsulate(inner: &ErpData, destination: u32) -> Option<Self> {
+        if let ErpData::AddressingDestination(_) = inner {
+            return None;
+        }
+
+        // RORG, data, sender, status
+        let inner_bytes = inner.to_bytes();
+        let inner_slice = inner_bytes.as_slice();
+        if inner_slice.len() < 1 + 4 + 1 || inner_slice.len() > 1 + MAXIMUM_ADT_DATA_LENGTH + 4 + 1 {
+            return None;
+        }
+
+        Some(Self {
+            inner_rorg: inner_slice[0],
+            inner_data: MaxArray::from_iter_or_panic(
+                inner_slice[1..inner_slice.len()-5].iter().copied().peekable()
+            ),
+            destination,
+            sender: u32::from_be_bytes(inner_slice[inner_slice.len()-5..inner_slice.len()-1].try_into().unwrap()),
+            status: inner_slice[inner_slice.len()-1],
+        })
+    }
+
+    /// Decapsulates the contained telegram, which then carries the sender ID and status byte of
+    /// this telegram.
+    ///
+    /// Returns `None` if the contained telegram cannot be decoded.
+    pub fn decapsulate(&self) -> Option<ErpData> {
+        let mut inner_bytes: MaxArray<u8, {MAXIMUM_ADT_DATA_LENGTH + 5}> = MaxArray::new();
+        push_all(&mut inner_bytes, self.inner_data.as_slice());
+        inner_bytes.push_u32_be(self.sender).unwrap();
+        inner_bytes.push(self.status).unwrap();
+        ErpData::from_rorg_and_data(self.inner_rorg, inner_bytes.as_slice())
+    }
+
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        // inner RORG, destination, sender, status
+        if bytes.len() < 1 + 4 + 4 + 1 || bytes.len() > 1 + MAXIMUM_ADT_DATA_LENGTH + 4 + 4 + 1 {
+            return None;
+        }
+
+        Some(Self {
+            inner_rorg: bytes[0],
+            inner_data: MaxArray::from_iter_or_panic(
+                bytes[1..bytes.len()-9].iter().copied().peekable()
+            ),
+            destination: u32::from_be_bytes(bytes[bytes.len()-9..bytes.len()-5].try_into().unwrap()),
+            sender: u32::from_be_bytes(bytes[bytes.len()-5..bytes.len()-1].try_into().unwrap()),
+            status: bytes[bytes.len()-1],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, {MAXIMUM_ADT_DATA_LENGTH + 10}> {
+        let mut ret = MaxArray::new();
+        ret.push(self.inner_rorg).unwrap();
+        push_all(&mut ret, self.inner_data.as_slice());
+        ret.push_u32_be(self.destination).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for AddressingDestinationTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+
+/// Smart Acknowledge Learn Request telegram (SM_LRN_REQ, 0xC6)
+///
+/// Sent by a Smart Ack sensor that wishes to be learned into a controller. Repeaters that are
+/// candidates for becoming the postmaster of the sensor fill in the RSSI and their own ID.
+///
+/// Defined in _Smart Acknowledge_.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SmartAckLearnRequestTelegram {
+    /// The request code (5 bits).
+    pub request_code: u8,
+
+    /// The ID of the manufacturer of the sensor (11 bits).
+    pub manufacturer_id: u16,
+
+    /// The EEP of the sensor (RORG, FUNC and TYPE; 24 bits).
+    pub eep: u32,
+
+    /// The signal strength with which the request was received by the postmaster candidate.
+    pub rssi: u8,
+
+    /// The ID of the repeater that is offering to become the postmaster for the sensor.
+    pub postmaster_candidate_id: u32,
+
+    pub sender: u32,
+    pub status: u8,
+}
+impl SmartAckLearnRequestTelegram {
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 15 {
+            return None;
+        }
+
+        let code_and_manufacturer = u16::from_be_bytes(bytes[0..2].try_into().unwrap());
+        let request_code = (code_and_manufacturer >> 11).try_into().unwrap();
+        let manufacturer_id = code_and_manufacturer & 0b111_11111111;
+        let eep =
+            u32::from(bytes[2]) << 16
+            | u32::from(bytes[3]) << 8
+            | u32::from(bytes[4])
+        ;
+
+        Some(Self {
+            request_code,
+            manufacturer_id,
+            eep,
+            rssi: bytes[5],
+            postmaster_candidate_id: u32::from_be_bytes(bytes[6..10].try_into().unwrap()),
+            sender: u32::from_be_bytes(bytes[10..14].try_into().unwrap()),
+            status: bytes[14],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 15> {
+        let mut ret = MaxArray::new();
+        let code_and_manufacturer =
+            (u16::from(self.request_code & 0b11111) << 11)
+            | (self.manufacturer_id & 0b111_11111111);
+        ret.push_u16_be(code_and_manufacturer).unwrap();
+
+        // actually a 3-byte value; skip the MSB
+        let eep_bytes: [u8; 4] = self.eep.to_be_bytes();
+        push_all(&mut ret, &eep_bytes[1..4]);
+
+        ret.push(self.rssi).unwrap();
+        ret.push_u32_be(self.postmaster_candidate_id).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for SmartAckLearnRequestTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+/// Smart Acknowledge Learn Answer telegram (SM_LRN_ANS, 0xC7)
+///
+/// Sent by the controller in response to a learn request; delivered to the sensor by its
+/// postmaster.
+///
+/// Defined in _Smart Acknowledge_.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SmartAckLearnAnswerTelegram {
+    /// The time, in milliseconds, that the sensor should wait before reclaiming.
+    pub response_time: u16,
+
+    /// The outcome of the learn request.
+    pub confirm_code: SmartAckConfirmCode,
+
+    pub sender: u32,
+    pub status: u8,
+}
+impl SmartAckLearnAnswerTelegram {
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 8 {
+            return None;
+        }
+
+        Some(Self {
+            response_time: u16::from_be_bytes(bytes[0..2].try_into().unwrap()),
+            confirm_code: bytes[2].into(),
+            sender: u32::from_be_bytes(bytes[3..7].try_into().unwrap()),
+            status: bytes[7],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 8> {
+        let mut ret = MaxArray::new();
+        ret.push_u16_be(self.response_time).unwrap();
+        ret.push_any(self.confirm_code).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for SmartAckLearnAnswerTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+/// Smart Acknowledge Reclaim telegram (SM_REC, 0xA7)
+///
+/// Sent by a Smart Ack sensor to collect the messages waiting for it in its postmaster's
+/// mailbox.
+///
+/// Defined in _Smart Acknowledge_.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SmartAckReclaimTelegram {
+    pub data: u8,
+    pub sender: u32,
+    pub status: u8,
+}
+impl SmartAckReclaimTelegram {
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 6 {
+            return None;
+        }
+
+        Some(Self {
+            data: bytes[0],
+            sender: u32::from_be_bytes(bytes[1..5].try_into().unwrap()),
+            status: bytes[5],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 6> {
+        let mut ret = MaxArray::new();
+        ret.push(self.data).unwrap();
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for SmartAckReclaimTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+
+/// Remote Management telegram (SYS_EX, 0xC5)
+///
+/// A remote management message is split across one or more of these telegrams, all of which share
+/// the same sequence number. The message can be reassembled using
+/// [`SysExReassembler`](crate::esp3::remote_man::SysExReassembler).
+///
+/// Defined in _Remote Management_.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RemoteManagementTelegram {
+    /// The sequence number shared by all telegrams of a message (2 bits).
+    pub sequence: u8,
+
+    pub payload: RemoteManagementPayload,
+    pub sender: u32,
+    pub status: u8,
+}
+impl RemoteManagementTelegram {
+    /// The index of this telegram within its message (6 bits).
+    pub fn index(&self) -> u8 {
+        match &self.payload {
+            RemoteManagementPayload::First { .. } => 0,
+            RemoteManagementPayload::Continuation { index, .. } => *index,
+        }
+    }
+
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 14 {
+            return None;
+        }
+
+        let sequence = (bytes[0] & 0b1100_0000) >> 6;
+        let index = bytes[0] & 0b0011_1111;
+        let payload = if index == 0 {
+            // 9 bits data length, 11 bits manufacturer, 12 bits function
+            let header = u32::from_be_bytes(bytes[1..5].try_into().unwrap());
+            RemoteManagementPayload::First {
+                data_length: ((header >> 23) & 0b1_11111111).try_into().unwrap(),
+                manufacturer: ((header >> 12) & 0b111_11111111).try_into().unwrap(),
+                function: (header & 0b1111_11111111).try_into().unwrap(),
+                data: bytes[5..9].try_into().unwrap(),
+            }
+        } else {
+            RemoteManagementPayload::Continuation {
+                index,
+                data: bytes[1..9].try_into().unwrap(),
+            }
+        };
+
+        Some(Self {
+            sequence,
+            payload,
+            sender: u32::from_be_bytes(bytes[9..13].try_into().unwrap()),
+            status: bytes[13],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, 14> {
+        let mut ret = MaxArray::new();
+        ret.push(((self.sequence & 0b11) << 6) | (self.index() & 0b0011_1111)).unwrap();
+        match &self.payload {
+            RemoteManagementPayload::First { data_length, manufacturer, function, data } => {
+                let header =
+                    (u32::from(*data_length & 0b1_11111111) << 23)
+                    | (u32::from(*manufacturer & 0b111_11111111) << 12)
+                    | u32::from(*function & 0b1111_11111111);
+                ret.push_u32_be(header).unwrap();
+                push_all(&mut ret, data);
+            },
+            RemoteManagementPayload::Continuation { data, .. } => {
+                push_all(&mut ret, data);
+            },
+        }
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for RemoteManagementTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+/// Secure telegram (SEC, 0x30) or secure telegram with encapsulated RORG (SEC_ENCAPS, 0x31)
+///
+/// How the payload is divided into encrypted data, rolling code and MAC depends on the security
+/// level format agreed upon during secure teach-in. The payload can be verified and decrypted
+/// using [`crate::esp3::security`].
+///
+/// Defined in _Security of EnOcean Radio Networks_.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SecureTelegram {
+    pub payload: MaxArray<u8, MAXIMUM_SEC_DATA_LENGTH>,
+    pub sender: u32,
+    pub status: u8,
+}
+impl SecureTelegram {
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        // at least one byte is required
+        if bytes.len() < 6 || bytes.len() > 5 + MAXIMUM_SEC_DATA_LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            payload: MaxArray::from_iter_or_panic(
+                bytes[0..bytes.len()-5].iter().copied().peekable()
+            ),
+            sender: u32::from_be_bytes(bytes[bytes.len()-5..bytes.len()-1].try_into().unwrap()),
+            status: bytes[bytes.len()-1],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, {MAXIMUM_SEC_DATA_LENGTH + 5}> {
+        let mut ret = MaxArray::new();
+        push_all(&mut ret, self.payload.as_slice());
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for SecureTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+/// Secure teach-in telegram (SEC_TI, 0x35)
+///
+/// Announces the security level format, rolling code and key of a secure device. The information
+/// is usually split across two telegrams, which can be reassembled and decoded using
+/// [`SecureTeachInReassembler`](crate::esp3::security::SecureTeachInReassembler).
+///
+/// Defined in _Security of EnOcean Radio Networks_.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SecureTeachInTelegram {
+    pub teach_in_info: u8,
+    pub data: MaxArray<u8, MAXIMUM_SEC_TI_DATA_LENGTH>,
+    pub sender: u32,
+    pub status: u8,
+}
+impl SecureTeachInTelegram {
+    /// The index of this telegram within the teach-in (0 or 1).
+    pub fn index(&self) -> u8 {
+        (self.teach_in_info & 0b1100_0000) >> 6
+    }
+
+    /// The number of telegrams that make up the teach-in. Only meaningful in the first telegram.
+    pub fn count(&self) -> u8 {
+        (self.teach_in_info & 0b0011_0000) >> 4
+    }
+
+    /// Whether the rolling code and key are encrypted using the pre-shared key of the device.
+    pub fn is_psk_encrypted(&self) -> bool {
+        (self.teach_in_info & 0b0000_1000) != 0
+    }
+
+    /// The teach-in type (0 = non-specific, 1 = PTM).
+    pub fn teach_in_type(&self) -> u8 {
+        (self.teach_in_info & 0b0000_0110) >> 1
+    }
+
+    /// The teach-in information bit, whose meaning depends on the teach-in type.
+    pub fn info_bit(&self) -> bool {
+        (self.teach_in_info & 0b0000_0001) != 0
+    }
+
+    /// Attempts to assemble this telegram from the given slice.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 6 || bytes.len() > 6 + MAXIMUM_SEC_TI_DATA_LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            teach_in_info: bytes[0],
+            data: MaxArray::from_iter_or_panic(
+                bytes[1..bytes.len()-5].iter().copied().peekable()
+            ),
+            sender: u32::from_be_bytes(bytes[bytes.len()-5..bytes.len()-1].try_into().unwrap()),
+            status: bytes[bytes.len()-1],
+        })
+    }
+
+    /// Encodes this telegram (without the RORG value) into bytes.
+    pub fn to_bytes(&self) -> MaxArray<u8, {MAXIMUM_SEC_TI_DATA_LENGTH + 6}> {
+        let mut ret = MaxArray::new();
+        ret.push(self.teach_in_info).unwrap();
+        push_all(&mut ret, self.data.as_slice());
+        ret.push_u32_be(self.sender).unwrap();
+        ret.push(self.status).unwrap();
+        ret
+    }
+}
+impl ErpStatusByte for SecureTeachInTelegram {
+    fn status_byte(&self) -> u8 { self.status }
+    fn set_status_byte(&mut self, status: u8) { self.status = status; }
+}
+
+/// The payload of a Remote Management (SYS_EX) telegram.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RemoteManagementPayload {
+    /// The first telegram of a message (index 0), which describes the whole message.
+    First {
+        /// The length of the message data in bytes (9 bits).
+        data_length: u16,
+
+        /// The manufacturer ID (11 bits).
+        manufacturer: u16,
+
+        /// The function number (12 bits).
+        function: u16,
+
+        /// The first four bytes of the message data, padded with zeroes.
+        data: [u8; 4],
+    },
+
+    /// A subsequent telegram of a message.
+    Continuation {
+        /// The index of the telegram within the message (6 bits, nonzero).
+        index: u8,
+
+        /// The next eight bytes of the message data, padded with zeroes.
+        data: [u8; 8],
+    },
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{
+        AddressingDestinationTelegram, ErpContents, ErpData, ErpDataRef, ErpStatusByte, MAXIMUM_VLD_DATA_LENGTH,
+        RepeatedSwitchTelegram, RepeaterCount, SignalData, VariableLengthTelegram,
+    };
+
+    /// A repeated switch telegram.
+    pub(crate) fn switch(sender: u32, data: u8, status: u8) -> ErpData {
+        ErpData::RepeatedSwitch(RepeatedSwitchTelegram {
+            data,
+            sender,
+            status,
+        })
+    }
+
+    fn assert_round_trip(bytes: &[u8]) {
+        let erp = ErpData::from_slice(bytes).unwrap();
+        assert_eq!(erp.to_bytes().as_slice(), bytes);
+
+        let erp_ref = ErpDataRef::from_slice(bytes).unwrap();
+        assert_eq!(erp_ref.rorg_value(), bytes[0]);
+        assert_eq!(erp_ref.into_owned(), Some(erp));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // RPS
+        assert_round_trip(&[0xF6, 0x50, 0xFE, 0xF6, 0x1A, 0x2B, 0x30]);
+        // 1BS
+        assert_round_trip(&[0xD5, 0x09, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        // 4BS
+        assert_round_trip(&[0xA5, 0x00, 0x00, 0x55, 0x08, 0x01, 0x82, 0x5D, 0xAB, 0x00]);
+        // VLD
+        assert_round_trip(&[0xD2, 0x01, 0x1E, 0x64, 0x01, 0x94, 0xE3, 0xB9, 0x01]);
+        // SIG: energy percentage, RX channel quality, learn mode status, unknown
+        assert_round_trip(&[0xD0, 0x06, 0x42, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        assert_round_trip(&[0xD0, 0x0A, 0x01, 0x02, 0x03, 0x04, 0x5A, 0x3C, 0x32, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        assert_round_trip(&[0xD0, 0x11, 0xD4, 0x06, 0x01, 0x02, 0x03, 0x04, 0xA5, 0x02, 0x05, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        assert_round_trip(&[0xD0, 0x99, 0xAA, 0xBB, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        // UTE: request and response
+        assert_round_trip(&[0xD4, 0xA0, 0xFF, 0x46, 0x00, 0x01, 0x01, 0xD2, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        assert_round_trip(&[0xD4, 0x91, 0x01, 0x46, 0x00, 0x01, 0x01, 0xD2, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        // ADT encapsulating VLD
+        assert_round_trip(&[0xA6, 0xD2, 0x01, 0x1E, 0xAA, 0xBB, 0xCC, 0xDD, 0x01, 0x94, 0xE3, 0xB9, 0x01]);
+        // Smart Ack learn request, learn answer and reclaim
+        assert_round_trip(&[0xC6, 0xF8, 0x46, 0xA5, 0x02, 0x05, 0x3C, 0xAA, 0xBB, 0xCC, 0xDD, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        assert_round_trip(&[0xC7, 0x01, 0xF4, 0x11, 0xAA, 0xBB, 0xCC, 0xDD, 0x00]);
+        assert_round_trip(&[0xA7, 0x01, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        // SYS_EX: first and continuation telegram
+        assert_round_trip(&[0xC5, 0x40, 0x01, 0x7F, 0xF0, 0x06, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        assert_round_trip(&[0xC5, 0x41, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        // SEC_TI: first of two telegrams
+        assert_round_trip(&[0x35, 0x24, 0x8B, 0x00, 0x01, 0x00, 0x86, 0x9F, 0xAB, 0x7D, 0x01, 0x02, 0x03, 0x04, 0x00]);
+        // unknown RORG
+        assert_round_trip(&[0x42, 0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn test_checked_constructors() {
+        assert!(VariableLengthTelegram::new(&[], 0x01020304, 0x00).is_none());
+        assert!(VariableLengthTelegram::new(&[0x00; MAXIMUM_VLD_DATA_LENGTH], 0x01020304, 0x00).is_some());
+        assert!(VariableLengthTelegram::new(&[0x00; MAXIMUM_VLD_DATA_LENGTH + 1], 0x01020304, 0x00).is_none());
+
+        assert!(SignalData::new_other(0x99, &[0x00; 13]).is_some());
+        assert!(SignalData::new_other(0x99, &[0x00; 14]).is_none());
+
+        let erp = ErpData::new_other(0x42, &[0x01, 0x02]).unwrap();
+        assert_eq!(erp.to_bytes().as_slice(), &[0x42, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_set_repeater_count() {
+        let mut vld = VariableLengthTelegram::new(&[0x01], 0x01020304, 0x80).unwrap();
+        vld.set_repeater_count(RepeaterCount::RepeatedOnce);
+        assert_eq!(vld.status, 0x81);
+        assert_eq!(vld.repeater_count(), RepeaterCount::RepeatedOnce);
+        vld.set_repeater_count(RepeaterCount::DoNotRepeat);
+        assert_eq!(vld.status, 0x8F);
+
+        let erp = ErpData::VariableLength(vld);
+        assert_eq!(erp.to_bytes().as_slice(), &[0xD2, 0x01, 0x01, 0x02, 0x03, 0x04, 0x8F]);
+    }
+
+    #[test]
+    fn test_contents() {
+        let contents = ErpContents::new(&switch(0x01020304, 0x50, 0x30)).unwrap();
+        assert_eq!(contents.rorg_value(), 0xF6);
+        assert_eq!(ErpContents::new(&switch(0x01020304, 0x50, 0x31)), Some(contents.clone()));
+        assert_ne!(ErpContents::new(&switch(0x01020304, 0x70, 0x30)), Some(contents.clone()));
+        assert_eq!(contents.to_telegram(0x31).as_ref(), Some(&switch(0x01020304, 0x50, 0x31)));
+
+        let erp = ErpData::new_other(0x42, &[0x01, 0x02]).unwrap();
+        assert_eq!(ErpContents::new(&erp), None);
+    }
+
+    #[test]
+    fn test_addressing_destination() {
+        let vld = ErpData::VariableLength(
+            VariableLengthTelegram::new(&[0x01, 0x1E], 0x0194E3B9, 0x01).unwrap()
+        );
+        let adt = AddressingDestinationTelegram::encapsulate(&vld, 0xAABBCCDD).unwrap();
+        assert_eq!(adt.inner_rorg, 0xD2);
+        assert_eq!(adt.inner_data.as_slice(), &[0x01, 0x1E]);
+        assert_eq!(adt.destination, 0xAABBCCDD);
+        assert_eq!(adt.sender, 0x0194E3B9);
+        assert_eq!(adt.status, 0x01);
+        assert_eq!(adt.decapsulate(), Some(vld));
+
+        let wrapped = ErpData::AddressingDestination(adt);
+        assert_eq!(
+            wrapped.to_bytes().as_slice(),
+            &[0xA6, 0xD2, 0x01, 0x1E, 0xAA, 0xBB, 0xCC, 0xDD, 0x01, 0x94, 0xE3, 0xB9, 0x01],
+        );
+
+        // ADTs cannot be nested
+        assert!(AddressingDestinationTelegram::encapsulate(&wrapped, 0x01020304).is_none());
+
+        // too short to contain a destination, sender and status
+        assert!(ErpData::from_slice(&[0xA6, 0xD2, 0x01, 0x02, 0x03, 0x04, 0x01, 0x02, 0x03]).is_none());
+    }
+
+    #[test]
+    fn test_smart_ack_learn_request() {
+        let erp = ErpData::from_slice(&[0xC6, 0xF8, 0x46, 0xA5, 0x02, 0x05, 0x3C, 0xAA, 0xBB, 0xCC, 0xDD, 0x01, 0x02, 0x03, 0x04, 0x00]).unwrap();
+        if let ErpData::SmartAckLearnRequest(req) = erp {
+            assert_eq!(req.request_code, 0x1F);
+            assert_eq!(req.manufacturer_id, 0x046);
+            assert_eq!(req.eep, 0xA50205);
+            assert_eq!(req.rssi, 0x3C);
+            assert_eq!(req.postmaster_candidate_id, 0xAABBCCDD);
+            assert_eq!(req.sender, 0x01020304);
+        } else {
+            panic!("expected SM_LRN_REQ, got {:?}", erp);
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Decoding and encoding of EnOcean Radio Protocol 2 (ERP2) frames.
+//!
+//! When the transceiver operates in [`TransceiverMode::Advanced`](crate::esp3::TransceiverMode),
+//! received telegrams are delivered as [`Esp3Packet::RadioErp2`] whose data is the raw ERP2 frame
+//! (without the leading length byte). This module converts these frames into the same [`ErpData`]
+//! types that are used for ERP1.
+//!
+//! Specified in _EnOcean Radio Protocol 2_.
+
+
+use crate::crc8::crc8_ccitt;
+use crate::esp3::{Esp3Packet, MAX_DATA_LENGTH};
+use crate::esp3::erp::ErpData;
+use crate::max_array::MaxArray;
+use crate::max_array_ext::MaxArrayPushIntExt;
+
+
+/// The maximum length of the optional data of an ERP2 frame.
+pub const MAXIMUM_ERP2_OPTIONAL_DATA_LENGTH: usize = 15;
+
+/// The telegram type value signalling that an extended telegram type byte follows.
+const EXTENDED_TELEGRAM_TYPE: u8 = 0b1111;
+
+
+/// Telegram types and the RORG values they correspond to.
+const TELEGRAM_TYPES: [(u8, u8); 11] = [
+    (0b0000, 0xF6), // RPS
+    (0b0001, 0xD5), // 1BS
+    (0b0010, 0xA5), // 4BS
+    (0b0011, 0xD0), // SIG
+    (0b0100, 0xD2), // VLD
+    (0b0101, 0xD4), // UTE
+    (0b0110, 0xD1), // MSC
+    (0b0111, 0x30), // SEC
+    (0b1000, 0x31), // SEC_ENCAPS
+    (0b1001, 0x35), // SEC_TI
+    (0b1010, 0xB3), // GP_SD
+];
+
+/// Extended telegram types and the RORG values they correspond to.
+const EXTENDED_TELEGRAM_TYPES: [(u8, u8); 8] = [
+    (0x00, 0xC5), // SYS_EX
+    (0x01, 0xC6), // Smart Ack learn request
+    (0x02, 0xC7), // Smart Ack learn answer
+    (0x03, 0x40), // CDM
+    (0x04, 0x32), // SECD
+    (0x05, 0xB0), // GP_TI
+    (0x06, 0xB1), // GP_TR
+    (0x07, 0xB2), // GP_CD
+];
+
+
+/// The addresses contained in an ERP2 frame, as selected by its address control bits.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Erp2Address {
+    /// 24-bit originator ID, no destination ID (0b000).
+    Originator24(u32),
+
+    /// 32-bit originator ID, no destination ID (0b001).
+    Originator32(u32),
+
+    /// 32-bit originator ID and 32-bit destination ID (0b010).
+    Originator32Destination32 { originator: u32, destination: u32 },
+
+    /// 48-bit originator ID, no destination ID (0b011).
+    Originator48(u64),
+}
+impl Erp2Address {
+    /// The value of the address control bits.
+    pub fn address_control(&self) -> u8 {
+        match self {
+            Self::Originator24(_) => 0b000,
+            Self::Originator32(_) => 0b001,
+            Self::Originator32Destination32 { .. } => 0b010,
+            Self::Originator48(_) => 0b011,
+        }
+    }
+
+    /// The ID of the device that sent the telegram.
+    pub fn originator(&self) -> u64 {
+        match self {
+            Self::Originator24(o) => (*o).into(),
+            Self::Originator32(o) => (*o).into(),
+            Self::Originator32Destination32 { originator, .. } => (*originator).into(),
+            Self::Originator48(o) => *o,
+        }
+    }
+
+    /// The ID of the device to which the telegram is addressed, if any.
+    pub fn opt_destination(&self) -> Option<u32> {
+        match self {
+            Self::Originator32Destination32 { destination, .. } => Some(*destination),
+            _ => None,
+        }
+    }
+
+    /// The lengths of the originator and destination IDs, in bytes, for the given address control
+    /// bits.
+    fn lengths(address_control: u8) -> Option<(usize, usize)> {
+        match address_control {
+            0b000 => Some((3, 0)),
+            0b001 => Some((4, 0)),
+            0b010 => Some((4, 4)),
+            0b011 => Some((6, 0)),
+            _ => None,
+        }
+    }
+}
+
+
+/// A telegram received or to be sent using ERP2.
+///
+/// ERP2 frames have no status byte; when converting to [`ErpData`], the status byte contains only
+/// the repeater count (in particular, the T21 and NU bits of RPS telegrams are cleared). The sender
+/// ID of the [`ErpData`] is the originator ID truncated to 32 bits; the full originator ID is
+/// available in [`address`](Self::address).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Erp2Telegram {
+    pub address: Erp2Address,
+    pub repeater_count: u8,
+    pub optional_data: MaxArray<u8, MAXIMUM_ERP2_OPTIONAL_DATA_LENGTH>,
+    pub data: ErpData,
+}
+impl Erp2Telegram {
+    /// Attempts to decode an ERP2 frame, including its trailing CRC8.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        // header + CRC
+        if bytes.len() < 2 {
+            return None;
+        }
+        let (frame, crc) = bytes.split_at(bytes.len() - 1);
+        if crc8_ccitt(frame) != crc[0] {
+            return None;
+        }
+
+        let header = frame[0];
+        let address_control = (header & 0b1110_0000) >> 5;
+        let has_extended_header = (header & 0b0001_0000) != 0;
+        let telegram_type = header & 0b0000_1111;
+        let mut pos = 1;
+
+        let (repeater_count, optional_length) = if has_extended_header {
+            let extended_header = *frame.get(pos)?;
+            pos += 1;
+            ((extended_header & 0b1111_0000) >> 4, usize::from(extended_header & 0b0000_1111))
+        } else {
+            (0, 0)
+        };
+
+        let rorg = if telegram_type == EXTENDED_TELEGRAM_TYPE {
+            let extended_type = *frame.get(pos)?;
+            pos += 1;
+            EXTENDED_TELEGRAM_TYPES.iter()
+                .find(|(t, _)| *t == extended_type)?
+                .1
+        } else {
+            TELEGRAM_TYPES.iter()
+                .find(|(t, _)| *t == telegram_type)?
+                .1
+        };
+
+        let (originator_length, destination_length) = Erp2Address::lengths(address_control)?;
+        if frame.len() < pos + originator_length + destination_length + optional_length {
+            return None;
+        }
+        let mut originator_be = [0u8; 8];
+        originator_be[8-originator_length..].copy_from_slice(&frame[pos..pos+originator_length]);
+        let originator = u64::from_be_bytes(originator_be);
+        pos += originator_length;
+        let address = match address_control {
+            0b000 => Erp2Address::Originator24(originator as u32),
+            0b001 => Erp2Address::Originator32(originator as u32),
+            0b010 => Erp2Address::Originator32Destination32 {
+                originator: originator as u32,
+                destination: u32::from_be_bytes(frame[pos..pos+4].try_into().unwrap()),
+            },
+            _ => Erp2Address::Originator48(originator),
+        };
+        pos += destination_length;
+
+        let data_end = frame.len() - optional_length;
+        let data_bytes = &frame[pos..data_end];
+        let optional_data = MaxArray::from_iter_or_panic(
+            frame[data_end..].iter().copied().peekable()
+        );
+
+        // assemble the equivalent ERP1 telegram
+        let mut erp1: MaxArray<u8, {MAX_DATA_LENGTH + 5}> = MaxArray::new();
+        erp1.push(rorg).unwrap();
+        for b in data_bytes {
+            erp1.push(*b).ok()?;
+        }
+        erp1.push_u32_be(address.originator() as u32).ok()?;
+        erp1.push(repeater_count).ok()?;
+        let data = ErpData::from_slice(erp1.as_slice())?;
+
+        Some(Self {
+            address,
+            repeater_count,
+            optional_data,
+            data,
+        })
+    }
+
+    /// Decodes the ERP2 frame contained in an [`Esp3Packet::RadioErp2`].
+    pub fn from_packet(packet: &Esp3Packet) -> Option<Self> {
+        match packet {
+            Esp3Packet::RadioErp2 { data, .. } => Self::from_slice(data.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Encodes this telegram into an ERP2 frame, including its trailing CRC8.
+    ///
+    /// The sender ID and status byte of [`data`](Self::data) are ignored. Returns `None` if the
+    /// telegram type cannot be transmitted using ERP2 or the repeater count does not fit.
+    pub fn to_bytes(&self) -> Option<MaxArray<u8, MAX_DATA_LENGTH>> {
+        if self.repeater_count > 0b1111 {
+            return None;
+        }
+
+        let rorg = self.data.rorg_value();
+        let (telegram_type, opt_extended_type) = match TELEGRAM_TYPES.iter().find(|(_, r)| *r == rorg) {
+            Some((t, _)) => (*t, None),
+            None => {
+                let (t, _) = EXTENDED_TELEGRAM_TYPES.iter()
+                    .find(|(_, r)| *r == rorg)?;
+                (EXTENDED_TELEGRAM_TYPE, Some(*t))
+            },
+        };
+
+        let has_extended_header = self.repeater_count > 0 || self.optional_data.len() > 0;
+        let mut header = (self.address.address_control() << 5) | telegram_type;
+        if has_extended_header {
+            header |= 0b0001_0000;
+        }
+
+        let mut ret = MaxArray::new();
+        ret.push(header).unwrap();
+        if has_extended_header {
+            let optional_length: u8 = self.optional_data.len().try_into().unwrap();
+            ret.push((self.repeater_count << 4) | optional_length).unwrap();
+        }
+        if let Some(extended_type) = opt_extended_type {
+            ret.push(extended_type).unwrap();
+        }
+
+        let (originator_length, _) = Erp2Address::lengths(self.address.address_control()).unwrap();
+        for b in &self.address.originator().to_be_bytes()[8-originator_length..] {
+            ret.push(*b).unwrap();
+        }
+        if let Some(destination) = self.address.opt_destination() {
+            ret.push_u32_be(destination).unwrap();
+        }
+
+        // strip RORG, sender ID and status byte
+        let erp1 = self.data.to_bytes();
+        let erp1_bytes = erp1.as_slice();
+        if erp1_bytes.len() < 1 + 5 {
+            return None;
+        }
+        for b in erp1_bytes[1..erp1_bytes.len()-5].iter().chain(self.optional_data.iter()) {
+            ret.push(*b).ok()?;
+        }
+
+        let crc = crc8_ccitt(ret.as_slice());
+        ret.push(crc).ok()?;
+        Some(ret)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Erp2Address, Erp2Telegram};
+    use crate::crc8::crc8_ccitt;
+    use crate::esp3::erp::{
+        ErpData, FourByteTelegram, RepeatedSwitchTelegram, SmartAckLearnAnswerTelegram,
+    };
+    use crate::max_array::MaxArray;
+
+    fn with_crc(frame: &[u8]) -> MaxArray<u8, 32> {
+        let mut ret = MaxArray::from_iter_or_panic(frame.iter().copied().peekable());
+        ret.push(crc8_ccitt(frame)).unwrap();
+        ret
+    }
+
+    #[test]
+    fn test_decode_rps() {
+        // 32-bit originator, no extended header, RPS
+        let frame = with_crc(&[0x20, 0xFE, 0xF6, 0x1A, 0x2B, 0x50]);
+        let telegram = Erp2Telegram::from_slice(frame.as_slice()).unwrap();
+        assert_eq!(telegram.address, Erp2Address::Originator32(0xFEF61A2B));
+        assert_eq!(telegram.repeater_count, 0);
+        assert_eq!(
+            telegram.data,
+            ErpData::RepeatedSwitch(RepeatedSwitchTelegram {
+                data: 0x50,
+                sender: 0xFEF61A2B,
+                status: 0x00,
+            }),
+        );
+        assert_eq!(telegram.to_bytes().unwrap().as_slice(), frame.as_slice());
+
+        // corrupted CRC
+        let mut corrupted = frame.clone();
+        let last = corrupted.len() - 1;
+        corrupted.as_mut_slice()[last] ^= 0xFF;
+        assert_eq!(Erp2Telegram::from_slice(corrupted.as_slice()), None);
+    }
+
+    #[test]
+    fn test_decode_extended() {
+        // 48-bit originator, extended header (repeated once, 2 bytes of optional data), 4BS
+        let frame = with_crc(&[
+            0x72, 0x12, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x00, 0x55, 0x08, 0xAA, 0xBB,
+        ]);
+        let telegram = Erp2Telegram::from_slice(frame.as_slice()).unwrap();
+        assert_eq!(telegram.address, Erp2Address::Originator48(0x010203040506));
+        assert_eq!(telegram.address.originator(), 0x010203040506);
+        assert_eq!(telegram.repeater_count, 1);
+        assert_eq!(telegram.optional_data.as_slice(), &[0xAA, 0xBB]);
+        assert_eq!(
+            telegram.data,
+            ErpData::FourByte(FourByteTelegram {
+                data: 0x00005508,
+                sender: 0x03040506,
+                status: 0x01,
+            }),
+        );
+        assert_eq!(telegram.to_bytes().unwrap().as_slice(), frame.as_slice());
+    }
+
+    #[test]
+    fn test_extended_telegram_type() {
+        let telegram = Erp2Telegram {
+            address: Erp2Address::Originator32Destination32 {
+                originator: 0x01020304,
+                destination: 0xAABBCCDD,
+            },
+            repeater_count: 0,
+            optional_data: MaxArray::new(),
+            data: ErpData::SmartAckLearnAnswer(SmartAckLearnAnswerTelegram {
+                response_time: 500,
+                confirm_code: 0x11.into(),
+                sender: 0x01020304,
+                status: 0x00,
+            }),
+        };
+        let frame = telegram.to_bytes().unwrap();
+        assert_eq!(&frame.as_slice()[0..2], &[0x4F, 0x02]);
+        assert_eq!(frame.len(), 2 + 4 + 4 + 3 + 1);
+        assert_eq!(Erp2Telegram::from_slice(frame.as_slice()), Some(telegram));
+    }
+}
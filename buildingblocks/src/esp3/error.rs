@@ -0,0 +1,248 @@
+//! Errors reported when decoding ESP3 packets and radio telegrams.
+
+
+use core::fmt;
+
+
+/// The reason why decoding failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParseErrorKind {
+    /// The buffer ends before the packet does.
+    Truncated { needed: usize, available: usize },
+
+    /// The buffer continues after the end of the packet.
+    TrailingBytes { expected: usize, actual: usize },
+
+    /// The packet does not start with the sync byte.
+    MissingSyncByte { found: u8 },
+
+    /// The CRC8 checksum of the header does not match.
+    HeaderCrcMismatch { calculated: u8, received: u8 },
+
+    /// The CRC8 checksum of the data and optional data does not match.
+    DataCrcMismatch { calculated: u8, received: u8 },
+
+    /// The data is longer than [`MAX_DATA_LENGTH`](crate::esp3::MAX_DATA_LENGTH) allows.
+    DataTooLong { length: usize, maximum: usize },
+
+    /// The data is empty but must start with a code (return code, event code, command code or
+    /// RORG).
+    MissingCode,
+
+    /// The code is not known in this context.
+    UnknownCode { code: u8 },
+
+    /// The data has a different length than its packet type and code require.
+    UnexpectedLength { expected: usize, actual: usize },
+
+    /// The data is shorter than its packet type and code require.
+    TooShort { minimum: usize, actual: usize },
+
+    /// The data consists of entries of a fixed length, the last of which is incomplete.
+    PartialEntry { entry_length: usize, actual: usize },
+
+    /// The radio telegram does not have the structure required by its RORG.
+    InvalidTelegram { rorg: u8, length: usize },
+}
+
+/// An error that occurred while decoding, along with the offset of the offending byte.
+///
+/// The offset is relative to the start of the slice passed to the decoding function.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParseError {
+    pub offset: usize,
+    pub kind: ParseErrorKind,
+}
+impl ParseError {
+    pub const fn new(offset: usize, kind: ParseErrorKind) -> Self {
+        Self {
+            offset,
+            kind,
+        }
+    }
+
+    /// Makes the offset relative to an earlier position, `base` bytes before the current one.
+    pub const fn offset_by(self, base: usize) -> Self {
+        Self::new(base + self.offset, self.kind)
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at offset {}: ", self.offset)?;
+        match self.kind {
+            ParseErrorKind::Truncated { needed, available }
+                => write!(f, "truncated ({} bytes needed, {} available)", needed, available),
+            ParseErrorKind::TrailingBytes { expected, actual }
+                => write!(f, "trailing bytes ({} bytes expected, {} given)", expected, actual),
+            ParseErrorKind::MissingSyncByte { found }
+                => write!(f, "expected sync byte, found 0x{:02X}", found),
+            ParseErrorKind::HeaderCrcMismatch { calculated, received }
+                => write!(f, "header CRC mismatch (calculated 0x{:02X}, received 0x{:02X})", calculated, received),
+            ParseErrorKind::DataCrcMismatch { calculated, received }
+                => write!(f, "data CRC mismatch (calculated 0x{:02X}, received 0x{:02X})", calculated, received),
+            ParseErrorKind::DataTooLong { length, maximum }
+                => write!(f, "data too long ({} bytes, at most {} supported)", length, maximum),
+            ParseErrorKind::MissingCode
+                => write!(f, "missing code"),
+            ParseErrorKind::UnknownCode { code }
+                => write!(f, "unknown code 0x{:02X}", code),
+            ParseErrorKind::UnexpectedLength { expected, actual }
+                => write!(f, "unexpected data length ({} bytes expected, {} given)", expected, actual),
+            ParseErrorKind::TooShort { minimum, actual }
+                => write!(f, "data too short ({} bytes needed, {} given)", minimum, actual),
+            ParseErrorKind::PartialEntry { entry_length, actual }
+                => write!(f, "partial entry ({} bytes is not a multiple of {})", actual, entry_length),
+            ParseErrorKind::InvalidTelegram { rorg, length }
+                => write!(f, "invalid telegram for RORG 0x{:02X} ({} bytes)", rorg, length),
+        }
+    }
+}
+
+
+/// Checks that the data has exactly the expected length.
+///
+/// If not, the error points at the first superfluous byte or just past the last byte.
+pub(crate) fn expect_length(data_slice: &[u8], expected: usize) -> Result<(), ParseError> {
+    let actual = data_slice.len();
+    if actual != expected {
+        return Err(ParseError::new(actual.min(expected), ParseErrorKind::UnexpectedLength { expected, actual }));
+    }
+    Ok(())
+}
+
+/// Checks that the data has at least the given length.
+pub(crate) fn expect_min_length(data_slice: &[u8], minimum: usize) -> Result<(), ParseError> {
+    let actual = data_slice.len();
+    if actual < minimum {
+        return Err(ParseError::new(actual, ParseErrorKind::TooShort { minimum, actual }));
+    }
+    Ok(())
+}
+
+/// Checks that the data consists of whole entries of the given length.
+///
+/// If not, the error points at the start of the incomplete entry.
+pub(crate) fn expect_entries(data_slice: &[u8], entry_length: usize) -> Result<(), ParseError> {
+    let actual = data_slice.len();
+    let partial = actual % entry_length;
+    if partial != 0 {
+        return Err(ParseError::new(actual - partial, ParseErrorKind::PartialEntry { entry_length, actual }));
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, ParseErrorKind};
+    use crate::crc8::crc8_ccitt;
+    use crate::esp3::{CommandData, Esp3Packet, HEADER_LENGTH};
+    use crate::esp3::erp::ErpData;
+    use crate::esp3::response_data::CommonCommandResponse;
+    use crate::max_array::MaxArray;
+    use core::fmt::Write;
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_packet_errors() {
+        let packet = Esp3Packet::CommonCommand(CommandData::CoRdVersion)
+            .to_packet().unwrap();
+        let bytes = packet.as_slice();
+        assert!(Esp3Packet::parse(bytes).is_ok());
+
+        assert_eq!(
+            Esp3Packet::parse(&bytes[0..4]),
+            Err(ParseError::new(4, ParseErrorKind::Truncated { needed: 7, available: 4 })),
+        );
+        assert_eq!(
+            Esp3Packet::parse(&bytes[0..bytes.len()-1]),
+            Err(ParseError::new(7, ParseErrorKind::Truncated { needed: 8, available: 7 })),
+        );
+
+        let mut trailing = packet.clone();
+        trailing.push(0x00).unwrap();
+        assert_eq!(
+            Esp3Packet::parse(trailing.as_slice()),
+            Err(ParseError::new(8, ParseErrorKind::TrailingBytes { expected: 8, actual: 9 })),
+        );
+
+        let mut no_sync = packet.clone();
+        no_sync.as_mut_slice()[0] = 0xAA;
+        assert_eq!(
+            Esp3Packet::parse(no_sync.as_slice()),
+            Err(ParseError::new(0, ParseErrorKind::MissingSyncByte { found: 0xAA })),
+        );
+
+        let mut bad_header = packet.clone();
+        bad_header.as_mut_slice()[5] ^= 0xFF;
+        assert_eq!(
+            Esp3Packet::parse(bad_header.as_slice()).map_err(|e| e.offset),
+            Err(5),
+        );
+
+        let mut bad_data = packet.clone();
+        bad_data.as_mut_slice()[7] ^= 0xFF;
+        assert_eq!(
+            Esp3Packet::parse(bad_data.as_slice()),
+            Err(ParseError::new(7, ParseErrorKind::DataCrcMismatch {
+                calculated: bytes[7],
+                received: bytes[7] ^ 0xFF,
+            })),
+        );
+
+        // CO_WR_RESET with a superfluous byte
+        let mut invalid = [0x55, 0x00, 0x02, 0x00, 0x05, 0x00, 0x02, 0x42, 0x00];
+        invalid[5] = crc8_ccitt(&invalid[1..5]);
+        invalid[8] = crc8_ccitt(&invalid[6..8]);
+        let error = Esp3Packet::parse(&invalid).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(HEADER_LENGTH + 1, ParseErrorKind::UnexpectedLength { expected: 0, actual: 1 }),
+        );
+
+        let mut message: MaxArray<u8, 128> = MaxArray::new();
+        write!(message, "{}", error).unwrap();
+        assert_eq!(
+            message.as_slice(),
+            b"at offset 7: unexpected data length (0 bytes expected, 1 given)",
+        );
+
+        // CO_READY without its wakeup cause
+        let mut truncated_event = [0x55, 0x00, 0x01, 0x00, 0x04, 0x00, 0x04, 0x00];
+        truncated_event[5] = crc8_ccitt(&truncated_event[1..5]);
+        truncated_event[7] = crc8_ccitt(&truncated_event[6..7]);
+        assert_eq!(
+            Esp3Packet::parse(&truncated_event),
+            Err(ParseError::new(HEADER_LENGTH + 1, ParseErrorKind::UnexpectedLength { expected: 1, actual: 0 })),
+        );
+    }
+
+    #[test]
+    fn test_response_errors() {
+        // CO_RD_IDBASE: a byte of the base ID is missing
+        assert_eq!(
+            CommonCommandResponse::parse_for_command(8, &[0xFF, 0x80, 0x00], &[]),
+            Err(ParseError::new(3, ParseErrorKind::UnexpectedLength { expected: 4, actual: 3 })),
+        );
+
+        // CO_RD_FILTER: the second filter is incomplete
+        assert_eq!(
+            CommonCommandResponse::parse_for_command(15, &[0x00, 0x01, 0x02, 0x03, 0x04, 0x00, 0x01], &[]),
+            Err(ParseError::new(5, ParseErrorKind::PartialEntry { entry_length: 5, actual: 7 })),
+        );
+
+        assert_eq!(
+            CommonCommandResponse::parse_for_command(0xEE, &[], &[]),
+            Err(ParseError::new(0, ParseErrorKind::UnknownCode { code: 0xEE })),
+        );
+    }
+
+    #[test]
+    fn test_telegram_errors() {
+        assert_eq!(ErpData::parse(&[]), Err(ParseError::new(0, ParseErrorKind::MissingCode)));
+        assert_eq!(
+            ErpData::parse(&[0xA5, 0x00, 0x00]),
+            Err(ParseError::new(1, ParseErrorKind::InvalidTelegram { rorg: 0xA5, length: 3 })),
+        );
+    }
+}
@@ -0,0 +1,397 @@
+//! Management of the filter table of an ESP3 device.
+//!
+//! [`FilterManager`] keeps the desired set of filters on the host, reads back the filters stored
+//! in the device and issues the commands required to reconcile the two via an [`Esp3Session`].
+//! Whenever the device reports that it has been reset, the filters are read back and reconciled
+//! again.
+
+
+use crate::esp3::{
+    CommandData, Esp3Packet, Esp3PacketRef, EventData, FilterAction, FilterCriterion, FilterEntry, FilterOperator,
+};
+use crate::esp3::response_data::CommonCommandResponse;
+use crate::esp3::session::{DecodedResponse, Esp3Request, Esp3Session, SessionError, SessionEvent};
+use crate::max_array::MaxArray;
+
+
+/// How far the filters in the device are known to match the desired filters.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FilterSyncState {
+    /// The filters in the device have to be read back.
+    NeedsRead,
+
+    /// The filters in the device are being read back.
+    Reading { request_id: u32 },
+
+    /// Commands to reconcile the filters are being sent to the device.
+    Applying,
+
+    /// The filters in the device match the desired filters.
+    Synchronized,
+}
+
+
+/// Keeps up to `FILTERS` desired filters and reconciles them with the filters stored in the
+/// device.
+///
+/// All filters share the same action, and are combined using the same operator. The device does
+/// not report the action of the filters it has stored; filters with the same criterion and value
+/// are considered equal regardless of their action.
+#[derive(Clone, Debug)]
+pub struct FilterManager<const FILTERS: usize> {
+    desired: MaxArray<FilterEntry, FILTERS>,
+    action: FilterAction,
+    operator: FilterOperator,
+    state: FilterSyncState,
+
+    // the filters assumed to be stored in the device, once the commands sent so far have been
+    // executed; unknown if the device has reported more filters than fit
+    opt_device: Option<MaxArray<FilterEntry, FILTERS>>,
+    opt_enabled: Option<bool>,
+    opt_last_request_id: Option<u32>,
+}
+impl<const FILTERS: usize> FilterManager<FILTERS> {
+    /// Creates a new manager with no desired filters.
+    pub const fn new(action: FilterAction, operator: FilterOperator) -> Self {
+        Self {
+            desired: MaxArray::new(),
+            action,
+            operator,
+            state: FilterSyncState::NeedsRead,
+            opt_device: None,
+            opt_enabled: None,
+            opt_last_request_id: None,
+        }
+    }
+
+    /// Creates a new manager that only forwards telegrams from the senders added using
+    /// [`FilterManager::allow_sender`].
+    pub const fn new_sender_allowlist() -> Self {
+        Self::new(FilterAction::Forward, FilterOperator::Or)
+    }
+
+    /// The desired filters.
+    pub fn desired(&self) -> &[FilterEntry] {
+        self.desired.as_slice()
+    }
+
+    /// How far the filters in the device are known to match the desired filters.
+    pub fn state(&self) -> FilterSyncState {
+        self.state
+    }
+
+    /// Adds a filter to the desired filters. Returns `false` if the list is full.
+    pub fn add_filter(&mut self, entry: FilterEntry) -> bool {
+        if self.desired.iter().any(|e| *e == entry) {
+            return true;
+        }
+        if self.desired.push(entry).is_err() {
+            return false;
+        }
+        self.desired_changed();
+        true
+    }
+
+    /// Removes a filter from the desired filters. Returns whether it was one of them.
+    pub fn remove_filter(&mut self, entry: FilterEntry) -> bool {
+        let Some(index) = self.desired.iter().position(|e| *e == entry) else {
+            return false;
+        };
+        self.desired.as_mut_slice()[index..].rotate_left(1);
+        self.desired.pop();
+        self.desired_changed();
+        true
+    }
+
+    /// Removes all desired filters, which disables filtering in the device.
+    pub fn clear_filters(&mut self) {
+        self.desired.clear();
+        self.desired_changed();
+    }
+
+    /// Adds a filter on the given sender ID. Returns `false` if the list is full.
+    pub fn allow_sender(&mut self, sender: u32) -> bool {
+        self.add_filter(FilterEntry {
+            criterion: FilterCriterion::SourceAddress,
+            value: sender,
+        })
+    }
+
+    /// Forces the filters in the device to be read back and reconciled again.
+    pub fn device_reset(&mut self) {
+        self.state = FilterSyncState::NeedsRead;
+        self.opt_device = None;
+        self.opt_enabled = None;
+        self.opt_last_request_id = None;
+    }
+
+    fn desired_changed(&mut self) {
+        if self.state == FilterSyncState::Synchronized {
+            self.state = FilterSyncState::Applying;
+        }
+    }
+
+    /// Returns the next command required to reconcile the filters in the device with the desired
+    /// filters and assumes that it will be executed successfully.
+    ///
+    /// Filters that are no longer desired are deleted before new filters are added, so that the
+    /// table of the device does not overflow. If none of the filters in the device are desired,
+    /// they are deleted using a single command. Filtering is then enabled if there are desired
+    /// filters, and disabled otherwise.
+    pub fn next_command(&mut self) -> Option<CommandData> {
+        let device = match &mut self.opt_device {
+            Some(device) => device,
+            None => {
+                self.opt_device = Some(MaxArray::new());
+                return Some(CommandData::CoWrFilterDelAll);
+            },
+        };
+
+        let undesired_count = device.iter()
+            .filter(|d| !self.desired.iter().any(|e| e == *d))
+            .count();
+        if undesired_count > 1 && undesired_count == device.len() {
+            device.clear();
+            return Some(CommandData::CoWrFilterDelAll);
+        }
+
+        if let Some(index) = device.iter().position(|d| !self.desired.iter().any(|e| e == d)) {
+            let slice = device.as_mut_slice();
+            let last_index = slice.len() - 1;
+            slice.swap(index, last_index);
+            let entry = device.pop().unwrap();
+            return Some(CommandData::CoWrFilterDel {
+                criterion: entry.criterion,
+                value: entry.value,
+                action: self.action,
+            });
+        }
+
+        if let Some(entry) = self.desired.iter().find(|e| !device.iter().any(|d| d == *e)) {
+            // the device only contains desired filters, so there is room
+            device.push(*entry).unwrap();
+            return Some(CommandData::CoWrFilterAdd {
+                criterion: entry.criterion,
+                value: entry.value,
+                action: self.action,
+            });
+        }
+
+        let enable = self.desired.len() > 0;
+        if self.opt_enabled != Some(enable) {
+            self.opt_enabled = Some(enable);
+            return Some(CommandData::CoWrFilterEnable {
+                enable: enable.into(),
+                operator: self.operator,
+            });
+        }
+
+        None
+    }
+
+    /// Enqueues the commands required to read back or reconcile the filters into the session.
+    ///
+    /// Should be called regularly; commands that do not fit into the queue of the session are
+    /// enqueued during a later call.
+    pub fn poll<const QUEUE: usize>(&mut self, session: &mut Esp3Session<QUEUE>) {
+        match self.state {
+            FilterSyncState::NeedsRead => {
+                if let Ok(request_id) = session.enqueue(CommandData::CoRdFilter) {
+                    self.state = FilterSyncState::Reading { request_id };
+                }
+            },
+            FilterSyncState::Applying => {
+                while session.queued() < QUEUE {
+                    let Some(command) = self.next_command() else {
+                        if self.opt_last_request_id.is_none() {
+                            self.state = FilterSyncState::Synchronized;
+                        }
+                        break;
+                    };
+                    // the queue has room
+                    let request_id = session.enqueue(command).unwrap();
+                    self.opt_last_request_id = Some(request_id);
+                }
+            },
+            FilterSyncState::Reading { .. } | FilterSyncState::Synchronized => {},
+        }
+    }
+
+    /// Processes the outcome of a request made through the session.
+    ///
+    /// Outcomes of requests that have not been made by this manager are ignored. If a command to
+    /// change the filters has failed, the filters are read back again.
+    pub fn handle_session_event(&mut self, event: &SessionEvent<'_>) {
+        if let FilterSyncState::Reading { request_id } = self.state {
+            if event.request_id == request_id {
+                self.handle_read(&event.result);
+                return;
+            }
+        }
+
+        let is_filter_command = matches!(
+            event.request,
+            Esp3Request::Common(
+                CommandData::CoWrFilterAdd { .. } | CommandData::CoWrFilterDel { .. }
+                | CommandData::CoWrFilterDelAll | CommandData::CoWrFilterEnable { .. }
+            ),
+        );
+        if !is_filter_command || self.state != FilterSyncState::Applying {
+            return;
+        }
+        if event.result.is_err() {
+            self.device_reset();
+        } else if self.opt_last_request_id == Some(event.request_id) {
+            // wait for the next poll to find out whether anything else needs to be done
+            self.opt_last_request_id = None;
+        }
+    }
+
+    fn handle_read(&mut self, result: &Result<DecodedResponse<'_>, SessionError>) {
+        self.opt_device = match result {
+            Ok(DecodedResponse::Common(CommonCommandResponse::RdFilter(filters))) => {
+                let mut device = MaxArray::new();
+                if device.fill_from(filters.filters().peekable()) {
+                    Some(device)
+                } else {
+                    None
+                }
+            },
+            // no filters are stored
+            Ok(DecodedResponse::Empty) => Some(MaxArray::new()),
+            Err(SessionError::TimedOut) => {
+                self.state = FilterSyncState::NeedsRead;
+                return;
+            },
+            // the filters are unknown; delete them all
+            _ => None,
+        };
+        self.opt_enabled = None;
+        self.opt_last_request_id = None;
+        self.state = FilterSyncState::Applying;
+    }
+
+    /// Processes an event sent by the device. If the device has been reset, the filters are read
+    /// back again.
+    pub fn handle_event(&mut self, event: &EventData) {
+        if let EventData::CoReady { .. } = event {
+            self.device_reset();
+        }
+    }
+
+    /// Processes a received packet. Packets that are not events are ignored.
+    ///
+    /// Behaves like [`FilterManager::handle_event`].
+    pub fn handle_packet(&mut self, packet: &Esp3Packet) {
+        if let Esp3Packet::Event(event) = packet {
+            self.handle_event(event);
+        }
+    }
+
+    /// Processes a received packet that is borrowed from the receive buffer.
+    ///
+    /// Behaves like [`FilterManager::handle_packet`].
+    pub fn handle_packet_ref(&mut self, packet: &Esp3PacketRef<'_>) {
+        if let Esp3PacketRef::Event { event_code, data, optional_data } = packet {
+            if let Ok(event) = EventData::from_data(*event_code, data, optional_data) {
+                self.handle_event(&event);
+            }
+        }
+    }
+}
+impl<const FILTERS: usize> Default for FilterManager<FILTERS> {
+    fn default() -> Self { Self::new_sender_allowlist() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{FilterManager, FilterSyncState};
+    use crate::esp3::{
+        CommandData, Esp3Packet, Esp3PacketRef, FilterAction, FilterCriterion, FilterOperator, OneByteBoolean,
+        ReturnCode,
+    };
+    use crate::esp3::session::Esp3Session;
+
+    fn add_source(value: u32) -> CommandData {
+        CommandData::CoWrFilterAdd {
+            criterion: FilterCriterion::SourceAddress,
+            value,
+            action: FilterAction::Forward,
+        }
+    }
+
+    fn response(data: &[u8]) -> Esp3PacketRef<'_> {
+        Esp3PacketRef::Response { return_code: ReturnCode::Ok, response_data: data, optional_data: &[] }
+    }
+
+    /// Transmits the next command of the session, answers it with the given packet and passes
+    /// the outcome on to the manager.
+    fn exchange(manager: &mut FilterManager<4>, session: &mut Esp3Session<4>, answer: &Esp3PacketRef<'_>) -> CommandData {
+        let Some(Esp3Packet::CommonCommand(command)) = session.poll_transmit(0) else {
+            panic!("no command transmitted");
+        };
+        let event = session.handle_packet_ref(answer, 0).unwrap();
+        manager.handle_session_event(&event);
+        command
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_reconcile() {
+        let mut manager: FilterManager<4> = FilterManager::new_sender_allowlist();
+        let mut session: Esp3Session<4> = Esp3Session::new(500);
+        assert!(manager.allow_sender(0x01020304));
+        assert!(manager.allow_sender(0x0A0B0C0D));
+
+        manager.poll(&mut session);
+        // the device has one desired and one undesired filter
+        let stored = response(&[
+            0x00, 0x01, 0x02, 0x03, 0x04,
+            0x00, 0xAA, 0xBB, 0xCC, 0xDD,
+        ]);
+        assert_eq!(exchange(&mut manager, &mut session, &stored), CommandData::CoRdFilter);
+        assert_eq!(manager.state(), FilterSyncState::Applying);
+
+        manager.poll(&mut session);
+        let ok = response(&[]);
+        assert_eq!(
+            exchange(&mut manager, &mut session, &ok),
+            CommandData::CoWrFilterDel {
+                criterion: FilterCriterion::SourceAddress,
+                value: 0xAABBCCDD,
+                action: FilterAction::Forward,
+            },
+        );
+        assert_eq!(exchange(&mut manager, &mut session, &ok), add_source(0x0A0B0C0D));
+        assert_eq!(
+            exchange(&mut manager, &mut session, &ok),
+            CommandData::CoWrFilterEnable { enable: OneByteBoolean::Yes, operator: FilterOperator::Or },
+        );
+        assert!(session.is_idle());
+        manager.poll(&mut session);
+        assert_eq!(manager.state(), FilterSyncState::Synchronized);
+
+        // only the change is applied
+        assert!(manager.allow_sender(0x11223344));
+        manager.poll(&mut session);
+        assert_eq!(exchange(&mut manager, &mut session, &ok), add_source(0x11223344));
+        assert!(session.is_idle());
+        manager.poll(&mut session);
+        assert_eq!(manager.state(), FilterSyncState::Synchronized);
+
+        // after a reset, the filters are read back; none of the stored ones are desired
+        manager.clear_filters();
+        manager.device_reset();
+        manager.poll(&mut session);
+        assert_eq!(exchange(&mut manager, &mut session, &stored), CommandData::CoRdFilter);
+        manager.poll(&mut session);
+        assert_eq!(exchange(&mut manager, &mut session, &ok), CommandData::CoWrFilterDelAll);
+        assert_eq!(
+            exchange(&mut manager, &mut session, &ok),
+            CommandData::CoWrFilterEnable { enable: OneByteBoolean::No, operator: FilterOperator::Or },
+        );
+        manager.poll(&mut session);
+        assert_eq!(manager.state(), FilterSyncState::Synchronized);
+    }
+}
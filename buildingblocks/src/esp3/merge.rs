@@ -0,0 +1,373 @@
+//! Merging of multiple received copies of the same ERP1 telegram.
+//!
+//! ERP1 senders transmit each telegram up to three times (as subtelegrams), and repeaters send
+//! further copies. [`TelegramMerger`] collects all copies of a telegram arriving within a
+//! configurable window and emits the telegram once, along with [`CopyStatistics`] describing the
+//! copies. It performs no I/O itself: the caller feeds it the received packets along with the
+//! current time as a wrapping millisecond counter and regularly polls it for merged telegrams.
+
+
+use crate::esp3::{Esp3Packet, Esp3PacketRef, SubTelegramInfo};
+use crate::esp3::erp::{ErpContents, ErpData};
+use crate::max_array::MaxArray;
+
+
+/// The time, in milliseconds, during which copies of a telegram are collected after its first copy
+/// has been received.
+pub const DEFAULT_MERGE_WINDOW_MS: u32 = 100;
+
+
+/// Information about the copies of a telegram that have been received.
+///
+/// Signal strengths are stored as transmitted in ESP3 packets, i.e. as the absolute value of the
+/// (negative) dBm value; smaller values therefore denote stronger signals.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CopyStatistics {
+    /// The number of copies received.
+    pub copies: u16,
+
+    /// The signal strength of the strongest copy, if any copy reported its signal strength.
+    pub opt_best_dbm: Option<u8>,
+
+    /// The signal strength of the weakest copy, if any copy reported its signal strength.
+    pub opt_worst_dbm: Option<u8>,
+
+    /// Whether at least one copy has been sent by a repeater.
+    pub repeated: bool,
+}
+impl CopyStatistics {
+    /// Statistics describing a single copy with the given signal strength and status byte.
+    pub fn single(opt_dbm: Option<u8>, status: u8) -> Self {
+        Self {
+            copies: 1,
+            opt_best_dbm: opt_dbm,
+            opt_worst_dbm: opt_dbm,
+            repeated: (status & 0b1111) != 0,
+        }
+    }
+
+    /// Statistics describing the subtelegrams listed in a
+    /// [`Esp3Packet::RadioSubTelegram`](crate::esp3::Esp3Packet::RadioSubTelegram) packet. Returns
+    /// `None` if the list is empty.
+    pub fn from_sub_telegrams<I: Iterator<Item = SubTelegramInfo>>(sub_telegrams: I) -> Option<Self> {
+        sub_telegrams
+            .map(|info| Self::single(Some(info.dbm), info.status))
+            .reduce(|mut total, copy| {
+                total.merge(&copy);
+                total
+            })
+    }
+
+    /// Adds the copies described by other statistics to these statistics.
+    pub fn merge(&mut self, other: &Self) {
+        self.copies = self.copies.saturating_add(other.copies);
+        self.opt_best_dbm = match (self.opt_best_dbm, other.opt_best_dbm) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.opt_worst_dbm = match (self.opt_worst_dbm, other.opt_worst_dbm) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        self.repeated |= other.repeated;
+    }
+}
+
+
+/// A telegram whose copies have been merged.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MergedTelegram {
+    /// The first copy of the telegram that has been received.
+    pub telegram: ErpData,
+
+    pub statistics: CopyStatistics,
+
+    /// The time at which the first copy has been received.
+    pub first_seen_ms: u32,
+}
+
+
+/// A telegram whose copies are being collected.
+#[derive(Debug)]
+struct PendingTelegram {
+    contents: ErpContents,
+
+    /// The status byte of the first copy.
+    status: u8,
+
+    statistics: CopyStatistics,
+    first_seen_ms: u32,
+}
+impl PendingTelegram {
+    fn into_merged(self) -> MergedTelegram {
+        MergedTelegram {
+            // the contents have been taken from a valid telegram
+            telegram: self.contents.to_telegram(self.status).unwrap(),
+            statistics: self.statistics,
+            first_seen_ms: self.first_seen_ms,
+        }
+    }
+}
+
+
+/// Merges copies of ERP1 telegrams received within a window.
+///
+/// At most `ENTRIES` telegrams can be collected at the same time.
+#[derive(Debug)]
+pub struct TelegramMerger<const ENTRIES: usize> {
+    window_ms: u32,
+    pending: MaxArray<PendingTelegram, ENTRIES>,
+}
+impl<const ENTRIES: usize> TelegramMerger<ENTRIES> {
+    /// Creates a new merger which collects copies for `window_ms` milliseconds after the first copy
+    /// of a telegram has been received.
+    pub const fn new(window_ms: u32) -> Self {
+        Self {
+            window_ms,
+            pending: MaxArray::new(),
+        }
+    }
+
+    /// The number of telegrams whose copies are currently being collected.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Processes one or more received copies of a telegram.
+    ///
+    /// Returns a telegram that has to be emitted right away: either the given telegram, if it has
+    /// no sender ID by which it could be merged or is of a type unknown to this module, or the
+    /// oldest collected telegram, if it had to make room for the given one.
+    pub fn push(&mut self, telegram: &ErpData, statistics: CopyStatistics, now_ms: u32) -> Option<MergedTelegram> {
+        let (Some(_), Some(contents)) = (telegram.opt_sender(), ErpContents::new(telegram)) else {
+            return Some(MergedTelegram {
+                telegram: telegram.clone(),
+                statistics,
+                first_seen_ms: now_ms,
+            });
+        };
+
+        // the contents include the sender ID
+        let existing = self.pending.as_mut_slice().iter_mut()
+            .find(|p| p.contents == contents);
+        if let Some(pending) = existing {
+            pending.statistics.merge(&statistics);
+            return None;
+        }
+
+        let evicted = if self.pending.len() == ENTRIES {
+            self.take_oldest()
+        } else {
+            None
+        };
+        let status = telegram.as_status_byte()
+            .map(|s| s.status_byte())
+            .unwrap_or(0);
+        let pending = PendingTelegram {
+            contents,
+            status,
+            statistics,
+            first_seen_ms: now_ms,
+        };
+        if ENTRIES == 0 {
+            return Some(pending.into_merged());
+        }
+        let _ = self.pending.push(pending);
+        evicted
+    }
+
+    /// Processes a received packet. Packets that do not contain an ERP1 telegram are ignored.
+    ///
+    /// Returns a telegram that has to be emitted right away; see [`TelegramMerger::push`].
+    pub fn push_packet(&mut self, packet: &Esp3Packet, now_ms: u32) -> Option<MergedTelegram> {
+        match packet {
+            Esp3Packet::RadioErp1 { radio_telegram, opt_dbm, .. } => {
+                let telegram = ErpData::from_slice(radio_telegram.as_slice())?;
+                self.push_single(&telegram, *opt_dbm, now_ms)
+            },
+            Esp3Packet::RadioSubTelegram { radio_telegram, opt_dbm, opt_sub_telegram_info, .. } => {
+                let telegram = ErpData::from_slice(radio_telegram.as_slice())?;
+                match CopyStatistics::from_sub_telegrams(opt_sub_telegram_info.iter().copied()) {
+                    Some(statistics) => self.push(&telegram, statistics, now_ms),
+                    None => self.push_single(&telegram, *opt_dbm, now_ms),
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Processes a received packet that is borrowed from the receive buffer.
+    ///
+    /// Behaves like [`TelegramMerger::push_packet`].
+    pub fn push_packet_ref(&mut self, packet: &Esp3PacketRef<'_>, now_ms: u32) -> Option<MergedTelegram> {
+        match packet {
+            Esp3PacketRef::RadioErp1 { radio_telegram, opt_dbm, .. } => {
+                let telegram = ErpData::from_slice(radio_telegram)?;
+                self.push_single(&telegram, *opt_dbm, now_ms)
+            },
+            Esp3PacketRef::RadioSubTelegram { radio_telegram, opt_dbm, .. } => {
+                let telegram = ErpData::from_slice(radio_telegram)?;
+                match CopyStatistics::from_sub_telegrams(packet.sub_telegram_info()) {
+                    Some(statistics) => self.push(&telegram, statistics, now_ms),
+                    None => self.push_single(&telegram, *opt_dbm, now_ms),
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the next telegram whose window has elapsed, if any.
+    pub fn poll(&mut self, now_ms: u32) -> Option<MergedTelegram> {
+        let index = self.pending.iter()
+            .position(|p| now_ms.wrapping_sub(p.first_seen_ms) >= self.window_ms)?;
+        Some(self.remove(index))
+    }
+
+    /// Returns the oldest telegram whose copies are still being collected, regardless of its
+    /// window.
+    pub fn take_oldest(&mut self) -> Option<MergedTelegram> {
+        // entries are kept in the order of their arrival; comparing the times would fail once the
+        // clock wraps around
+        if self.pending.len() == 0 {
+            return None;
+        }
+        Some(self.remove(0))
+    }
+
+    fn push_single(&mut self, telegram: &ErpData, opt_dbm: Option<u8>, now_ms: u32) -> Option<MergedTelegram> {
+        let status = telegram.as_status_byte()
+            .map(|s| s.status_byte())
+            .unwrap_or(0);
+        self.push(telegram, CopyStatistics::single(opt_dbm, status), now_ms)
+    }
+
+    fn remove(&mut self, index: usize) -> MergedTelegram {
+        // keep the order of the remaining entries
+        let slice = self.pending.as_mut_slice();
+        slice[index..].rotate_left(1);
+        self.pending.pop().unwrap().into_merged()
+    }
+}
+impl<const ENTRIES: usize> Default for TelegramMerger<ENTRIES> {
+    fn default() -> Self { Self::new(DEFAULT_MERGE_WINDOW_MS) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyStatistics, MergedTelegram, TelegramMerger};
+    use crate::esp3::{Esp3PacketRef, SecurityLevel};
+    use crate::esp3::erp::{ErpData, FourByteTelegram};
+    use crate::esp3::erp::tests::switch;
+
+    fn push_erp1(merger: &mut TelegramMerger<2>, telegram: &ErpData, dbm: u8, now_ms: u32) -> Option<MergedTelegram> {
+        let radio_telegram = telegram.to_bytes();
+        let packet = Esp3PacketRef::RadioErp1 {
+            radio_telegram: radio_telegram.as_slice(),
+            opt_sub_telegram_number: Some(1),
+            opt_destination_id: Some(0xFFFFFFFF),
+            opt_dbm: Some(dbm),
+            opt_security_level: None,
+        };
+        merger.push_packet_ref(&packet, now_ms)
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_merge_copies() {
+        let mut merger: TelegramMerger<2> = TelegramMerger::new(100);
+
+        let original = switch(0x01020304, 0x50, 0x30);
+        assert_eq!(push_erp1(&mut merger, &original, 70, 0), None);
+        // a repeated copy
+        assert_eq!(push_erp1(&mut merger, &switch(0x01020304, 0x50, 0x31), 50, 30), None);
+        // a different telegram from the same sender
+        assert_eq!(push_erp1(&mut merger, &switch(0x01020304, 0x70, 0x30), 60, 40), None);
+        assert_eq!(merger.pending(), 2);
+
+        assert_eq!(merger.poll(99), None);
+        let merged = merger.poll(100).unwrap();
+        assert_eq!(merged.telegram, original);
+        assert_eq!(merged.first_seen_ms, 0);
+        assert_eq!(merged.statistics, CopyStatistics {
+            copies: 2,
+            opt_best_dbm: Some(50),
+            opt_worst_dbm: Some(70),
+            repeated: true,
+        });
+        assert_eq!(merger.poll(100), None);
+
+        // making room for a third telegram evicts the oldest one
+        assert_eq!(push_erp1(&mut merger, &switch(0x0A0B0C0D, 0x10, 0x30), 40, 110), None);
+        let evicted = push_erp1(&mut merger, &switch(0x0A0B0C0E, 0x10, 0x30), 40, 120).unwrap();
+        assert_eq!(evicted.telegram, switch(0x01020304, 0x70, 0x30));
+        assert!(!evicted.statistics.repeated);
+        assert_eq!(merger.pending(), 2);
+    }
+
+    #[test]
+    fn test_sub_telegrams() {
+        let mut merger: TelegramMerger<2> = TelegramMerger::new(100);
+
+        let radio_telegram = switch(0x01020304, 0x50, 0x30).to_bytes();
+        // (tick, dBm, status) of each subtelegram
+        let sub_telegram_info = [
+            0x00, 60, 0x30,
+            0x0A, 45, 0x30,
+            0x14, 80, 0x30,
+        ];
+        let packet = Esp3PacketRef::RadioSubTelegram {
+            radio_telegram: radio_telegram.as_slice(),
+            opt_sub_telegram_number: Some(3),
+            opt_destination_id: Some(0xFFFFFFFF),
+            opt_dbm: Some(45),
+            opt_security_level: Some(SecurityLevel::NoSecurity),
+            opt_timestamp: Some(0),
+            sub_telegram_info_bytes: &sub_telegram_info,
+        };
+        assert_eq!(merger.push_packet_ref(&packet, 0), None);
+
+        let merged = merger.take_oldest().unwrap();
+        assert_eq!(merged.statistics, CopyStatistics {
+            copies: 3,
+            opt_best_dbm: Some(45),
+            opt_worst_dbm: Some(80),
+            repeated: false,
+        });
+
+        // telegrams without sender cannot be merged
+        let other = ErpData::new_other(0x42, &[0x01]).unwrap();
+        let merged = merger.push(&other, CopyStatistics::single(None, 0), 0).unwrap();
+        assert_eq!(merged.statistics.copies, 1);
+        assert_eq!(merger.pending(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_wraparound() {
+        let mut merger: TelegramMerger<2> = TelegramMerger::new(100);
+
+        // the oldest telegram has been received before the clock wrapped around
+        assert_eq!(push_erp1(&mut merger, &switch(0x01020304, 0x50, 0x30), 70, u32::MAX - 10), None);
+        assert_eq!(push_erp1(&mut merger, &switch(0x0A0B0C0D, 0x50, 0x30), 70, 5), None);
+        let oldest = merger.take_oldest().unwrap();
+        assert_eq!(oldest.telegram, switch(0x01020304, 0x50, 0x30));
+        assert_eq!(merger.take_oldest().unwrap().first_seen_ms, 5);
+        assert_eq!(merger.take_oldest(), None);
+    }
+
+    #[test]
+    fn test_contents() {
+        let mut merger: TelegramMerger<2> = TelegramMerger::new(100);
+
+        // different telegrams of the same sender
+        let first = ErpData::FourByte(FourByteTelegram { data: 0xE41BA9F1, sender: 0x01020304, status: 0x00 });
+        let second = ErpData::FourByte(FourByteTelegram { data: 0x2D0E5418, sender: 0x01020304, status: 0x00 });
+        assert_eq!(push_erp1(&mut merger, &first, 70, 0), None);
+        assert_eq!(push_erp1(&mut merger, &second, 70, 10), None);
+        assert_eq!(merger.pending(), 2);
+        // the first copy is restored along with its status byte
+        let oldest = merger.take_oldest().unwrap();
+        assert_eq!(oldest.telegram, first);
+    }
+}
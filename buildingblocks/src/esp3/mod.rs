@@ -1,14 +1,29 @@
 //! An implementation of the EnOcean Serial Protocol 3 (ESP3).
 
 
+pub mod base_id;
+pub mod builder;
+pub mod decoder;
 pub mod erp;
+pub mod erp2;
 pub mod eep;
+pub mod eep_payload;
+pub mod error;
+pub mod filter;
+pub mod merge;
+pub mod recom;
+pub mod remote_man;
+pub mod repeater;
 pub mod response_data;
+pub mod scheduler;
+pub mod security;
+pub mod session;
 
 
 use bitflags::bitflags;
 
 use crate::crc8::crc8_ccitt;
+use crate::esp3::error::{ParseError, ParseErrorKind, expect_length, expect_min_length};
 use crate::max_array::MaxArray;
 use crate::max_array_ext::MaxArrayPushIntExt;
 
@@ -463,63 +478,476 @@ impl Esp3Packet {
         }
     }
 
+    /// Decodes a packet that occupies the whole slice.
     pub fn from_slice(bytes: &[u8]) -> Option<Self> {
-        // check minimum length
-        if bytes.len() < MIN_ESP3_PACKET_LENGTH {
-            return None;
+        Self::parse(bytes).ok()
+    }
+
+    /// Decodes a packet that occupies the whole slice, reporting why decoding failed.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (packet, length) = Self::parse_prefix(bytes)?;
+        if length != bytes.len() {
+            return Err(ParseError::new(length, ParseErrorKind::TrailingBytes {
+                expected: length,
+                actual: bytes.len(),
+            }));
+        }
+        Ok(packet)
+    }
+
+    /// Decodes all packets in a slice containing any number of consecutive packets, e.g. a capture
+    /// file.
+    pub fn parse_all(bytes: &[u8]) -> ParsedPackets<'_> {
+        ParsedPackets {
+            bytes,
+            position: 0,
         }
+    }
+
+    /// Decodes the packet at the start of the slice, which may be followed by further bytes.
+    /// Returns the packet along with the number of bytes it occupies.
+    pub fn parse_prefix(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let (packet, length) = Esp3PacketRef::parse_prefix(bytes)?;
+        packet.decode()
+            .map(|packet| (packet, length))
+            .map_err(|e| e.offset_by(HEADER_LENGTH))
+    }
+}
+
+
+/// An ESP3 packet whose framing (sync byte, lengths and checksums) has been validated but whose
+/// contents have not been decoded yet.
+struct Frame<'a> {
+    packet_type: u8,
+    data: &'a [u8],
+    optional_data: &'a [u8],
+    length: usize,
+}
+
+/// Validates the framing of the packet at the start of the slice.
+fn parse_frame(bytes: &[u8]) -> Result<Frame<'_>, ParseError> {
+    // check minimum length
+    if bytes.len() < MIN_ESP3_PACKET_LENGTH {
+        return Err(ParseError::new(bytes.len(), ParseErrorKind::Truncated {
+            needed: MIN_ESP3_PACKET_LENGTH,
+            available: bytes.len(),
+        }));
+    }
+
+    // check sync byte
+    if bytes[0] != SYNC_BYTE {
+        return Err(ParseError::new(0, ParseErrorKind::MissingSyncByte { found: bytes[0] }));
+    }
+
+    // check header CRC
+    let crc_header = crc8_ccitt(&bytes[1..5]);
+    if crc_header != bytes[5] {
+        return Err(ParseError::new(5, ParseErrorKind::HeaderCrcMismatch {
+            calculated: crc_header,
+            received: bytes[5],
+        }));
+    }
+
+    // extract lengths
+    let data_length_u16 = (u16::from(bytes[1]) << 8) | u16::from(bytes[2]);
+    let data_length: usize = data_length_u16.into();
+    let opt_length_u8 = bytes[3];
+    let opt_length: usize = opt_length_u8.into();
+    if data_length > MAX_DATA_LENGTH {
+        return Err(ParseError::new(1, ParseErrorKind::DataTooLong {
+            length: data_length,
+            maximum: MAX_DATA_LENGTH,
+        }));
+    }
+
+    // check total length
+    let total_length = HEADER_LENGTH + data_length + opt_length + FOOTER_LENGTH;
+    if bytes.len() < total_length {
+        return Err(ParseError::new(bytes.len(), ParseErrorKind::Truncated {
+            needed: total_length,
+            available: bytes.len(),
+        }));
+    }
 
-        // check sync byte
-        if bytes[0] != SYNC_BYTE {
-            return None;
+    // check data CRC
+    let crc_data = crc8_ccitt(&bytes[HEADER_LENGTH..HEADER_LENGTH+data_length+opt_length]);
+    if crc_data != bytes[HEADER_LENGTH+data_length+opt_length] {
+        return Err(ParseError::new(total_length - 1, ParseErrorKind::DataCrcMismatch {
+            calculated: crc_data,
+            received: bytes[HEADER_LENGTH+data_length+opt_length],
+        }));
+    }
+
+    let data_slice = &bytes[HEADER_LENGTH..HEADER_LENGTH+data_length];
+    let optional_slice = &bytes[HEADER_LENGTH+data_length..HEADER_LENGTH+data_length+opt_length];
+
+    Ok(Frame {
+        packet_type: bytes[4],
+        data: data_slice,
+        optional_data: optional_slice,
+        length: total_length,
+    })
+}
+
+/// Copies the bytes into a new array. Fails if they do not fit.
+fn copy_to_array<const MAX_SIZE: usize>(bytes: &[u8]) -> Result<MaxArray<u8, MAX_SIZE>, ParseError> {
+    let mut array = MaxArray::new();
+    if !array.fill_from(bytes.iter().copied().peekable()) {
+        return Err(ParseError::new(MAX_SIZE, ParseErrorKind::DataTooLong {
+            length: bytes.len(),
+            maximum: MAX_SIZE,
+        }));
+    }
+    Ok(array)
+}
+
+
+/// An iterator over the packets in a slice containing any number of consecutive packets.
+///
+/// Returned by [`Esp3Packet::parse_all`]. Yields each packet along with its offset. Bytes that do
+/// not form a valid packet are skipped and reported as an error; offsets are relative to the start
+/// of the slice.
+#[derive(Clone, Debug)]
+pub struct ParsedPackets<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+impl<'a> Iterator for ParsedPackets<'a> {
+    type Item = Result<(usize, Esp3Packet), ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.position;
+        let rest = &self.bytes[start..];
+        let first_byte = *rest.first()?;
+
+        if first_byte != SYNC_BYTE {
+            // skip (and report) the whole run of garbage
+            self.position += rest.iter()
+                .position(|b| *b == SYNC_BYTE)
+                .unwrap_or(rest.len());
+            return Some(Err(ParseError::new(start, ParseErrorKind::MissingSyncByte { found: first_byte })));
         }
 
-        // extract lengths
-        let data_length_u16 = (u16::from(bytes[1]) << 8) | u16::from(bytes[2]);
-        let data_length: usize = data_length_u16.into();
-        let opt_length_u8 = bytes[3];
-        let opt_length: usize = opt_length_u8.into();
-
-        // check total length
-        let total_length = HEADER_LENGTH + data_length + opt_length + FOOTER_LENGTH;
-        if bytes.len() != total_length {
-            // FIXME: change from "not exactly total_length" to "at least total_length"?
-            return None;
+        match Esp3Packet::parse_prefix(rest) {
+            Ok((packet, length)) => {
+                self.position += length;
+                Some(Ok((start, packet)))
+            },
+            Err(error) => {
+                self.position += match error.kind {
+                    // the sync byte might have been a false positive, or the packet might have been
+                    // cut off; continue at the next sync byte that starts a complete frame, if any
+                    ParseErrorKind::Truncated { .. } => {
+                        (1..rest.len())
+                            .find(|i| rest[*i] == SYNC_BYTE && parse_frame(&rest[*i..]).is_ok())
+                            .unwrap_or(rest.len())
+                    },
+
+                    // the sync byte might have been a false positive
+                    ParseErrorKind::MissingSyncByte { .. }
+                    |ParseErrorKind::HeaderCrcMismatch { .. }
+                    |ParseErrorKind::DataCrcMismatch { .. } => 1,
+
+                    // the header is valid; skip the whole frame
+                    ParseErrorKind::TrailingBytes { .. }
+                    |ParseErrorKind::DataTooLong { .. }
+                    |ParseErrorKind::MissingCode
+                    |ParseErrorKind::UnknownCode { .. }
+                    |ParseErrorKind::UnexpectedLength { .. }
+                    |ParseErrorKind::TooShort { .. }
+                    |ParseErrorKind::PartialEntry { .. }
+                    |ParseErrorKind::InvalidTelegram { .. } => {
+                        let data_length = usize::from(u16::from_be_bytes([rest[1], rest[2]]));
+                        let opt_length = usize::from(rest[3]);
+                        (HEADER_LENGTH + data_length + opt_length + FOOTER_LENGTH).min(rest.len())
+                    },
+                };
+                Some(Err(ParseError::new(start + error.offset, error.kind)))
+            },
         }
+    }
+}
 
-        // check header CRC
-        let crc_header = crc8_ccitt(&bytes[1..5]);
-        if crc_header != bytes[5] {
-            return None;
+/// The contents of an ESP3 data packet, borrowed from the buffer it was decoded from.
+///
+/// Unlike [`Esp3Packet`], this does not copy any data; it is therefore small enough to be handled
+/// on the stack even if [`MAX_DATA_LENGTH`] is large. Packets whose data starts with a code (events
+/// and commands) are not decoded further; [`Esp3PacketRef::into_owned`] decodes them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Esp3PacketRef<'a> {
+    RadioErp1 {
+        radio_telegram: &'a [u8],
+        opt_sub_telegram_number: Option<u8>,
+        opt_destination_id: Option<u32>,
+        opt_dbm: Option<u8>,
+        opt_security_level: Option<SecurityLevel>,
+    },
+    Response {
+        return_code: ReturnCode,
+        response_data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+    RadioSubTelegram {
+        radio_telegram: &'a [u8],
+        opt_sub_telegram_number: Option<u8>,
+        opt_destination_id: Option<u32>,
+        opt_dbm: Option<u8>,
+        opt_security_level: Option<SecurityLevel>,
+        opt_timestamp: Option<u16>,
+        // 3 bytes for each subtelegram; see sub_telegram_info()
+        sub_telegram_info_bytes: &'a [u8],
+    },
+    Event {
+        event_code: u8,
+        data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+    CommonCommand {
+        command_code: u8,
+        data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+    SmartAckCommand {
+        command_code: u8,
+        data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+    RemoteManCommand {
+        function: u16,
+        manufacturer: u16,
+        message: &'a [u8],
+        opt_destination_id: Option<u32>,
+        opt_source_id: Option<u32>,
+        opt_dbm: Option<u8>,
+        opt_send_with_delay: Option<OneByteBoolean>,
+    },
+    RadioMessage {
+        rorg: u8,
+        data: &'a [u8],
+        opt_destination_id: Option<u32>,
+        opt_source_id: Option<u32>,
+        opt_dbm: Option<u8>,
+        opt_security_level: Option<SecurityLevel>,
+    },
+    RadioErp2 {
+        data: &'a [u8],
+        opt_sub_telegram_number: Option<u8>,
+        opt_dbm: Option<u8>,
+        opt_security_level: Option<SecurityLevel>,
+    },
+    CommandAccepted {
+        is_blocking: OneByteBoolean,
+        estimated_time_ms: u16,
+    },
+    Radio802Dot15Dot4 {
+        raw_data: &'a [u8],
+        opt_rssi: Option<u8>,
+    },
+    Command24 {
+        command_code: u8,
+        data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+    Unknown {
+        packet_type: u8,
+        data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+}
+impl<'a> Esp3PacketRef<'a> {
+    pub fn packet_type(&self) -> u8 {
+        match self {
+            Self::RadioErp1 { .. } => 1,
+            Self::Response { .. } => 2,
+            Self::RadioSubTelegram { .. } => 3,
+            Self::Event { .. } => 4,
+            Self::CommonCommand { .. } => 5,
+            Self::SmartAckCommand { .. } => 6,
+            Self::RemoteManCommand { .. } => 7,
+            Self::RadioMessage { .. } => 9,
+            Self::RadioErp2 { .. } => 10,
+            Self::CommandAccepted { .. } => 12,
+            Self::Radio802Dot15Dot4 { .. } => 16,
+            Self::Command24 { .. } => 17,
+            Self::Unknown { packet_type, .. } => *packet_type,
         }
+    }
 
-        // check data CRC
-        let crc_data = crc8_ccitt(&bytes[HEADER_LENGTH..HEADER_LENGTH+data_length+opt_length]);
-        if crc_data != bytes[HEADER_LENGTH+data_length+opt_length] {
-            return None;
+    /// Decodes a packet that occupies the whole slice, reporting why decoding failed.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, ParseError> {
+        let (packet, length) = Self::parse_prefix(bytes)?;
+        if length != bytes.len() {
+            return Err(ParseError::new(length, ParseErrorKind::TrailingBytes {
+                expected: length,
+                actual: bytes.len(),
+            }));
         }
+        Ok(packet)
+    }
+
+    /// Decodes the packet at the start of the slice, which may be followed by further bytes.
+    /// Returns the packet along with the number of bytes it occupies.
+    pub fn parse_prefix(bytes: &'a [u8]) -> Result<(Self, usize), ParseError> {
+        let frame = parse_frame(bytes)?;
+        Self::from_parts(frame.packet_type, frame.data, frame.optional_data)
+            .map(|packet| (packet, frame.length))
+            .map_err(|e| e.offset_by(HEADER_LENGTH))
+    }
+
+    /// The information about each subtelegram of a [`Esp3PacketRef::RadioSubTelegram`].
+    ///
+    /// Returns an empty iterator for other packets.
+    pub fn sub_telegram_info(&self) -> impl Iterator<Item = SubTelegramInfo> + 'a {
+        let bytes: &'a [u8] = match self {
+            Self::RadioSubTelegram { sub_telegram_info_bytes, .. } => sub_telegram_info_bytes,
+            _ => &[],
+        };
+        bytes.chunks_exact(3)
+            .map(|chunk| SubTelegramInfo { tick: chunk[0], dbm: chunk[1], status: chunk[2] })
+    }
+
+    /// Copies this packet into an [`Esp3Packet`], decoding events and commands.
+    ///
+    /// Returns `None` if the event or command cannot be decoded or if the data does not fit into
+    /// an [`Esp3Packet`].
+    pub fn into_owned(self) -> Option<Esp3Packet> {
+        self.decode().ok()
+    }
+
+    /// Copies this packet into an [`Esp3Packet`], decoding events and commands and reporting why
+    /// decoding failed.
+    ///
+    /// Offsets are relative to the start of the packet's data, i.e. the byte following the header.
+    pub fn decode(self) -> Result<Esp3Packet, ParseError> {
+        match self {
+            Self::RadioErp1 { radio_telegram, opt_sub_telegram_number, opt_destination_id, opt_dbm, opt_security_level } => {
+                Ok(Esp3Packet::RadioErp1 {
+                    radio_telegram: copy_to_array(radio_telegram)?,
+                    opt_sub_telegram_number,
+                    opt_destination_id,
+                    opt_dbm,
+                    opt_security_level,
+                })
+            },
+            Self::Response { return_code, response_data, optional_data } => {
+                Ok(Esp3Packet::Response {
+                    return_code,
+                    response_data: copy_to_array(response_data)?,
+                    optional_data: copy_to_array(optional_data)?,
+                })
+            },
+            Self::RadioSubTelegram { radio_telegram, opt_sub_telegram_number, opt_destination_id, opt_dbm, opt_security_level, opt_timestamp, .. } => {
+                let mut opt_sub_telegram_info = MaxArray::new();
+                for info in self.sub_telegram_info() {
+                    opt_sub_telegram_info.push(info)
+                        .map_err(|_| ParseError::new(0, ParseErrorKind::DataTooLong {
+                            length: self.sub_telegram_info().count(),
+                            maximum: opt_sub_telegram_info.max_size(),
+                        }))?;
+                }
 
-        let data_slice = &bytes[HEADER_LENGTH..HEADER_LENGTH+data_length];
-        let optional_slice = &bytes[HEADER_LENGTH+data_length..HEADER_LENGTH+data_length+opt_length];
+                Ok(Esp3Packet::RadioSubTelegram {
+                    radio_telegram: copy_to_array(radio_telegram)?,
+                    opt_sub_telegram_number,
+                    opt_destination_id,
+                    opt_dbm,
+                    opt_security_level,
+                    opt_timestamp,
+                    opt_sub_telegram_info,
+                })
+            },
+            Self::Event { event_code, data, optional_data } => {
+                // offsets are relative to the byte following the code
+                EventData::from_data(event_code, data, optional_data)
+                    .map(Esp3Packet::Event)
+                    .map_err(|e| e.offset_by(1))
+            },
+            Self::CommonCommand { command_code, data, optional_data } => {
+                // offsets are relative to the byte following the code
+                CommandData::from_data(command_code, data, optional_data)
+                    .map(Esp3Packet::CommonCommand)
+                    .map_err(|e| e.offset_by(1))
+            },
+            Self::SmartAckCommand { command_code, data, optional_data } => {
+                // offsets are relative to the byte following the code
+                SmartAckData::from_data(command_code, data, optional_data)
+                    .map(Esp3Packet::SmartAckCommand)
+                    .map_err(|e| e.offset_by(1))
+            },
+            Self::RemoteManCommand { function, manufacturer, message, opt_destination_id, opt_source_id, opt_dbm, opt_send_with_delay } => {
+                Ok(Esp3Packet::RemoteManCommand {
+                    function,
+                    manufacturer,
+                    message: copy_to_array(message)?,
+                    opt_destination_id,
+                    opt_source_id,
+                    opt_dbm,
+                    opt_send_with_delay,
+                })
+            },
+            Self::RadioMessage { rorg, data, opt_destination_id, opt_source_id, opt_dbm, opt_security_level } => {
+                Ok(Esp3Packet::RadioMessage {
+                    rorg,
+                    data: copy_to_array(data)?,
+                    opt_destination_id,
+                    opt_source_id,
+                    opt_dbm,
+                    opt_security_level,
+                })
+            },
+            Self::RadioErp2 { data, opt_sub_telegram_number, opt_dbm, opt_security_level } => {
+                Ok(Esp3Packet::RadioErp2 {
+                    data: copy_to_array(data)?,
+                    opt_sub_telegram_number,
+                    opt_dbm,
+                    opt_security_level,
+                })
+            },
+            Self::CommandAccepted { is_blocking, estimated_time_ms } => {
+                Ok(Esp3Packet::CommandAccepted {
+                    is_blocking,
+                    estimated_time_ms,
+                })
+            },
+            Self::Radio802Dot15Dot4 { raw_data, opt_rssi } => {
+                Ok(Esp3Packet::Radio802Dot15Dot4 {
+                    raw_data: copy_to_array(raw_data)?,
+                    opt_rssi,
+                })
+            },
+            Self::Command24 { command_code, data, optional_data } => {
+                // offsets are relative to the byte following the code
+                Command24Data::from_data(command_code, data, optional_data)
+                    .map(Esp3Packet::Command24)
+                    .map_err(|e| e.offset_by(1))
+            },
+            Self::Unknown { packet_type, data, optional_data } => {
+                Ok(Esp3Packet::Unknown {
+                    packet_type,
+                    data: copy_to_array(data)?,
+                    optional_data: copy_to_array(optional_data)?,
+                })
+            },
+        }
+    }
 
-        let packet_type = bytes[4];
+    /// Decodes a packet of the given type from its data and optional data.
+    ///
+    /// Offsets are relative to the start of the data.
+    fn from_parts(packet_type: u8, data_slice: &'a [u8], optional_slice: &'a [u8]) -> Result<Self, ParseError> {
         match packet_type {
             1 => { // RadioErp1
-                let radio_telegram = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
-                );
-
-                let opt_sub_telegram_number = (optional_slice.len() >= 1)
+                let opt_sub_telegram_number = (!optional_slice.is_empty())
                     .then(|| optional_slice[0]);
-                let opt_destination_id = (optional_slice.len() >= 1 + 4)
+                let opt_destination_id = (optional_slice.len() > 4)
                     .then(|| u32::from_be_bytes(optional_slice[1..1+4].try_into().unwrap()));
-                let opt_dbm = (optional_slice.len() >= 1 + 4 + 1)
+                let opt_dbm = (optional_slice.len() > 1 + 4)
                     .then(|| optional_slice[1+4]);
-                let opt_security_level = (optional_slice.len() >= 1 + 4 + 1 + 1)
+                let opt_security_level = (optional_slice.len() > 1 + 4 + 1)
                     .then(|| optional_slice[1+4+1].into());
 
-                Some(Self::RadioErp1 {
-                    radio_telegram,
+                Ok(Self::RadioErp1 {
+                    radio_telegram: data_slice,
                     opt_sub_telegram_number,
                     opt_destination_id,
                     opt_dbm,
@@ -527,113 +955,83 @@ impl Esp3Packet {
                 })
             },
             2 => { // Response
-                if data_slice.len() < 1 {
-                    return None;
-                }
-
-                let return_code = data_slice[0].into();
-                let response_data = MaxArray::from_iter_or_panic(
-                    data_slice[1..].iter().map(|b| *b).peekable()
-                );
-                let optional_data = MaxArray::from_iter_or_panic(
-                    optional_slice.iter().map(|b| *b).peekable()
-                );
-
-                Some(Self::Response {
-                    return_code,
+                let (return_code, response_data) = data_slice.split_first()
+                    .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+                Ok(Self::Response {
+                    return_code: (*return_code).into(),
                     response_data,
-                    optional_data,
+                    optional_data: optional_slice,
                 })
             },
             3 => { // RadioSubTelegram
-                let radio_telegram = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
-                );
-
-                let opt_sub_telegram_number = (optional_slice.len() >= 1)
+                let opt_sub_telegram_number = (!optional_slice.is_empty())
                     .then(|| optional_slice[0]);
-                let opt_destination_id = (optional_slice.len() >= 1 + 4)
+                let opt_destination_id = (optional_slice.len() > 4)
                     .then(|| u32::from_be_bytes(optional_slice[1..1+4].try_into().unwrap()));
-                let opt_dbm = (optional_slice.len() >= 1 + 4 + 1)
+                let opt_dbm = (optional_slice.len() > 1 + 4)
                     .then(|| optional_slice[1+4]);
-                let opt_security_level = (optional_slice.len() >= 1 + 4 + 1 + 1)
+                let opt_security_level = (optional_slice.len() > 1 + 4 + 1)
                     .then(|| optional_slice[1+4+1].into());
                 let opt_timestamp = (optional_slice.len() >= 1 + 4 + 1 + 1 + 2)
                     .then(|| u16::from_be_bytes(optional_slice[1+4+1+1..1+4+1+1+2].try_into().unwrap()));
+                let sub_telegram_info_bytes = optional_slice.get(1+4+1+1+2..)
+                    .unwrap_or(&[]);
 
-                let mut i = 1 + 4 + 1 + 1 + 2;
-                let mut opt_sub_telegram_info = MaxArray::new();
-                while i + 3 <= optional_slice.len() {
-                    let tick = optional_slice[i+0];
-                    let dbm = optional_slice[i+1];
-                    let status = optional_slice[i+2];
-                    opt_sub_telegram_info
-                        .push(SubTelegramInfo { tick, dbm, status })
-                        .unwrap();
-
-                    i += 3;
-                }
-
-                Some(Self::RadioSubTelegram {
-                    radio_telegram,
+                Ok(Self::RadioSubTelegram {
+                    radio_telegram: data_slice,
                     opt_sub_telegram_number,
                     opt_destination_id,
                     opt_dbm,
                     opt_security_level,
                     opt_timestamp,
-                    opt_sub_telegram_info,
+                    sub_telegram_info_bytes,
                 })
             },
             4 => { // Event
-                if data_slice.len() < 1 {
-                    return None;
-                }
-
-                let event_code = data_slice[0];
-                EventData::from_data(event_code, &data_slice[1..], optional_slice)
-                    .map(Self::Event)
+                let (event_code, data) = data_slice.split_first()
+                    .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+                Ok(Self::Event {
+                    event_code: *event_code,
+                    data,
+                    optional_data: optional_slice,
+                })
             },
             5 => { // CommonCommand
-                if data_slice.len() < 1 {
-                    return None;
-                }
-
-                let command_code = data_slice[0];
-                CommandData::from_data(command_code, &data_slice[1..], optional_slice)
-                    .map(Self::CommonCommand)
+                let (command_code, data) = data_slice.split_first()
+                    .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+                Ok(Self::CommonCommand {
+                    command_code: *command_code,
+                    data,
+                    optional_data: optional_slice,
+                })
             },
             6 => { // SmartAckCommand
-                if data_slice.len() < 1 {
-                    return None;
-                }
-
-                let command_code = data_slice[0];
-                SmartAckData::from_data(command_code, &data_slice[1..], optional_slice)
-                    .map(Self::SmartAckCommand)
+                let (command_code, data) = data_slice.split_first()
+                    .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+                Ok(Self::SmartAckCommand {
+                    command_code: *command_code,
+                    data,
+                    optional_data: optional_slice,
+                })
             },
             7 => { // RemoteManCommand
-                if data_slice.len() < 4 {
-                    return None;
-                }
+                expect_min_length(data_slice, 4)?;
                 let function = u16::from_be_bytes(data_slice[0..2].try_into().unwrap());
                 let manufacturer = u16::from_be_bytes(data_slice[2..4].try_into().unwrap());
-                let message = MaxArray::from_iter_or_panic(
-                    data_slice[4..].iter().map(|b| *b).peekable()
-                );
 
                 let opt_destination_id = (optional_slice.len() >= 4)
                     .then(|| u32::from_be_bytes(optional_slice[0..4].try_into().unwrap()));
                 let opt_source_id = (optional_slice.len() >= 4 + 4)
                     .then(|| u32::from_be_bytes(optional_slice[4..4+4].try_into().unwrap()));
-                let opt_dbm = (optional_slice.len() >= 4 + 4 + 1)
+                let opt_dbm = (optional_slice.len() > 4 + 4)
                     .then(|| optional_slice[4+4]);
-                let opt_send_with_delay = (optional_slice.len() >= 4 + 4 + 1 + 1)
+                let opt_send_with_delay = (optional_slice.len() > 4 + 4 + 1)
                     .then(|| optional_slice[4+4+1].into());
 
-                Some(Self::RemoteManCommand {
+                Ok(Self::RemoteManCommand {
                     function,
                     manufacturer,
-                    message,
+                    message: &data_slice[4..],
                     opt_destination_id,
                     opt_source_id,
                     opt_dbm,
@@ -641,26 +1039,20 @@ impl Esp3Packet {
                 })
             },
             9 => { // RadioMessage
-                if data_slice.len() < 1 {
-                    return None;
-                }
-
-                let rorg = data_slice[0];
-                let data = MaxArray::from_iter_or_panic(
-                    data_slice[1..].iter().map(|b| *b).peekable()
-                );
+                let (rorg, data) = data_slice.split_first()
+                    .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
 
                 let opt_destination_id = (optional_slice.len() >= 4)
                     .then(|| u32::from_be_bytes(optional_slice[0..4].try_into().unwrap()));
                 let opt_source_id = (optional_slice.len() >= 4 + 4)
                     .then(|| u32::from_be_bytes(optional_slice[4..4+4].try_into().unwrap()));
-                let opt_dbm = (optional_slice.len() >= 4 + 4 + 1)
+                let opt_dbm = (optional_slice.len() > 4 + 4)
                     .then(|| optional_slice[4+4]);
-                let opt_security_level = (optional_slice.len() >= 4 + 4 + 1 + 1)
+                let opt_security_level = (optional_slice.len() > 4 + 4 + 1)
                     .then(|| optional_slice[4+4+1].into());
 
-                Some(Self::RadioMessage {
-                    rorg,
+                Ok(Self::RadioMessage {
+                    rorg: *rorg,
                     data,
                     opt_destination_id,
                     opt_source_id,
@@ -669,78 +1061,60 @@ impl Esp3Packet {
                 })
             },
             10 => { // RadioErp2
-                let data = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
-                );
-
-                let opt_sub_telegram_number = (optional_slice.len() >= 1)
+                let opt_sub_telegram_number = (!optional_slice.is_empty())
                     .then(|| optional_slice[0]);
-                let opt_dbm = (optional_slice.len() >= 1 + 1)
+                let opt_dbm = (optional_slice.len() > 1)
                     .then(|| optional_slice[1]);
-                let opt_security_level = (optional_slice.len() >= 1 + 1 + 1)
+                let opt_security_level = (optional_slice.len() > 1 + 1)
                     .then(|| optional_slice[1+1].into());
 
-                Some(Self::RadioErp2 {
-                    data,
+                Ok(Self::RadioErp2 {
+                    data: data_slice,
                     opt_sub_telegram_number,
                     opt_dbm,
                     opt_security_level,
                 })
             },
             12 => { // CommandAccepted
-                if data_slice.len() != 3 {
-                    return None;
-                }
+                expect_length(data_slice, 3)?;
 
                 let is_blocking = data_slice[0].into();
                 let estimated_time_ms = u16::from_be_bytes(data_slice[1..3].try_into().unwrap());
 
-                Some(Self::CommandAccepted {
+                Ok(Self::CommandAccepted {
                     is_blocking,
                     estimated_time_ms,
                 })
             },
             16 => { // Radio802Dot15Dot4
-                let raw_data = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
-                );
-
-                let opt_rssi = (optional_slice.len() >= 1)
+                let opt_rssi = (!optional_slice.is_empty())
                     .then(|| optional_slice[0]);
 
-                Some(Self::Radio802Dot15Dot4 {
-                    raw_data,
+                Ok(Self::Radio802Dot15Dot4 {
+                    raw_data: data_slice,
                     opt_rssi,
                 })
             },
             17 => { // Command24
-                if data_slice.len() < 1 {
-                    return None;
-                }
-
-                let command_code = data_slice[0];
-                Command24Data::from_data(command_code, &data_slice[1..], optional_slice)
-                    .map(Self::Command24)
+                let (command_code, data) = data_slice.split_first()
+                    .ok_or(ParseError::new(0, ParseErrorKind::MissingCode))?;
+                Ok(Self::Command24 {
+                    command_code: *command_code,
+                    data,
+                    optional_data: optional_slice,
+                })
             },
             other => {
-                let data = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
-                );
-                let optional_data = MaxArray::from_iter_or_panic(
-                    optional_slice.iter().map(|b| *b).peekable()
-                );
-
-                Some(Self::Unknown {
+                Ok(Self::Unknown {
                     packet_type: other,
-                    data,
-                    optional_data,
+                    data: data_slice,
+                    optional_data: optional_slice,
                 })
             }
         }
     }
 }
 
-
 /// An ESP3 security level value.
 #[derive(Clone, Copy, Debug)]
 #[from_to_repr::from_to_other(base_type = u8, derive_compare = "as_int")]
@@ -781,6 +1155,7 @@ pub struct SubTelegramInfo {
 }
 
 /// An EnOcean event that may occur.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum EventData {
     SmartAckReclaimNotSuccessful,
@@ -836,11 +1211,7 @@ impl EventData {
     }
 
     pub fn expects_response(&self) -> bool {
-        match self {
-            Self::SmartAckReclaimNotSuccessful => true,
-            Self::SmartAckConfirmLearn { .. } => true,
-            _ => false,
-        }
+        matches!(self, Self::SmartAckReclaimNotSuccessful | Self::SmartAckConfirmLearn { .. })
     }
 
     pub fn to_packet_data(&self) -> MaxArray<u8, MAX_DATA_LENGTH> {
@@ -955,18 +1326,14 @@ impl EventData {
         Some(ret)
     }
 
-    pub fn from_data(event_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+    pub fn from_data(event_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
         match event_code {
             1 => {
-                if data_slice.len() != 0 {
-                    return None;
-                }
-                Some(Self::SmartAckReclaimNotSuccessful)
+                expect_length(data_slice, 0)?;
+                Ok(Self::SmartAckReclaimNotSuccessful)
             },
             2 => {
-                if data_slice.len() != 16 {
-                    return None;
-                }
+                expect_length(data_slice, 16)?;
 
                 let postmaster_priority = PostmasterPriority::from_bits_truncate(data_slice[0]);
                 let manufacturer_id = u16::from_be_bytes(data_slice[1..3].try_into().unwrap());
@@ -980,7 +1347,7 @@ impl EventData {
                 let smart_ack_client_id = u32::from_be_bytes(data_slice[11..15].try_into().unwrap());
                 let hop_count = data_slice[15];
 
-                Some(Self::SmartAckConfirmLearn {
+                Ok(Self::SmartAckConfirmLearn {
                     postmaster_priority,
                     manufacturer_id,
                     eep,
@@ -991,85 +1358,71 @@ impl EventData {
                 })
             },
             3 => {
-                if data_slice.len() != 3 {
-                    return None;
-                }
+                expect_length(data_slice, 3)?;
 
                 let response_time = u16::from_be_bytes(data_slice[0..2].try_into().unwrap());
                 let confirm_code = data_slice[2].into();
 
-                Some(Self::SmartAckLearnAck {
+                Ok(Self::SmartAckLearnAck {
                     response_time,
                     confirm_code,
                 })
             },
             4 => {
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let wakeup_cause = data_slice[0].into();
 
-                let opt_security_mode = (optional_slice.len() >= 1)
+                let opt_security_mode = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
-                Some(Self::CoReady {
+                Ok(Self::CoReady {
                     wakeup_cause,
                     opt_security_mode,
                 })
             },
             5 => {
-                if data_slice.len() != 5 {
-                    return None;
-                }
+                expect_length(data_slice, 5)?;
 
                 let cause = data_slice[0].into();
                 let device_id = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
 
-                Some(Self::CoEventSecureDevices {
+                Ok(Self::CoEventSecureDevices {
                     cause,
                     device_id,
                 })
             },
             6 => {
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let sending_possible = data_slice[0].into();
 
-                Some(Self::CoDutyCycleLimit {
+                Ok(Self::CoDutyCycleLimit {
                     sending_possible,
                 })
             },
             7 => {
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let reason = data_slice[0].into();
 
-                Some(Self::CoTransmitFailed {
+                Ok(Self::CoTransmitFailed {
                     reason,
                 })
             },
             8 => {
-                if data_slice.len() != 0 {
-                    return None;
-                }
-                Some(Self::CoTxDone)
+                expect_length(data_slice, 0)?;
+                Ok(Self::CoTxDone)
             },
             9 => {
-                if data_slice.len() != 0 {
-                    return None;
-                }
-                Some(Self::CoLearnModeDisabled)
+                expect_length(data_slice, 0)?;
+                Ok(Self::CoLearnModeDisabled)
             },
             other => {
-                Some(Self::Unknown {
+                Ok(Self::Unknown {
                     code: other,
-                    data: MaxArray::from_iter_or_panic(data_slice.iter().map(|b| *b).peekable()),
-                    optional_data: MaxArray::from_iter_or_panic(optional_slice.iter().map(|b| *b).peekable()),
+                    data: MaxArray::from_iter_or_panic(data_slice.iter().copied().peekable()),
+                    optional_data: MaxArray::from_iter_or_panic(optional_slice.iter().copied().peekable()),
                 })
             },
         }
@@ -1077,6 +1430,7 @@ impl EventData {
 }
 
 /// An EnOcean common command.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum CommandData {
     CoWrSleep {
@@ -1770,14 +2124,12 @@ impl CommandData {
         }
     }
 
-    pub fn from_data(command_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+    pub fn from_data(command_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
         match command_code {
             2..=6|8|10|13|15|21|24|35|37|39|49|51|59|63|65 => {
-                if data_slice.len() != 0 {
-                    return None;
-                }
+                expect_length(data_slice, 0)?;
                 #[allow(deprecated)]
-                Some(match command_code {
+                Ok(match command_code {
                     2 => Self::CoWrReset,
                     3 => Self::CoRdVersion,
                     4 => Self::CoRdSysLog,
@@ -1801,174 +2153,146 @@ impl CommandData {
                 })
             },
             1 => { // CoWrSleep
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let deep_sleep_period = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                Some(Self::CoWrSleep {
+                Ok(Self::CoWrSleep {
                     deep_sleep_period,
                 })
             },
             7 => { // CoWrIdBase
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let base_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                Some(Self::CoWrIdBase {
+                Ok(Self::CoWrIdBase {
                     base_id,
                 })
             },
             9 => { // CoWrRepeater
-                if data_slice.len() != 2 {
-                    return None;
-                }
+                expect_length(data_slice, 2)?;
 
                 let enable = data_slice[0].into();
                 let level = data_slice[1].into();
-                Some(Self::CoWrRepeater {
+                Ok(Self::CoWrRepeater {
                     enable,
                     level,
                 })
             },
             11 => { // CoWrFilterAdd
-                if data_slice.len() != 6 {
-                    return None;
-                }
+                expect_length(data_slice, 6)?;
 
                 let criterion = data_slice[0].into();
                 let value = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let action = data_slice[5].into();
-                Some(Self::CoWrFilterAdd {
+                Ok(Self::CoWrFilterAdd {
                     criterion,
                     value,
                     action,
                 })
             },
             12 => { // CoWrFilterDel
-                if data_slice.len() != 6 {
-                    return None;
-                }
+                expect_length(data_slice, 6)?;
 
                 let criterion = data_slice[0].into();
                 let value = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let action = data_slice[5].into();
-                Some(Self::CoWrFilterDel {
+                Ok(Self::CoWrFilterDel {
                     criterion,
                     value,
                     action,
                 })
             },
             14 => { // CoWrFilterEnable
-                if data_slice.len() != 2 {
-                    return None;
-                }
+                expect_length(data_slice, 2)?;
 
                 let enable = data_slice[0].into();
                 let operator = data_slice[1].into();
-                Some(Self::CoWrFilterEnable {
+                Ok(Self::CoWrFilterEnable {
                     enable,
                     operator,
                 })
             },
             16 => { // CoWrWaitMaturity
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let wait_for_maturity = data_slice[0].into();
-                Some(Self::CoWrWaitMaturity {
+                Ok(Self::CoWrWaitMaturity {
                     wait_for_maturity,
                 })
             },
             17 => { // CoWrSubTelegram
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let enable_subtelegram_info = data_slice[0].into();
-                Some(Self::CoWrSubTelegram {
+                Ok(Self::CoWrSubTelegram {
                     enable_subtelegram_info,
                 })
             },
             18 => { // CoWrMem
-                if data_slice.len() < 5 {
-                    return None;
-                }
+                expect_min_length(data_slice, 5)?;
 
                 let memory_type = data_slice[0].into();
                 let address = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let data = MaxArray::from_iter_or_panic(
-                    data_slice[5..].iter().map(|b| *b).peekable()
+                    data_slice[5..].iter().copied().peekable()
                 );
-                Some(Self::CoWrMem {
+                Ok(Self::CoWrMem {
                     memory_type,
                     address,
                     data,
                 })
             },
             19 => { // CoRdMem
-                if data_slice.len() != 7 {
-                    return None;
-                }
+                expect_length(data_slice, 7)?;
 
                 let memory_type = data_slice[0].into();
                 let address = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let length = u16::from_be_bytes(data_slice[5..7].try_into().unwrap());
-                Some(Self::CoRdMem {
+                Ok(Self::CoRdMem {
                     memory_type,
                     address,
                     length,
                 })
             },
             20 => { // CoRdMemAddress
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let area = data_slice[0].into();
-                Some(Self::CoRdMemAddress {
+                Ok(Self::CoRdMemAddress {
                     area,
                 })
             },
             22 => { // CoWrSecurity
-                if data_slice.len() != 10 {
-                    return None;
-                }
+                expect_length(data_slice, 10)?;
 
-                let security_level = data_slice[0].into();
+                let security_level = data_slice[0];
                 let key = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let rolling_code = u32::from_be_bytes(data_slice[5..9].try_into().unwrap());
                 #[allow(deprecated)]
-                Some(Self::CoWrSecurity {
+                Ok(Self::CoWrSecurity {
                     security_level,
                     key,
                     rolling_code,
                 })
             },
             23 => { // CoWrLearnMode
-                if data_slice.len() != 5 {
-                    return None;
-                }
+                expect_length(data_slice, 5)?;
 
                 let enable = data_slice[0].into();
                 let timeout = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
 
-                let opt_channel = (optional_slice.len() >= 1)
+                let opt_channel = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
-                Some(Self::CoWrLearnMode {
+                Ok(Self::CoWrLearnMode {
                     enable,
                     timeout,
                     opt_channel,
                 })
             },
             25 => { // CoWrSecureDeviceAdd
-                if data_slice.len() != 24 {
-                    return None;
-                }
+                expect_length(data_slice, 24)?;
 
-                let slf = data_slice[0].into();
+                let slf = data_slice[0];
                 let device_id = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let private_key = [
                     u32::from_be_bytes(data_slice[5..9].try_into().unwrap()),
@@ -1984,15 +2308,15 @@ impl CommandData {
                     | u32::from(data_slice[23])
                 ;
 
-                let opt_direction = (optional_slice.len() >= 1)
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
                 let opt_is_ptm_sender = (optional_slice.len() >= 2)
                     .then(|| optional_slice[1].into());
                 let opt_teach_info = (optional_slice.len() >= 3)
-                    .then(|| optional_slice[2].into());
+                    .then(|| optional_slice[2]);
 
                 #[allow(deprecated)]
-                Some(Self::CoWrSecureDeviceAdd {
+                Ok(Self::CoWrSecureDeviceAdd {
                     slf,
                     device_id,
                     private_key,
@@ -2003,75 +2327,63 @@ impl CommandData {
                 })
             },
             26 => { // CoWrSecureDeviceDel
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                let opt_direction = (optional_slice.len() >= 1)
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
                 #[allow(deprecated)]
-                Some(Self::CoWrSecureDeviceDel {
+                Ok(Self::CoWrSecureDeviceDel {
                     device_id,
                     opt_direction,
                 })
             },
             27 => { // CoRdSecureDeviceByIndex
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let index = data_slice[0];
-                let opt_direction = (optional_slice.len() >= 1)
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
                 #[allow(deprecated)]
-                Some(Self::CoRdSecureDeviceByIndex {
+                Ok(Self::CoRdSecureDeviceByIndex {
                     index,
                     opt_direction,
                 })
             },
             28 => { // CoWrMode
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let mode = data_slice[0].into();
-                Some(Self::CoWrMode {
+                Ok(Self::CoWrMode {
                     mode,
                 })
             },
             29 => { // CoRdNumSecureDevices
-                if data_slice.len() != 0 {
-                    return None;
-                }
+                expect_length(data_slice, 0)?;
 
-                let opt_direction = (optional_slice.len() >= 1)
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
-                Some(Self::CoRdNumSecureDevices {
+                Ok(Self::CoRdNumSecureDevices {
                     opt_direction,
                 })
             },
             30 => { // CoRdSecureDeviceById
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                let opt_direction = (optional_slice.len() >= 1)
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
-                Some(Self::CoRdSecureDeviceById {
+                Ok(Self::CoRdSecureDeviceById {
                     device_id,
                     opt_direction,
                 })
             },
             31 => { // CoWrSecureDeviceAddPsk
-                if data_slice.len() != 20 {
-                    return None;
-                }
+                expect_length(data_slice, 20)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
                 let psk = [
@@ -2081,124 +2393,102 @@ impl CommandData {
                     u32::from_be_bytes(data_slice[16..20].try_into().unwrap()),
                 ];
 
-                Some(Self::CoWrSecureDeviceAddPsk {
+                Ok(Self::CoWrSecureDeviceAddPsk {
                     device_id,
                     psk,
                 })
             },
             32 => { // CoWrSecureDeviceSendTeachIn
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                let opt_teach_info = (optional_slice.len() >= 1)
-                    .then(|| optional_slice[0].into());
+                let opt_teach_info = (!optional_slice.is_empty())
+                    .then(|| optional_slice[0]);
 
-                Some(Self::CoWrSecureDeviceSendTeachIn {
+                Ok(Self::CoWrSecureDeviceSendTeachIn {
                     device_id,
                     opt_teach_info,
                 })
             },
             33 => { // CoWrTemporaryRlcWindow
-                if data_slice.len() != 5 {
-                    return None;
-                }
+                expect_length(data_slice, 5)?;
 
                 let enable = data_slice[0].into();
                 let rlc_window = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
 
-                Some(Self::CoWrTemporaryRlcWindow {
+                Ok(Self::CoWrTemporaryRlcWindow {
                     enable,
                     rlc_window,
                 })
             },
             34 => { // CoRdSecureDevicePsk
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                Some(Self::CoRdSecureDevicePsk {
+                Ok(Self::CoRdSecureDevicePsk {
                     device_id,
                 })
             },
             36 => { // CoSetBaudRate
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let baud_rate = data_slice[0].into();
-                Some(Self::CoSetBaudRate {
+                Ok(Self::CoSetBaudRate {
                     baud_rate,
                 })
             },
             46 => { // CoWrReManCode
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let code = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                Some(Self::CoWrReManCode {
+                Ok(Self::CoWrReManCode {
                     code,
                 })
             },
             47 => { // CoWrStartupDelay
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let delay = data_slice[0];
-                Some(Self::CoWrStartupDelay {
+                Ok(Self::CoWrStartupDelay {
                     delay,
                 })
             },
             48 => { // CoWrReManRepeating
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let repeat_re_man_telegrams = data_slice[0].into();
-                Some(Self::CoWrReManRepeating {
+                Ok(Self::CoWrReManRepeating {
                     repeat_re_man_telegrams,
                 })
             },
             50 => { // CoSetNoiseThreshold
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
-                let rssi_level = data_slice[0].into();
-                Some(Self::CoSetNoiseThreshold {
+                let rssi_level = data_slice[0];
+                Ok(Self::CoSetNoiseThreshold {
                     rssi_level,
                 })
             },
             54 => { // CoWrRlcSavePeriod
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
-                let save_period = data_slice[0].into();
-                Some(Self::CoWrRlcSavePeriod {
+                let save_period = data_slice[0];
+                Ok(Self::CoWrRlcSavePeriod {
                     save_period,
                 })
             },
             55 => { // CoWrRlcLegacyMode
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let enable = data_slice[0].into();
-                Some(Self::CoWrRlcLegacyMode {
+                Ok(Self::CoWrRlcLegacyMode {
                     enable,
                 })
             },
             56 => { // CoWrSecureDeviceV2Add
-                if data_slice.len() != 26 {
-                    return None;
-                }
+                expect_length(data_slice, 26)?;
 
-                let slf = data_slice[0].into();
+                let slf = data_slice[0];
                 let device_id = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
                 let private_key = [
                     u32::from_be_bytes(data_slice[5..9].try_into().unwrap()),
@@ -2209,10 +2499,10 @@ impl CommandData {
                 let rolling_code = u32::from_be_bytes(data_slice[21..25].try_into().unwrap());
                 let teach_info = data_slice[25];
 
-                let opt_direction = (optional_slice.len() >= 1)
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
-                Some(Self::CoWrSecureDeviceV2Add {
+                Ok(Self::CoWrSecureDeviceV2Add {
                     slf,
                     device_id,
                     private_key,
@@ -2222,36 +2512,30 @@ impl CommandData {
                 })
             },
             57 => { // CoRdSecureDeviceV2ByIndex
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
-                let index = data_slice[0].into();
-                let opt_direction = (optional_slice.len() >= 1)
+                let index = data_slice[0];
+                let opt_direction = (!optional_slice.is_empty())
                     .then(|| optional_slice[0].into());
 
-                Some(Self::CoRdSecureDeviceV2ByIndex {
+                Ok(Self::CoRdSecureDeviceV2ByIndex {
                     index,
                     opt_direction,
                 })
             },
             58 => { // CoWrRssiTestMode
-                if data_slice.len() != 3 {
-                    return None;
-                }
+                expect_length(data_slice, 3)?;
 
                 let enable = data_slice[0].into();
                 let timeout = u16::from_be_bytes(data_slice[1..3].try_into().unwrap());
 
-                Some(Self::CoWrRssiTestMode {
+                Ok(Self::CoWrRssiTestMode {
                     enable,
                     timeout,
                 })
             },
             60 => { // CoWrSecureDeviceMaintenanceKey
-                if data_slice.len() != 21 {
-                    return None;
-                }
+                expect_length(data_slice, 21)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
                 let maintenance_key = [
@@ -2262,47 +2546,41 @@ impl CommandData {
                 ];
                 let key_number = data_slice[20];
 
-                Some(Self::CoWrSecureDeviceMaintenanceKey {
+                Ok(Self::CoWrSecureDeviceMaintenanceKey {
                     device_id,
                     maintenance_key,
                     key_number,
                 })
             },
             61 => { // CoRdSecureDeviceMaintenanceKey
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let index = data_slice[0];
-                Some(Self::CoRdSecureDeviceMaintenanceKey {
+                Ok(Self::CoRdSecureDeviceMaintenanceKey {
                     index,
                 })
             },
             62 => { // CoWrTransparentMode
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let enable = data_slice[0].into();
-                Some(Self::CoWrTransparentMode {
+                Ok(Self::CoWrTransparentMode {
                     enable,
                 })
             },
             64 => { // CoWrTxOnlyMode
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let mode = data_slice[0].into();
-                Some(Self::CoWrTxOnlyMode {
+                Ok(Self::CoWrTxOnlyMode {
                     mode,
                 })
             },
             other => {
-                Some(Self::Unknown {
+                Ok(Self::Unknown {
                     code: other,
-                    data: MaxArray::from_iter_or_panic(data_slice.iter().map(|b| *b).peekable()),
-                    optional_data: MaxArray::from_iter_or_panic(optional_slice.iter().map(|b| *b).peekable()),
+                    data: MaxArray::from_iter_or_panic(data_slice.iter().copied().peekable()),
+                    optional_data: MaxArray::from_iter_or_panic(optional_slice.iter().copied().peekable()),
                 })
             },
         }
@@ -2310,6 +2588,7 @@ impl CommandData {
 }
 
 /// Data carried by a Smart Acknowledgement command.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum SmartAckData {
     SaWrLearnMode {
@@ -2451,43 +2730,37 @@ impl SmartAckData {
         Some(MaxArray::new())
     }
 
-    pub fn from_data(event_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+    pub fn from_data(event_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
         match event_code {
             2|6 => {
-                if data_slice.len() != 0 {
-                    return None;
-                }
+                expect_length(data_slice, 0)?;
 
-                Some(match event_code {
+                Ok(match event_code {
                     2 => Self::SaRdLearnMode,
                     6 => Self::SaRdLearnedClients,
                     _ => unreachable!(),
                 })
             },
             1 => { // SaWrLearnMode
-                if data_slice.len() != 6 {
-                    return None;
-                }
+                expect_length(data_slice, 6)?;
 
                 let enable = data_slice[0].into();
                 let extended = data_slice[1].into();
                 let timeout = u32::from_be_bytes(data_slice[2..6].try_into().unwrap());
-                Some(Self::SaWrLearnMode {
+                Ok(Self::SaWrLearnMode {
                     enable,
                     extended,
                     timeout,
                 })
             },
             3 => { // SaWrLearnConfirm
-                if data_slice.len() != 11 {
-                    return None;
-                }
+                expect_length(data_slice, 11)?;
 
                 let response_time = u16::from_be_bytes(data_slice[0..2].try_into().unwrap());
                 let confirm_code = data_slice[2].into();
                 let postmaster_candidate_id = u32::from_be_bytes(data_slice[3..7].try_into().unwrap());
                 let smart_ack_client_id = u32::from_be_bytes(data_slice[7..11].try_into().unwrap());
-                Some(Self::SaWrLearnConfirm {
+                Ok(Self::SaWrLearnConfirm {
                     response_time,
                     confirm_code,
                     postmaster_candidate_id,
@@ -2495,9 +2768,7 @@ impl SmartAckData {
                 })
             },
             4 => { // SaWrClientLearnRequest
-                if data_slice.len() != 5 {
-                    return None;
-                }
+                expect_length(data_slice, 5)?;
 
                 let manufacturer_id = u16::from_be_bytes(data_slice[0..2].try_into().unwrap());
                 let eep =
@@ -2505,74 +2776,64 @@ impl SmartAckData {
                     | (u32::from(data_slice[3]) << 8)
                     | u32::from(data_slice[4])
                 ;
-                Some(Self::SaWrClientLearnRequest {
+                Ok(Self::SaWrClientLearnRequest {
                     manufacturer_id,
                     eep,
                 })
             },
             5 => { // SaWrReset
-                if data_slice.len() != 4 {
-                    return None;
-                }
+                expect_length(data_slice, 4)?;
 
                 let client_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-                Some(Self::SaWrReset {
+                Ok(Self::SaWrReset {
                     client_id,
                 })
             },
             7 => { // SaWrReclaims
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let reclaim_count = data_slice[0];
-                Some(Self::SaWrReclaims {
+                Ok(Self::SaWrReclaims {
                     reclaim_count,
                 })
             },
             8 => { // SaWrPostmaster
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let mailbox_count = data_slice[0];
-                Some(Self::SaWrPostmaster {
+                Ok(Self::SaWrPostmaster {
                     mailbox_count,
                 })
             },
             9 => { // SaRdMailboxStatus
-                if data_slice.len() != 8 {
-                    return None;
-                }
+                expect_length(data_slice, 8)?;
 
                 let smart_ack_client_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
                 let controller_id = u32::from_be_bytes(data_slice[4..8].try_into().unwrap());
-                Some(Self::SaRdMailboxStatus {
+                Ok(Self::SaRdMailboxStatus {
                     smart_ack_client_id,
                     controller_id,
                 })
             },
             10 => { // SaDelMailbox
-                if data_slice.len() != 8 {
-                    return None;
-                }
+                expect_length(data_slice, 8)?;
 
                 let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
                 let controller_id = u32::from_be_bytes(data_slice[4..8].try_into().unwrap());
-                Some(Self::SaDelMailbox {
+                Ok(Self::SaDelMailbox {
                     device_id,
                     controller_id,
                 })
             },
             other => {
                 let data = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
+                    data_slice.iter().copied().peekable()
                 );
                 let optional_data = MaxArray::from_iter_or_panic(
-                    optional_slice.iter().map(|b| *b).peekable()
+                    optional_slice.iter().copied().peekable()
                 );
 
-                Some(Self::Unknown {
+                Ok(Self::Unknown {
                     code: other,
                     data,
                     optional_data,
@@ -2583,6 +2844,7 @@ impl SmartAckData {
 }
 
 /// Data carried by a 2.4 GHz command.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Command24Data {
     SetChannel {
@@ -2631,33 +2893,29 @@ impl Command24Data {
         Some(MaxArray::new())
     }
 
-    pub fn from_data(event_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+    pub fn from_data(event_code: u8, data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
         match event_code {
             1 => {
-                if data_slice.len() != 1 {
-                    return None;
-                }
+                expect_length(data_slice, 1)?;
 
                 let channel = data_slice[0];
-                Some(Self::SetChannel {
+                Ok(Self::SetChannel {
                     channel,
                 })
             },
             2 => {
-                if data_slice.len() != 0 {
-                    return None;
-                }
-                Some(Self::ReadChannel)
+                expect_length(data_slice, 0)?;
+                Ok(Self::ReadChannel)
             },
             other => {
                 let data = MaxArray::from_iter_or_panic(
-                    data_slice.iter().map(|b| *b).peekable()
+                    data_slice.iter().copied().peekable()
                 );
                 let optional_data = MaxArray::from_iter_or_panic(
-                    optional_slice.iter().map(|b| *b).peekable()
+                    optional_slice.iter().copied().peekable()
                 );
 
-                Some(Self::Unknown {
+                Ok(Self::Unknown {
                     code: other,
                     data,
                     optional_data,
@@ -2975,3 +3233,68 @@ pub enum MailboxStatus {
     DoesNotExist = 2,
     Other(u8),
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandData, Esp3Packet, HEADER_LENGTH};
+    use crate::crc8::crc8_ccitt;
+    use crate::esp3::error::{ParseError, ParseErrorKind};
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_packet_stream() {
+        let version = Esp3Packet::CommonCommand(CommandData::CoRdVersion)
+            .to_packet().unwrap();
+        let reset = Esp3Packet::CommonCommand(CommandData::CoWrReset)
+            .to_packet().unwrap();
+
+        let mut stream = Vec::new();
+        stream.extend_from_slice(version.as_slice());
+        stream.extend_from_slice(reset.as_slice());
+        assert_eq!(
+            Esp3Packet::parse_prefix(&stream),
+            Ok((Esp3Packet::CommonCommand(CommandData::CoRdVersion), version.len())),
+        );
+        assert_eq!(
+            Esp3Packet::parse(&stream).map_err(|e| e.kind),
+            Err(ParseErrorKind::TrailingBytes { expected: version.len(), actual: stream.len() }),
+        );
+
+        // garbage, a packet, a packet with a broken data CRC, a packet, a packet cut off by the next
+        // one, a packet, a truncated packet
+        let mut broken = version.clone();
+        broken.as_mut_slice()[HEADER_LENGTH] ^= 0xFF;
+        let mut cut_off = vec![0x55, 0x00, 0x64, 0x00, 0x05, 0x00];
+        cut_off[5] = crc8_ccitt(&cut_off[1..5]);
+        let mut stream = vec![0x00, 0x01];
+        stream.extend_from_slice(version.as_slice());
+        stream.extend_from_slice(broken.as_slice());
+        stream.extend_from_slice(reset.as_slice());
+        stream.extend_from_slice(&cut_off);
+        stream.extend_from_slice(reset.as_slice());
+        stream.extend_from_slice(&version.as_slice()[0..4]);
+
+        let broken_start = 2 + version.len();
+        let reset_start = broken_start + broken.len();
+        let cut_off_start = reset_start + reset.len();
+        let second_reset_start = cut_off_start + cut_off.len();
+        let results: Vec<_> = Esp3Packet::parse_all(&stream).collect();
+        assert_eq!(results, vec![
+            Err(ParseError::new(0, ParseErrorKind::MissingSyncByte { found: 0x00 })),
+            Ok((2, Esp3Packet::CommonCommand(CommandData::CoRdVersion))),
+            Err(ParseError::new(broken_start + broken.len() - 1, ParseErrorKind::DataCrcMismatch {
+                calculated: crc8_ccitt(&broken.as_slice()[HEADER_LENGTH..broken.len()-1]),
+                received: broken.as_slice()[broken.len()-1],
+            })),
+            Err(ParseError::new(broken_start + 1, ParseErrorKind::MissingSyncByte { found: 0x00 })),
+            Ok((reset_start, Esp3Packet::CommonCommand(CommandData::CoWrReset))),
+            Err(ParseError::new(stream.len(), ParseErrorKind::Truncated {
+                needed: HEADER_LENGTH + 0x64 + 1,
+                available: stream.len() - cut_off_start,
+            })),
+            Ok((second_reset_start, Esp3Packet::CommonCommand(CommandData::CoWrReset))),
+            Err(ParseError::new(stream.len(), ParseErrorKind::Truncated { needed: 7, available: 4 })),
+        ]);
+    }
+}
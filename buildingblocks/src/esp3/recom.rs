@@ -0,0 +1,589 @@
+//! Remote Commissioning (ReCom) of EnOcean devices.
+//!
+//! Remote commissioning builds upon [remote management](crate::esp3::remote_man): its requests and
+//! answers are transported as [RemoteManMessage]s with the standard manufacturer ID. This module
+//! covers reading and writing the link tables and the configuration parameters of a device, as
+//! described in _Remote Commissioning_.
+//!
+//! Link tables and configuration parameters are usually too large to be transferred in a single
+//! message; [link_table_queries] and [device_config_queries] split the transfer into pages.
+
+
+use crate::esp3::remote_man::{MAXIMUM_REMAN_DATA_LENGTH, RemoteManMessage, STANDARD_MANUFACTURER_ID};
+use crate::max_array::MaxArray;
+use crate::max_array_ext::MaxArrayPushIntExt;
+
+
+/// The length of an encoded link table entry in bytes.
+const LINK_TABLE_ENTRY_LENGTH: usize = 9;
+
+/// The maximum number of link table entries that fit into a single message.
+pub const MAXIMUM_LINK_TABLE_ENTRIES: usize = (MAXIMUM_REMAN_DATA_LENGTH - 1) / LINK_TABLE_ENTRY_LENGTH;
+
+
+/// The link table being accessed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LinkTableDirection {
+    /// The table of devices whose telegrams this device reacts to.
+    Inbound,
+
+    /// The table of devices to which this device sends telegrams.
+    Outbound,
+}
+impl LinkTableDirection {
+    fn from_byte(b: u8) -> Self {
+        if (b & 0b1000_0000) != 0 {
+            Self::Outbound
+        } else {
+            Self::Inbound
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Inbound => 0b0000_0000,
+            Self::Outbound => 0b1000_0000,
+        }
+    }
+}
+
+/// An entry in a link table.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LinkTableEntry {
+    /// The position of this entry in the link table.
+    pub index: u8,
+
+    /// The ID of the linked device.
+    pub device_id: u32,
+
+    pub eep_rorg: u8,
+    pub eep_func: u8,
+    pub eep_type: u8,
+
+    /// The channel of the linked device.
+    pub channel: u8,
+}
+impl LinkTableEntry {
+    fn from_slice(bytes: &[u8]) -> Self {
+        Self {
+            index: bytes[0],
+            device_id: u32::from_be_bytes(bytes[1..5].try_into().unwrap()),
+            eep_rorg: bytes[5],
+            eep_func: bytes[6],
+            eep_type: bytes[7],
+            channel: bytes[8],
+        }
+    }
+
+    fn push_to<const MAX_SIZE: usize>(&self, data: &mut MaxArray<u8, MAX_SIZE>) {
+        data.push(self.index).unwrap();
+        data.push_u32_be(self.device_id).unwrap();
+        data.push(self.eep_rorg).unwrap();
+        data.push(self.eep_func).unwrap();
+        data.push(self.eep_type).unwrap();
+        data.push(self.channel).unwrap();
+    }
+}
+
+/// Decodes a link table direction byte followed by link table entries.
+fn link_table_from_slice(data: &[u8]) -> Option<(LinkTableDirection, MaxArray<LinkTableEntry, MAXIMUM_LINK_TABLE_ENTRIES>)> {
+    if data.is_empty() || !(data.len() - 1).is_multiple_of(LINK_TABLE_ENTRY_LENGTH) {
+        return None;
+    }
+
+    let direction = LinkTableDirection::from_byte(data[0]);
+    let entries = MaxArray::from_iter_or_panic(
+        data[1..].chunks(LINK_TABLE_ENTRY_LENGTH)
+            .map(LinkTableEntry::from_slice)
+            .peekable()
+    );
+    Some((direction, entries))
+}
+
+
+/// A collection of device configuration parameters, as transferred in a single message.
+///
+/// Each parameter consists of a 16-bit index and a value of up to 255 bytes.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DeviceConfigEntries {
+    data: MaxArray<u8, MAXIMUM_REMAN_DATA_LENGTH>,
+}
+impl DeviceConfigEntries {
+    /// Creates an empty collection of configuration parameters.
+    pub const fn new() -> Self {
+        Self {
+            data: MaxArray::new(),
+        }
+    }
+
+    /// Appends a configuration parameter. Returns `false` if the value is longer than 255 bytes or
+    /// the parameter does not fit into the message anymore.
+    pub fn push(&mut self, index: u16, value: &[u8]) -> bool {
+        let value_length: u8 = match value.len().try_into() {
+            Ok(vl) => vl,
+            Err(_) => return false,
+        };
+        if !self.data.can_fit(2 + 1 + value.len()) {
+            return false;
+        }
+
+        self.data.push_u16_be(index).unwrap();
+        self.data.push(value_length).unwrap();
+        for b in value {
+            self.data.push(*b).unwrap();
+        }
+        true
+    }
+
+    /// Returns whether the collection contains no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.data.len() == 0
+    }
+
+    /// Returns an iterator over the index and value of each parameter.
+    pub fn iter(&self) -> DeviceConfigEntriesIter<'_> {
+        DeviceConfigEntriesIter {
+            rest: self.data.as_slice(),
+        }
+    }
+
+    /// The encoded parameters.
+    pub fn as_slice(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+
+    /// Attempts to decode configuration parameters from the given slice.
+    pub fn from_slice(data: &[u8]) -> Option<Self> {
+        if data.len() > MAXIMUM_REMAN_DATA_LENGTH {
+            return None;
+        }
+
+        // ensure the lengths are consistent
+        let mut rest = data;
+        while !rest.is_empty() {
+            if rest.len() < 3 {
+                return None;
+            }
+            let value_length = usize::from(rest[2]);
+            if rest.len() < 3 + value_length {
+                return None;
+            }
+            rest = &rest[3+value_length..];
+        }
+
+        Some(Self {
+            data: MaxArray::from_iter_or_panic(data.iter().copied().peekable()),
+        })
+    }
+}
+impl Default for DeviceConfigEntries {
+    fn default() -> Self { Self::new() }
+}
+
+/// An iterator over the parameters in a [DeviceConfigEntries] collection.
+pub struct DeviceConfigEntriesIter<'a> {
+    rest: &'a [u8],
+}
+impl<'a> Iterator for DeviceConfigEntriesIter<'a> {
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.len() < 3 {
+            return None;
+        }
+        let index = u16::from_be_bytes(self.rest[0..2].try_into().unwrap());
+        let value_length = usize::from(self.rest[2]);
+        let value = &self.rest[3..3+value_length];
+        self.rest = &self.rest[3+value_length..];
+        Some((index, value))
+    }
+}
+
+
+/// A remote commissioning request, sent to a device.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RecomRequest {
+    GetLinkTableMetadata,
+    GetLinkTable {
+        direction: LinkTableDirection,
+        start_index: u8,
+        end_index: u8,
+    },
+    SetLinkTable {
+        direction: LinkTableDirection,
+        entries: MaxArray<LinkTableEntry, MAXIMUM_LINK_TABLE_ENTRIES>,
+    },
+    GetDeviceConfig {
+        start_index: u16,
+        end_index: u16,
+    },
+    SetDeviceConfig {
+        entries: DeviceConfigEntries,
+    },
+}
+impl RecomRequest {
+    /// Returns the function number of this request.
+    pub fn function_number(&self) -> u16 {
+        match self {
+            Self::GetLinkTableMetadata => 0x210,
+            Self::GetLinkTable { .. } => 0x211,
+            Self::SetLinkTable { .. } => 0x212,
+            Self::GetDeviceConfig { .. } => 0x230,
+            Self::SetDeviceConfig { .. } => 0x231,
+        }
+    }
+
+    /// Whether the device responds to this request with a [RecomAnswer].
+    pub fn expects_answer(&self) -> bool {
+        matches!(self, Self::GetLinkTableMetadata|Self::GetLinkTable { .. }|Self::GetDeviceConfig { .. })
+    }
+
+    /// Encodes this request into a remote management message.
+    pub fn to_message(&self) -> RemoteManMessage {
+        let mut data = MaxArray::new();
+        match self {
+            Self::GetLinkTableMetadata => {},
+            Self::GetLinkTable { direction, start_index, end_index } => {
+                data.push(direction.to_byte()).unwrap();
+                data.push(*start_index).unwrap();
+                data.push(*end_index).unwrap();
+            },
+            Self::SetLinkTable { direction, entries } => {
+                data.push(direction.to_byte()).unwrap();
+                for entry in entries.iter() {
+                    entry.push_to(&mut data);
+                }
+            },
+            Self::GetDeviceConfig { start_index, end_index } => {
+                data.push_u16_be(*start_index).unwrap();
+                data.push_u16_be(*end_index).unwrap();
+            },
+            Self::SetDeviceConfig { entries } => {
+                for b in entries.as_slice() {
+                    data.push(*b).unwrap();
+                }
+            },
+        }
+
+        RemoteManMessage {
+            function: self.function_number(),
+            manufacturer: STANDARD_MANUFACTURER_ID,
+            data,
+        }
+    }
+
+    /// Attempts to decode a remote commissioning request from the given message.
+    pub fn from_message(message: &RemoteManMessage) -> Option<Self> {
+        if message.manufacturer != STANDARD_MANUFACTURER_ID {
+            return None;
+        }
+
+        let data = message.data.as_slice();
+        match message.function {
+            0x210 => data.is_empty().then_some(Self::GetLinkTableMetadata),
+            0x211 => {
+                if data.len() != 3 {
+                    return None;
+                }
+                Some(Self::GetLinkTable {
+                    direction: LinkTableDirection::from_byte(data[0]),
+                    start_index: data[1],
+                    end_index: data[2],
+                })
+            },
+            0x212 => {
+                let (direction, entries) = link_table_from_slice(data)?;
+                Some(Self::SetLinkTable {
+                    direction,
+                    entries,
+                })
+            },
+            0x230 => {
+                if data.len() != 4 {
+                    return None;
+                }
+                Some(Self::GetDeviceConfig {
+                    start_index: u16::from_be_bytes(data[0..2].try_into().unwrap()),
+                    end_index: u16::from_be_bytes(data[2..4].try_into().unwrap()),
+                })
+            },
+            0x231 => DeviceConfigEntries::from_slice(data)
+                .map(|entries| Self::SetDeviceConfig { entries }),
+            _ => None,
+        }
+    }
+}
+
+
+/// A remote commissioning answer, sent by a device in response to a [RecomRequest].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RecomAnswer {
+    LinkTableMetadata {
+        inbound_supported: bool,
+        outbound_supported: bool,
+        inbound_count: u8,
+        inbound_max: u8,
+        outbound_count: u8,
+        outbound_max: u8,
+    },
+    LinkTable {
+        direction: LinkTableDirection,
+        entries: MaxArray<LinkTableEntry, MAXIMUM_LINK_TABLE_ENTRIES>,
+    },
+    DeviceConfig {
+        entries: DeviceConfigEntries,
+    },
+}
+impl RecomAnswer {
+    /// Returns the function number of this answer.
+    pub fn function_number(&self) -> u16 {
+        match self {
+            Self::LinkTableMetadata { .. } => 0x810,
+            Self::LinkTable { .. } => 0x811,
+            Self::DeviceConfig { .. } => 0x830,
+        }
+    }
+
+    /// Encodes this answer into a remote management message.
+    pub fn to_message(&self) -> RemoteManMessage {
+        let mut data = MaxArray::new();
+        match self {
+            Self::LinkTableMetadata {
+                inbound_supported,
+                outbound_supported,
+                inbound_count,
+                inbound_max,
+                outbound_count,
+                outbound_max,
+            } => {
+                let mut flags = 0;
+                if *inbound_supported {
+                    flags |= 0b0010_0000;
+                }
+                if *outbound_supported {
+                    flags |= 0b0001_0000;
+                }
+                data.push(flags).unwrap();
+                data.push(*inbound_count).unwrap();
+                data.push(*inbound_max).unwrap();
+                data.push(*outbound_count).unwrap();
+                data.push(*outbound_max).unwrap();
+            },
+            Self::LinkTable { direction, entries } => {
+                data.push(direction.to_byte()).unwrap();
+                for entry in entries.iter() {
+                    entry.push_to(&mut data);
+                }
+            },
+            Self::DeviceConfig { entries } => {
+                for b in entries.as_slice() {
+                    data.push(*b).unwrap();
+                }
+            },
+        }
+
+        RemoteManMessage {
+            function: self.function_number(),
+            manufacturer: STANDARD_MANUFACTURER_ID,
+            data,
+        }
+    }
+
+    /// Attempts to decode a remote commissioning answer from the given message.
+    pub fn from_message(message: &RemoteManMessage) -> Option<Self> {
+        if message.manufacturer != STANDARD_MANUFACTURER_ID {
+            return None;
+        }
+
+        let data = message.data.as_slice();
+        match message.function {
+            0x810 => {
+                if data.len() != 5 {
+                    return None;
+                }
+                Some(Self::LinkTableMetadata {
+                    inbound_supported: (data[0] & 0b0010_0000) != 0,
+                    outbound_supported: (data[0] & 0b0001_0000) != 0,
+                    inbound_count: data[1],
+                    inbound_max: data[2],
+                    outbound_count: data[3],
+                    outbound_max: data[4],
+                })
+            },
+            0x811 => {
+                let (direction, entries) = link_table_from_slice(data)?;
+                Some(Self::LinkTable {
+                    direction,
+                    entries,
+                })
+            },
+            0x830 => DeviceConfigEntries::from_slice(data)
+                .map(|entries| Self::DeviceConfig { entries }),
+            _ => None,
+        }
+    }
+}
+
+
+/// Returns the requests required to read the link table entries from `first_index` to
+/// `last_index` (inclusive), at most `page_size` entries at a time.
+///
+/// A page size of 0 is treated as 1.
+pub fn link_table_queries(direction: LinkTableDirection, first_index: u8, last_index: u8, page_size: u8) -> impl Iterator<Item = RecomRequest> {
+    page_ranges(first_index.into(), last_index.into(), page_size.into())
+        .map(move |(start, end)| RecomRequest::GetLinkTable {
+            direction,
+            start_index: start.try_into().unwrap(),
+            end_index: end.try_into().unwrap(),
+        })
+}
+
+/// Returns the requests required to read the configuration parameters from `first_index` to
+/// `last_index` (inclusive), at most `page_size` parameters at a time.
+///
+/// A page size of 0 is treated as 1.
+pub fn device_config_queries(first_index: u16, last_index: u16, page_size: u16) -> impl Iterator<Item = RecomRequest> {
+    page_ranges(first_index, last_index, page_size)
+        .map(|(start, end)| RecomRequest::GetDeviceConfig {
+            start_index: start,
+            end_index: end,
+        })
+}
+
+/// Splits the inclusive range from `first` to `last` into inclusive ranges of at most `page_size`
+/// elements.
+fn page_ranges(first: u16, last: u16, page_size: u16) -> impl Iterator<Item = (u16, u16)> {
+    let page_size = page_size.max(1);
+    let mut opt_next = (first <= last).then_some(first);
+    core::iter::from_fn(move || {
+        let start = opt_next?;
+        let end = start.saturating_add(page_size - 1).min(last);
+        opt_next = if end < last { Some(end + 1) } else { None };
+        Some((start, end))
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DeviceConfigEntries, LinkTableDirection, LinkTableEntry, RecomAnswer, RecomRequest,
+        device_config_queries, link_table_queries,
+    };
+    use crate::esp3::remote_man::RemoteManMessage;
+    use crate::max_array::MaxArray;
+
+    #[test]
+    fn test_link_table() {
+        let mut entries = MaxArray::new();
+        entries.push(LinkTableEntry {
+            index: 0,
+            device_id: 0x0194E3B9,
+            eep_rorg: 0xA5,
+            eep_func: 0x20,
+            eep_type: 0x01,
+            channel: 0,
+        }).unwrap();
+        entries.push(LinkTableEntry {
+            index: 1,
+            device_id: 0xFFFFFFFF,
+            eep_rorg: 0,
+            eep_func: 0,
+            eep_type: 0,
+            channel: 0,
+        }).unwrap();
+
+        let set = RecomRequest::SetLinkTable {
+            direction: LinkTableDirection::Inbound,
+            entries: entries.clone(),
+        };
+        let message = set.to_message();
+        assert_eq!(message.function, 0x212);
+        assert_eq!(
+            message.data.as_slice(),
+            &[
+                0x00,
+                0x00, 0x01, 0x94, 0xE3, 0xB9, 0xA5, 0x20, 0x01, 0x00,
+                0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00,
+            ],
+        );
+        assert_eq!(RecomRequest::from_message(&message), Some(set));
+        assert!(!RecomRequest::from_message(&message).unwrap().expects_answer());
+
+        let answer = RecomAnswer::LinkTable {
+            direction: LinkTableDirection::Outbound,
+            entries,
+        };
+        let answer_message = answer.to_message();
+        assert_eq!(answer_message.function, 0x811);
+        assert_eq!(answer_message.data.as_slice()[0], 0x80);
+        assert_eq!(RecomAnswer::from_message(&answer_message), Some(answer));
+
+        // truncated entry
+        let truncated = RemoteManMessage::new(0x811, 0x7FF, &answer_message.data.as_slice()[0..18]).unwrap();
+        assert_eq!(RecomAnswer::from_message(&truncated), None);
+    }
+
+    #[test]
+    fn test_link_table_metadata() {
+        let metadata = RecomAnswer::LinkTableMetadata {
+            inbound_supported: true,
+            outbound_supported: false,
+            inbound_count: 3,
+            inbound_max: 24,
+            outbound_count: 0,
+            outbound_max: 0,
+        };
+        let message = metadata.to_message();
+        assert_eq!(message.function, 0x810);
+        assert_eq!(message.data.as_slice(), &[0x20, 3, 24, 0, 0]);
+        assert_eq!(RecomAnswer::from_message(&message), Some(metadata));
+    }
+
+    #[test]
+    fn test_device_config() {
+        let mut entries = DeviceConfigEntries::new();
+        assert!(entries.is_empty());
+        assert!(entries.push(0x0001, &[0x42]));
+        assert!(entries.push(0x0102, &[0x01, 0x02, 0x03]));
+        assert!(!entries.push(0x0103, &[0x00; 256]));
+
+        let collected: Vec<(u16, &[u8])> = entries.iter().collect();
+        assert_eq!(collected, vec![(0x0001, &[0x42][..]), (0x0102, &[0x01, 0x02, 0x03][..])]);
+
+        let answer = RecomAnswer::DeviceConfig { entries };
+        let message = answer.to_message();
+        assert_eq!(message.function, 0x830);
+        assert_eq!(message.data.as_slice(), &[0x00, 0x01, 0x01, 0x42, 0x01, 0x02, 0x03, 0x01, 0x02, 0x03]);
+        assert_eq!(RecomAnswer::from_message(&message), Some(answer));
+
+        // inconsistent length
+        assert!(DeviceConfigEntries::from_slice(&[0x00, 0x01, 0x02, 0x42]).is_none());
+    }
+
+    #[test]
+    fn test_paging() {
+        let link_queries: Vec<RecomRequest> = link_table_queries(LinkTableDirection::Inbound, 0, 23, 10).collect();
+        assert_eq!(
+            link_queries,
+            vec![
+                RecomRequest::GetLinkTable { direction: LinkTableDirection::Inbound, start_index: 0, end_index: 9 },
+                RecomRequest::GetLinkTable { direction: LinkTableDirection::Inbound, start_index: 10, end_index: 19 },
+                RecomRequest::GetLinkTable { direction: LinkTableDirection::Inbound, start_index: 20, end_index: 23 },
+            ],
+        );
+
+        let config_queries: Vec<RecomRequest> = device_config_queries(0xFFFE, 0xFFFF, 0).collect();
+        assert_eq!(
+            config_queries,
+            vec![
+                RecomRequest::GetDeviceConfig { start_index: 0xFFFE, end_index: 0xFFFE },
+                RecomRequest::GetDeviceConfig { start_index: 0xFFFF, end_index: 0xFFFF },
+            ],
+        );
+
+        assert_eq!(link_table_queries(LinkTableDirection::Outbound, 5, 4, 10).count(), 0);
+    }
+}
@@ -0,0 +1,714 @@
+//! Remote Management (ReMan) of EnOcean devices.
+//!
+//! Remote management messages travel over the air in one or more SYS_EX telegrams
+//! ([RemoteManagementTelegram]) and over the serial interface in a
+//! [`Esp3Packet::RemoteManCommand`]. This module reassembles the former into [RemoteManMessage]s
+//! and decodes the standard functions described in _Remote Management_ into [RemoteManRequest]s
+//! and [RemoteManAnswer]s.
+
+
+use crate::esp3::Esp3Packet;
+use crate::esp3::erp::{RemoteManagementPayload, RemoteManagementTelegram};
+use crate::max_array::MaxArray;
+use crate::max_array_ext::MaxArrayPushIntExt;
+
+
+/// The maximum length of the data of a remote management message.
+///
+/// The data length field is 9 bits wide, but a message consists of at most 64 telegrams (the index
+/// field is 6 bits wide), the first of which carries 4 bytes of data and the others 8 bytes each.
+pub const MAXIMUM_REMAN_DATA_LENGTH: usize = 4 + 63 * 8;
+
+/// The manufacturer ID used for the standard remote management functions.
+pub const STANDARD_MANUFACTURER_ID: u16 = 0x7FF;
+
+/// The maximum number of functions that can be listed in a [RemoteManAnswer::QueryFunction].
+pub const MAXIMUM_QUERY_FUNCTION_ENTRIES: usize = MAXIMUM_REMAN_DATA_LENGTH / 4;
+
+
+/// A complete remote management message.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RemoteManMessage {
+    /// The function number (12 bits).
+    pub function: u16,
+
+    /// The manufacturer ID (11 bits).
+    pub manufacturer: u16,
+
+    pub data: MaxArray<u8, MAXIMUM_REMAN_DATA_LENGTH>,
+}
+impl RemoteManMessage {
+    /// Creates a new remote management message.
+    ///
+    /// Returns `None` if the function number or manufacturer ID do not fit into their respective
+    /// fields or if the data is longer than [`MAXIMUM_REMAN_DATA_LENGTH`].
+    pub fn new(function: u16, manufacturer: u16, data: &[u8]) -> Option<Self> {
+        if function > 0xFFF || manufacturer > 0x7FF || data.len() > MAXIMUM_REMAN_DATA_LENGTH {
+            return None;
+        }
+
+        Some(Self {
+            function,
+            manufacturer,
+            data: MaxArray::from_iter_or_panic(data.iter().copied().peekable()),
+        })
+    }
+
+    /// Extracts the remote management message from an ESP3 packet.
+    ///
+    /// Returns `None` if the packet is not a remote management command or if its message is too
+    /// long.
+    pub fn from_packet(packet: &Esp3Packet) -> Option<Self> {
+        match packet {
+            Esp3Packet::RemoteManCommand { function, manufacturer, message, .. } =>
+                Self::new(*function, *manufacturer, message.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Wraps this message into an ESP3 packet to be sent by the controller.
+    ///
+    /// If no destination ID is given, the message is broadcast.
+    pub fn to_packet(&self, opt_destination_id: Option<u32>) -> Esp3Packet {
+        Esp3Packet::RemoteManCommand {
+            function: self.function,
+            manufacturer: self.manufacturer,
+            message: MaxArray::from_iter_or_panic(self.data.iter().copied().peekable()),
+            opt_destination_id,
+            opt_source_id: None,
+            opt_dbm: None,
+            opt_send_with_delay: None,
+        }
+    }
+
+    /// The number of SYS_EX telegrams required to transmit this message.
+    pub fn telegram_count(&self) -> usize {
+        telegram_count(self.data.len())
+    }
+
+    /// Returns the SYS_EX telegram with the given index of this message, or `None` if the index is
+    /// out of range.
+    pub fn telegram(&self, index: u8, sequence: u8, sender: u32, status: u8) -> Option<RemoteManagementTelegram> {
+        if usize::from(index) >= self.telegram_count() {
+            return None;
+        }
+
+        let payload = if index == 0 {
+            let mut data = [0u8; 4];
+            let length = self.data.len().min(4);
+            data[0..length].copy_from_slice(&self.data.as_slice()[0..length]);
+            RemoteManagementPayload::First {
+                data_length: self.data.len().try_into().unwrap(),
+                manufacturer: self.manufacturer,
+                function: self.function,
+                data,
+            }
+        } else {
+            let mut data = [0u8; 8];
+            let start = 4 + (usize::from(index) - 1) * 8;
+            let end = (start + 8).min(self.data.len());
+            data[0..end-start].copy_from_slice(&self.data.as_slice()[start..end]);
+            RemoteManagementPayload::Continuation {
+                index,
+                data,
+            }
+        };
+
+        Some(RemoteManagementTelegram {
+            sequence: sequence & 0b11,
+            payload,
+            sender,
+            status,
+        })
+    }
+
+    /// Returns an iterator over the SYS_EX telegrams required to transmit this message.
+    pub fn telegrams(&self, sequence: u8, sender: u32, status: u8) -> impl Iterator<Item = RemoteManagementTelegram> + '_ {
+        (0..self.telegram_count())
+            .map(move |i| self.telegram(i.try_into().unwrap(), sequence, sender, status).unwrap())
+    }
+}
+
+
+/// Calculates the number of SYS_EX telegrams required to transmit a message with the given data
+/// length.
+fn telegram_count(data_length: usize) -> usize {
+    if data_length <= 4 {
+        1
+    } else {
+        1 + (data_length - 4).div_ceil(8)
+    }
+}
+
+
+/// A message that is in the process of being reassembled.
+#[derive(Clone, Copy, Debug)]
+struct ReassemblySlot {
+    sender: u32,
+    sequence: u8,
+    opt_header: Option<(u16, u16, u16)>, // data length, manufacturer, function
+    received_indexes: u64,
+    data: [u8; MAXIMUM_REMAN_DATA_LENGTH],
+    last_used: u32,
+}
+impl ReassemblySlot {
+    fn new(sender: u32, sequence: u8) -> Self {
+        Self {
+            sender,
+            sequence,
+            opt_header: None,
+            received_indexes: 0,
+            data: [0u8; MAXIMUM_REMAN_DATA_LENGTH],
+            last_used: 0,
+        }
+    }
+
+    fn store(&mut self, payload: &RemoteManagementPayload) {
+        match payload {
+            RemoteManagementPayload::First { data_length, manufacturer, function, data } => {
+                self.opt_header = Some((*data_length, *manufacturer, *function));
+                self.data[0..4].copy_from_slice(data);
+                self.received_indexes |= 1;
+            },
+            RemoteManagementPayload::Continuation { index, data } => {
+                let index = index & 0b0011_1111;
+                if index == 0 {
+                    return;
+                }
+                let start = 4 + (usize::from(index) - 1) * 8;
+                self.data[start..start+8].copy_from_slice(data);
+                self.received_indexes |= 1 << index;
+            },
+        }
+    }
+}
+
+
+/// Reassembles remote management messages from SYS_EX telegrams.
+///
+/// Up to `SLOTS` messages from different senders (or with different sequence numbers) may be
+/// reassembled at the same time. If a telegram belonging to a new message arrives while all slots
+/// are occupied, the message that has been waiting the longest is discarded.
+#[derive(Clone, Debug)]
+pub struct SysExReassembler<const SLOTS: usize> {
+    slots: [Option<ReassemblySlot>; SLOTS],
+    clock: u32,
+}
+impl<const SLOTS: usize> SysExReassembler<SLOTS> {
+    const EMPTY_SLOT: Option<ReassemblySlot> = None;
+
+    /// Creates a new reassembler with no messages in progress.
+    pub const fn new() -> Self {
+        Self {
+            slots: [Self::EMPTY_SLOT; SLOTS],
+            clock: 0,
+        }
+    }
+
+    /// The number of messages currently being reassembled.
+    pub fn pending(&self) -> usize {
+        self.slots.iter().filter(|s| s.is_some()).count()
+    }
+
+    /// Discards all messages currently being reassembled.
+    pub fn clear(&mut self) {
+        for slot in &mut self.slots {
+            *slot = None;
+        }
+    }
+
+    /// Processes a SYS_EX telegram. Returns the complete message and its sender if this telegram
+    /// completed it.
+    ///
+    /// Repeated telegrams are tolerated; telegrams may arrive in any order.
+    pub fn push(&mut self, telegram: &RemoteManagementTelegram) -> Option<(u32, RemoteManMessage)> {
+        if SLOTS == 0 {
+            return None;
+        }
+
+        self.clock = self.clock.wrapping_add(1);
+        let sequence = telegram.sequence & 0b11;
+
+        let slot_index = match self.find_slot(telegram.sender, sequence) {
+            Some(i) => i,
+            None => {
+                let i = self.free_slot();
+                self.slots[i] = Some(ReassemblySlot::new(telegram.sender, sequence));
+                i
+            },
+        };
+        let slot = self.slots[slot_index].as_mut().unwrap();
+        slot.last_used = self.clock;
+        slot.store(&telegram.payload);
+
+        let (data_length, manufacturer, function) = slot.opt_header?;
+        let data_length = usize::from(data_length);
+        if data_length > MAXIMUM_REMAN_DATA_LENGTH {
+            // cannot be transmitted in a valid sequence of telegrams
+            self.slots[slot_index] = None;
+            return None;
+        }
+
+        let count = telegram_count(data_length);
+        let expected_indexes = if count >= 64 { u64::MAX } else { (1 << count) - 1 };
+        if slot.received_indexes & expected_indexes != expected_indexes {
+            return None;
+        }
+
+        let message = RemoteManMessage {
+            function,
+            manufacturer,
+            data: MaxArray::from_iter_or_panic(slot.data[0..data_length].iter().copied().peekable()),
+        };
+        let sender = slot.sender;
+        self.slots[slot_index] = None;
+        Some((sender, message))
+    }
+
+    fn find_slot(&self, sender: u32, sequence: u8) -> Option<usize> {
+        self.slots.iter()
+            .position(|s| s.as_ref().map(|s| s.sender == sender && s.sequence == sequence).unwrap_or(false))
+    }
+
+    fn free_slot(&self) -> usize {
+        if let Some(i) = self.slots.iter().position(|s| s.is_none()) {
+            return i;
+        }
+
+        // evict the slot that has not been used for the longest time
+        let clock = self.clock;
+        self.slots.iter()
+            .enumerate()
+            .max_by_key(|(_, s)| clock.wrapping_sub(s.as_ref().unwrap().last_used))
+            .map(|(i, _)| i)
+            .unwrap()
+    }
+}
+impl<const SLOTS: usize> Default for SysExReassembler<SLOTS> {
+    fn default() -> Self { Self::new() }
+}
+
+
+/// The result of the most recent remote management function, as reported by
+/// [RemoteManAnswer::QueryStatus].
+#[derive(Clone, Copy, Debug)]
+#[from_to_repr::from_to_other(base_type = u8, derive_compare = "as_int")]
+pub enum RemoteManReturnCode {
+    Ok = 0x00,
+    WrongTargetId = 0x01,
+    WrongUnlockCode = 0x02,
+    WrongEep = 0x03,
+    WrongManufacturerId = 0x04,
+    WrongDataSize = 0x05,
+    NoCodeSet = 0x06,
+    NotSent = 0x07,
+    RpcFailed = 0x08,
+    MessageTimeOut = 0x09,
+    TooLongMessage = 0x0A,
+    MessagePartAlreadyReceived = 0x0B,
+    MessagePartNotReceived = 0x0C,
+    AddressOutOfRange = 0x0D,
+    CodeDataSizeExceeded = 0x0E,
+    WrongData = 0x0F,
+    Other(u8),
+}
+
+/// An EEP as transmitted in remote management messages (21 bits).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RemoteManEep {
+    pub rorg: u8,
+
+    /// The function value (6 bits).
+    pub func: u8,
+
+    /// The type value (7 bits).
+    pub eep_type: u8,
+}
+impl RemoteManEep {
+    /// Decodes the EEP from the topmost 21 bits of the given three bytes; the remaining bits are
+    /// ignored.
+    fn from_bytes(bytes: [u8; 3]) -> Self {
+        let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        Self {
+            rorg: ((value >> 16) & 0xFF).try_into().unwrap(),
+            func: ((value >> 10) & 0b11_1111).try_into().unwrap(),
+            eep_type: ((value >> 3) & 0b111_1111).try_into().unwrap(),
+        }
+    }
+
+    /// Encodes the EEP into the topmost 21 bits of three bytes; the remaining bits are zero.
+    fn to_bytes(self) -> [u8; 3] {
+        let value =
+            (u32::from(self.rorg) << 16)
+            | (u32::from(self.func & 0b11_1111) << 10)
+            | (u32::from(self.eep_type & 0b111_1111) << 3);
+        let bytes = value.to_be_bytes();
+        [bytes[1], bytes[2], bytes[3]]
+    }
+}
+
+/// A function supported by a device, as reported by [RemoteManAnswer::QueryFunction].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RemoteManFunctionEntry {
+    /// The function number (12 bits).
+    pub function: u16,
+
+    /// The manufacturer ID (11 bits).
+    pub manufacturer: u16,
+}
+
+
+/// A standard remote management request, sent to a device.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RemoteManRequest {
+    Unlock { security_code: u32 },
+    Lock { security_code: u32 },
+    SetCode { security_code: u32 },
+    QueryId { eep: RemoteManEep },
+    Action,
+    Ping,
+    QueryFunction,
+    QueryStatus,
+}
+impl RemoteManRequest {
+    /// Returns the function number of this request.
+    pub fn function_number(&self) -> u16 {
+        match self {
+            Self::Unlock { .. } => 0x001,
+            Self::Lock { .. } => 0x002,
+            Self::SetCode { .. } => 0x003,
+            Self::QueryId { .. } => 0x004,
+            Self::Action => 0x005,
+            Self::Ping => 0x006,
+            Self::QueryFunction => 0x007,
+            Self::QueryStatus => 0x008,
+        }
+    }
+
+    /// Encodes this request into a remote management message.
+    pub fn to_message(&self) -> RemoteManMessage {
+        let mut data = MaxArray::new();
+        match self {
+            Self::Unlock { security_code }
+                | Self::Lock { security_code }
+                | Self::SetCode { security_code } => {
+                data.push_u32_be(*security_code).unwrap();
+            },
+            Self::QueryId { eep } => {
+                for b in eep.to_bytes() {
+                    data.push(b).unwrap();
+                }
+            },
+            Self::Action|Self::Ping|Self::QueryFunction|Self::QueryStatus => {},
+        }
+
+        RemoteManMessage {
+            function: self.function_number(),
+            manufacturer: STANDARD_MANUFACTURER_ID,
+            data,
+        }
+    }
+
+    /// Attempts to decode a standard remote management request from the given message.
+    pub fn from_message(message: &RemoteManMessage) -> Option<Self> {
+        if message.manufacturer != STANDARD_MANUFACTURER_ID {
+            return None;
+        }
+
+        let data = message.data.as_slice();
+        match message.function {
+            0x001 => {
+                if data.len() != 4 {
+                    return None;
+                }
+                Some(Self::Unlock { security_code: u32::from_be_bytes(data.try_into().unwrap()) })
+            },
+            0x002 => {
+                if data.len() != 4 {
+                    return None;
+                }
+                Some(Self::Lock { security_code: u32::from_be_bytes(data.try_into().unwrap()) })
+            },
+            0x003 => {
+                if data.len() != 4 {
+                    return None;
+                }
+                Some(Self::SetCode { security_code: u32::from_be_bytes(data.try_into().unwrap()) })
+            },
+            0x004 => {
+                if data.len() != 3 {
+                    return None;
+                }
+                Some(Self::QueryId { eep: RemoteManEep::from_bytes(data.try_into().unwrap()) })
+            },
+            0x005 => data.is_empty().then_some(Self::Action),
+            0x006 => data.is_empty().then_some(Self::Ping),
+            0x007 => data.is_empty().then_some(Self::QueryFunction),
+            0x008 => data.is_empty().then_some(Self::QueryStatus),
+            _ => None,
+        }
+    }
+}
+
+
+/// A standard remote management answer, sent by a device in response to a [RemoteManRequest].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RemoteManAnswer {
+    QueryId {
+        eep: RemoteManEep,
+    },
+    Ping {
+        eep: RemoteManEep,
+        rssi: u8,
+    },
+    QueryFunction {
+        functions: MaxArray<RemoteManFunctionEntry, MAXIMUM_QUERY_FUNCTION_ENTRIES>,
+    },
+    QueryStatus {
+        code_set: bool,
+        last_sequence: u8,
+        last_function: u16,
+        last_return_code: RemoteManReturnCode,
+    },
+}
+impl RemoteManAnswer {
+    /// Returns the function number of this answer.
+    pub fn function_number(&self) -> u16 {
+        match self {
+            Self::QueryId { .. } => 0x604,
+            Self::Ping { .. } => 0x606,
+            Self::QueryFunction { .. } => 0x607,
+            Self::QueryStatus { .. } => 0x608,
+        }
+    }
+
+    /// Encodes this answer into a remote management message.
+    pub fn to_message(&self) -> RemoteManMessage {
+        let mut data = MaxArray::new();
+        match self {
+            Self::QueryId { eep } => {
+                for b in eep.to_bytes() {
+                    data.push(b).unwrap();
+                }
+            },
+            Self::Ping { eep, rssi } => {
+                for b in eep.to_bytes() {
+                    data.push(b).unwrap();
+                }
+                data.push(*rssi).unwrap();
+            },
+            Self::QueryFunction { functions } => {
+                for entry in functions.iter() {
+                    data.push_u16_be(entry.function & 0xFFF).unwrap();
+                    data.push_u16_be(entry.manufacturer & 0x7FF).unwrap();
+                }
+            },
+            Self::QueryStatus { code_set, last_sequence, last_function, last_return_code } => {
+                data.push(if *code_set { 0b1000_0000 } else { 0 }).unwrap();
+                data.push_u16_be(
+                    (u16::from(*last_sequence & 0b11) << 14)
+                    | (*last_function & 0xFFF)
+                ).unwrap();
+                data.push_any(*last_return_code).unwrap();
+            },
+        }
+
+        RemoteManMessage {
+            function: self.function_number(),
+            manufacturer: STANDARD_MANUFACTURER_ID,
+            data,
+        }
+    }
+
+    /// Attempts to decode a standard remote management answer from the given message.
+    pub fn from_message(message: &RemoteManMessage) -> Option<Self> {
+        if message.manufacturer != STANDARD_MANUFACTURER_ID {
+            return None;
+        }
+
+        let data = message.data.as_slice();
+        match message.function {
+            0x604 => {
+                if data.len() != 3 {
+                    return None;
+                }
+                Some(Self::QueryId { eep: RemoteManEep::from_bytes(data.try_into().unwrap()) })
+            },
+            0x606 => {
+                if data.len() != 4 {
+                    return None;
+                }
+                Some(Self::Ping {
+                    eep: RemoteManEep::from_bytes(data[0..3].try_into().unwrap()),
+                    rssi: data[3],
+                })
+            },
+            0x607 => {
+                if !data.len().is_multiple_of(4) {
+                    return None;
+                }
+                let functions = MaxArray::from_iter_or_panic(
+                    data.chunks(4)
+                        .map(|chunk| RemoteManFunctionEntry {
+                            function: u16::from_be_bytes(chunk[0..2].try_into().unwrap()) & 0xFFF,
+                            manufacturer: u16::from_be_bytes(chunk[2..4].try_into().unwrap()) & 0x7FF,
+                        })
+                        .peekable()
+                );
+                Some(Self::QueryFunction { functions })
+            },
+            0x608 => {
+                if data.len() != 4 {
+                    return None;
+                }
+                let sequence_and_function = u16::from_be_bytes(data[1..3].try_into().unwrap());
+                Some(Self::QueryStatus {
+                    code_set: (data[0] & 0b1000_0000) != 0,
+                    last_sequence: (sequence_and_function >> 14).try_into().unwrap(),
+                    last_function: sequence_and_function & 0xFFF,
+                    last_return_code: data[3].into(),
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RemoteManAnswer, RemoteManEep, RemoteManFunctionEntry, RemoteManMessage, RemoteManRequest,
+        RemoteManReturnCode, STANDARD_MANUFACTURER_ID, SysExReassembler,
+    };
+    use crate::esp3::erp::{ErpData, RemoteManagementPayload, RemoteManagementTelegram};
+    use crate::max_array::MaxArray;
+
+    #[test]
+    fn test_single_telegram() {
+        let message = RemoteManRequest::Unlock { security_code: 0xDEADBEEF }.to_message();
+        assert_eq!(message.telegram_count(), 1);
+
+        let telegram = message.telegram(0, 1, 0x01020304, 0x8F).unwrap();
+        let erp = ErpData::RemoteManagement(telegram);
+        assert_eq!(
+            erp.to_bytes().as_slice(),
+            &[0xC5, 0x40, 0x02, 0x7F, 0xF0, 0x01, 0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02, 0x03, 0x04, 0x8F],
+        );
+
+        let mut reassembler: SysExReassembler<2> = SysExReassembler::new();
+        let (sender, reassembled) = reassembler.push(&telegram).unwrap();
+        assert_eq!(sender, 0x01020304);
+        assert_eq!(reassembled, message);
+        assert_eq!(
+            RemoteManRequest::from_message(&reassembled),
+            Some(RemoteManRequest::Unlock { security_code: 0xDEADBEEF }),
+        );
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn test_multiple_telegrams_out_of_order() {
+        let data: Vec<u8> = (0..20).collect();
+        let message = RemoteManMessage::new(0x123, 0x046, &data).unwrap();
+        assert_eq!(message.telegram_count(), 3);
+
+        let telegrams: Vec<RemoteManagementTelegram> = message.telegrams(2, 0xAABBCCDD, 0x00).collect();
+        assert_eq!(telegrams.len(), 3);
+        assert_eq!(
+            telegrams[2].payload,
+            RemoteManagementPayload::Continuation {
+                index: 2,
+                data: [12, 13, 14, 15, 16, 17, 18, 19],
+            },
+        );
+
+        let mut reassembler: SysExReassembler<2> = SysExReassembler::new();
+        assert_eq!(reassembler.push(&telegrams[2]), None);
+        assert_eq!(reassembler.push(&telegrams[0]), None);
+        // repeated telegram
+        assert_eq!(reassembler.push(&telegrams[2]), None);
+        assert_eq!(reassembler.pending(), 1);
+        let (sender, reassembled) = reassembler.push(&telegrams[1]).unwrap();
+        assert_eq!(sender, 0xAABBCCDD);
+        assert_eq!(reassembled, message);
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn test_interleaved_senders_and_eviction() {
+        let data: Vec<u8> = (0..12).collect();
+        let message = RemoteManMessage::new(0x123, 0x046, &data).unwrap();
+        let from_a: Vec<RemoteManagementTelegram> = message.telegrams(1, 0x0000000A, 0x00).collect();
+        let from_b: Vec<RemoteManagementTelegram> = message.telegrams(1, 0x0000000B, 0x00).collect();
+        let from_c: Vec<RemoteManagementTelegram> = message.telegrams(1, 0x0000000C, 0x00).collect();
+
+        let mut reassembler: SysExReassembler<2> = SysExReassembler::new();
+        assert_eq!(reassembler.push(&from_a[0]), None);
+        assert_eq!(reassembler.push(&from_b[0]), None);
+
+        // evicts the message from A
+        assert_eq!(reassembler.push(&from_c[0]), None);
+        assert_eq!(reassembler.pending(), 2);
+
+        assert_eq!(reassembler.push(&from_b[1]).map(|(s, _)| s), Some(0x0000000B));
+        assert_eq!(reassembler.push(&from_c[1]).map(|(s, _)| s), Some(0x0000000C));
+
+        // A has to start over
+        assert_eq!(reassembler.push(&from_a[1]), None);
+        assert_eq!(reassembler.pending(), 1);
+    }
+
+    #[test]
+    fn test_answers() {
+        let eep = RemoteManEep { rorg: 0xA5, func: 0x20, eep_type: 0x01 };
+
+        let ping = RemoteManAnswer::Ping { eep, rssi: 0x3C };
+        let ping_message = ping.to_message();
+        assert_eq!(ping_message.function, 0x606);
+        assert_eq!(ping_message.manufacturer, STANDARD_MANUFACTURER_ID);
+        assert_eq!(ping_message.data.as_slice(), &[0xA5, 0x80, 0x08, 0x3C]);
+        assert_eq!(RemoteManAnswer::from_message(&ping_message), Some(ping));
+
+        let mut functions = MaxArray::new();
+        functions.push(RemoteManFunctionEntry { function: 0x001, manufacturer: 0x7FF }).unwrap();
+        functions.push(RemoteManFunctionEntry { function: 0x230, manufacturer: 0x046 }).unwrap();
+        let query_function = RemoteManAnswer::QueryFunction { functions };
+        let query_function_message = query_function.to_message();
+        assert_eq!(
+            query_function_message.data.as_slice(),
+            &[0x00, 0x01, 0x07, 0xFF, 0x02, 0x30, 0x00, 0x46],
+        );
+        assert_eq!(RemoteManAnswer::from_message(&query_function_message), Some(query_function));
+
+        let query_status = RemoteManAnswer::QueryStatus {
+            code_set: true,
+            last_sequence: 2,
+            last_function: 0x004,
+            last_return_code: RemoteManReturnCode::WrongEep,
+        };
+        let query_status_message = query_status.to_message();
+        assert_eq!(query_status_message.data.as_slice(), &[0x80, 0x80, 0x04, 0x03]);
+        assert_eq!(RemoteManAnswer::from_message(&query_status_message), Some(query_status));
+
+        // not a standard function
+        let vendor = RemoteManMessage::new(0x606, 0x046, &[0xA5, 0x80, 0x08, 0x3C]).unwrap();
+        assert_eq!(RemoteManAnswer::from_message(&vendor), None);
+    }
+
+    #[test]
+    fn test_requests() {
+        let query_id = RemoteManRequest::QueryId {
+            eep: RemoteManEep { rorg: 0xD2, func: 0x01, eep_type: 0x12 },
+        };
+        let message = query_id.to_message();
+        assert_eq!(message.function, 0x004);
+        assert_eq!(message.data.as_slice(), &[0xD2, 0x04, 0x90]);
+        assert_eq!(RemoteManRequest::from_message(&message), Some(query_id));
+
+        for request in [RemoteManRequest::Action, RemoteManRequest::Ping, RemoteManRequest::QueryFunction, RemoteManRequest::QueryStatus] {
+            assert_eq!(RemoteManRequest::from_message(&request.to_message()), Some(request));
+        }
+    }
+}
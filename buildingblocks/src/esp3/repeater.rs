@@ -0,0 +1,269 @@
+//! A software implementation of an ERP1 repeater.
+//!
+//! [`Repeater`] decides which received telegrams are to be sent again, and with which repeater
+//! count. Unlike the repeater built into EnOcean modules (enabled using
+//! [`CommandData::CoWrRepeater`](crate::esp3::CommandData::CoWrRepeater)), it can be restricted to
+//! specific senders and its behavior is fully under the control of the application. It performs no
+//! I/O itself: the caller feeds it the received telegrams along with the current time as a
+//! wrapping millisecond counter and transmits the telegrams it returns.
+
+
+use crate::esp3::RepeaterLevel;
+use crate::esp3::erp::{ErpContents, ErpData, RepeaterCount};
+use crate::max_array::MaxArray;
+
+
+/// The time, in milliseconds, within which a telegram with the same contents is considered a
+/// duplicate.
+pub const DEFAULT_DUPLICATE_WINDOW_MS: u32 = 1000;
+
+
+/// Which senders' telegrams are repeated.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SenderFilter {
+    /// Telegrams from all senders are repeated.
+    All,
+
+    /// Only telegrams from the senders in the list are repeated.
+    OnlyListed,
+
+    /// Telegrams from all senders except those in the list are repeated.
+    AllExceptListed,
+}
+
+
+/// A recently seen telegram.
+#[derive(Clone, Debug)]
+struct SeenTelegram {
+    contents: ErpContents,
+    seen_ms: u32,
+}
+
+
+/// A repeater for ERP1 telegrams.
+///
+/// `SENDERS` is the maximum number of sender IDs in the filter list; `HISTORY` is the number of
+/// recently seen telegrams remembered for duplicate suppression.
+#[derive(Clone, Debug)]
+pub struct Repeater<const SENDERS: usize, const HISTORY: usize> {
+    level: RepeaterLevel,
+    filter: SenderFilter,
+    senders: MaxArray<u32, SENDERS>,
+    duplicate_window_ms: u32,
+    history: [Option<SeenTelegram>; HISTORY],
+    next_history_index: usize,
+}
+impl<const SENDERS: usize, const HISTORY: usize> Repeater<SENDERS, HISTORY> {
+    const EMPTY_ENTRY: Option<SeenTelegram> = None;
+
+    /// Creates a new repeater with the given level which repeats telegrams from all senders.
+    pub const fn new(level: RepeaterLevel, duplicate_window_ms: u32) -> Self {
+        Self {
+            level,
+            filter: SenderFilter::All,
+            senders: MaxArray::new(),
+            duplicate_window_ms,
+            history: [Self::EMPTY_ENTRY; HISTORY],
+            next_history_index: 0,
+        }
+    }
+
+    /// The level of this repeater.
+    pub fn level(&self) -> RepeaterLevel {
+        self.level
+    }
+
+    /// Changes the level of this repeater. [`RepeaterLevel::Off`] disables it.
+    pub fn set_level(&mut self, level: RepeaterLevel) {
+        self.level = level;
+    }
+
+    /// Which senders' telegrams are repeated.
+    pub fn filter(&self) -> SenderFilter {
+        self.filter
+    }
+
+    /// Changes which senders' telegrams are repeated.
+    pub fn set_filter(&mut self, filter: SenderFilter) {
+        self.filter = filter;
+    }
+
+    /// The sender IDs in the filter list.
+    pub fn senders(&self) -> &[u32] {
+        self.senders.as_slice()
+    }
+
+    /// Adds a sender ID to the filter list. Returns `false` if the list is full.
+    pub fn add_sender(&mut self, sender: u32) -> bool {
+        if self.senders.iter().any(|s| *s == sender) {
+            return true;
+        }
+        self.senders.push(sender).is_ok()
+    }
+
+    /// Removes a sender ID from the filter list. Returns whether it was in the list.
+    pub fn remove_sender(&mut self, sender: u32) -> bool {
+        let Some(index) = self.senders.iter().position(|s| *s == sender) else {
+            return false;
+        };
+        let slice = self.senders.as_mut_slice();
+        let last_index = slice.len() - 1;
+        slice.swap(index, last_index);
+        self.senders.pop();
+        true
+    }
+
+    /// Removes all sender IDs from the filter list.
+    pub fn clear_senders(&mut self) {
+        self.senders.clear();
+    }
+
+    /// Forgets all recently seen telegrams.
+    pub fn clear_history(&mut self) {
+        self.history = [Self::EMPTY_ENTRY; HISTORY];
+        self.next_history_index = 0;
+    }
+
+    /// Processes a received telegram. Returns the telegram to be sent, with its repeater count
+    /// incremented, if it is to be repeated.
+    ///
+    /// Telegrams are not repeated if they have already been repeated as often as the level of this
+    /// repeater allows, if they are marked as not to be repeated, if their sender is filtered out
+    /// or if a telegram with the same contents has been seen within the duplicate window.
+    pub fn handle_telegram(&mut self, telegram: &ErpData, now_ms: u32) -> Option<ErpData> {
+        let max_count = match self.level {
+            RepeaterLevel::OneLevel => 1,
+            RepeaterLevel::TwoLevel => 2,
+            _ => return None,
+        };
+
+        let sender = telegram.opt_sender()?;
+        let listed = self.senders.iter().any(|s| *s == sender);
+        let allowed = match self.filter {
+            SenderFilter::All => true,
+            SenderFilter::OnlyListed => listed,
+            SenderFilter::AllExceptListed => !listed,
+        };
+        if !allowed {
+            return None;
+        }
+
+        if self.is_duplicate(telegram, now_ms) {
+            return None;
+        }
+
+        let count: u8 = telegram.as_status_byte()?.repeater_count().into();
+        if count >= max_count {
+            // also covers DoNotRepeat
+            return None;
+        }
+
+        let mut repeated = telegram.clone();
+        let new_count = match count {
+            0 => RepeaterCount::RepeatedOnce,
+            _ => RepeaterCount::RepeatedTwice,
+        };
+        repeated.as_status_byte_mut()?.set_repeater_count(new_count);
+        Some(repeated)
+    }
+
+    /// Checks whether the telegram has been seen within the duplicate window, remembering it if
+    /// not.
+    fn is_duplicate(&mut self, telegram: &ErpData, now_ms: u32) -> bool {
+        let Some(contents) = ErpContents::new(telegram) else {
+            // telegrams of unknown types are never repeated anyway
+            return false;
+        };
+        let seen = self.history.iter()
+            .flatten()
+            .any(|s| now_ms.wrapping_sub(s.seen_ms) < self.duplicate_window_ms && s.contents == contents);
+        if seen {
+            return true;
+        }
+
+        if HISTORY > 0 {
+            self.history[self.next_history_index] = Some(SeenTelegram {
+                contents,
+                seen_ms: now_ms,
+            });
+            self.next_history_index = (self.next_history_index + 1) % HISTORY;
+        }
+        false
+    }
+}
+impl<const SENDERS: usize, const HISTORY: usize> Default for Repeater<SENDERS, HISTORY> {
+    fn default() -> Self { Self::new(RepeaterLevel::OneLevel, DEFAULT_DUPLICATE_WINDOW_MS) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{Repeater, SenderFilter};
+    use crate::esp3::RepeaterLevel;
+    use crate::esp3::erp::{ErpData, FourByteTelegram, RepeaterCount};
+    use crate::esp3::erp::tests::switch;
+
+    #[test]
+    fn test_repeat_levels() {
+        let mut repeater: Repeater<4, 8> = Repeater::new(RepeaterLevel::OneLevel, 1000);
+
+        let repeated = repeater.handle_telegram(&switch(0x01020304, 0x50, 0x30), 0).unwrap();
+        assert_eq!(repeated, switch(0x01020304, 0x50, 0x31));
+        assert_eq!(repeated.as_status_byte().unwrap().repeater_count(), RepeaterCount::RepeatedOnce);
+
+        // already repeated once; the copy of the original is also a duplicate
+        assert_eq!(repeater.handle_telegram(&switch(0x01020304, 0x70, 0x31), 10), None);
+        assert_eq!(repeater.handle_telegram(&switch(0x01020304, 0x50, 0x31), 20), None);
+
+        repeater.set_level(RepeaterLevel::TwoLevel);
+        assert_eq!(
+            repeater.handle_telegram(&switch(0x01020304, 0x10, 0x31), 30),
+            Some(switch(0x01020304, 0x10, 0x32)),
+        );
+        assert_eq!(repeater.handle_telegram(&switch(0x01020304, 0x11, 0x32), 40), None);
+        assert_eq!(repeater.handle_telegram(&switch(0x01020304, 0x12, 0x3F), 50), None);
+
+        repeater.set_level(RepeaterLevel::Off);
+        assert_eq!(repeater.handle_telegram(&switch(0x01020304, 0x13, 0x30), 60), None);
+
+        // unknown telegram types have no known status byte
+        repeater.set_level(RepeaterLevel::OneLevel);
+        let other = ErpData::new_other(0x42, &[0x01, 0x02, 0x03, 0x04, 0x05, 0x00]).unwrap();
+        assert_eq!(repeater.handle_telegram(&other, 70), None);
+    }
+
+    #[test]
+    fn test_duplicates_and_filter() {
+        let mut repeater: Repeater<2, 2> = Repeater::new(RepeaterLevel::OneLevel, 100);
+
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x50, 0x30), 0).is_some());
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x50, 0x30), 99).is_none());
+        // outside the window
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x50, 0x30), 200).is_some());
+        // across wraparound of the clock
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x60, 0x30), u32::MAX - 10).is_some());
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x60, 0x30), 10).is_none());
+        // different telegrams of the same sender
+        let first = ErpData::FourByte(FourByteTelegram { data: 0xE41BA9F1, sender: 0x01020304, status: 0x00 });
+        let second = ErpData::FourByte(FourByteTelegram { data: 0x2D0E5418, sender: 0x01020304, status: 0x00 });
+        assert!(repeater.handle_telegram(&first, 20).is_some());
+        assert!(repeater.handle_telegram(&second, 30).is_some());
+
+        repeater.clear_history();
+        repeater.set_filter(SenderFilter::OnlyListed);
+        assert!(repeater.add_sender(0xAABBCCDD));
+        assert!(repeater.add_sender(0x11223344));
+        assert!(!repeater.add_sender(0x55667788));
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x50, 0x30), 0).is_none());
+        assert!(repeater.handle_telegram(&switch(0xAABBCCDD, 0x50, 0x30), 0).is_some());
+
+        repeater.set_filter(SenderFilter::AllExceptListed);
+        assert!(repeater.handle_telegram(&switch(0x01020304, 0x50, 0x30), 0).is_some());
+        assert!(repeater.handle_telegram(&switch(0x11223344, 0x50, 0x30), 0).is_none());
+
+        assert!(repeater.remove_sender(0x11223344));
+        assert!(!repeater.remove_sender(0x11223344));
+        assert_eq!(repeater.senders(), &[0xAABBCCDD]);
+        assert!(repeater.handle_telegram(&switch(0x11223344, 0x50, 0x30), 0).is_some());
+    }
+}
@@ -6,6 +6,7 @@ use crate::esp3::{
     MAX_DATA_LENGTH, MAX_OPTIONAL_LENGTH, MemoryType, OneByteBoolean, Protocol, RepeaterEnable,
     RepeaterLevel, TxOnlyMode,
 };
+use crate::esp3::error::{ParseError, ParseErrorKind, expect_entries, expect_length};
 use crate::max_array::MaxArray;
 use crate::max_array_ext::MaxArrayPushIntExt;
 
@@ -15,7 +16,8 @@ pub trait ResponseData {
     /// Attempts to deserialize a response packet from the given data and optional data slices.
     ///
     /// Note that the passed data slice must not contain the response code in the first byte.
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> where Self : Sized;
+    /// Offsets in the error are relative to the start of the data slice.
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> where Self : Sized;
 
     /// Attempts to serialize this response packet into data bytes.
     ///
@@ -40,10 +42,8 @@ pub struct CoRdVersion {
     pub app_description: [u8; 16],
 }
 impl ResponseData for CoRdVersion {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 32 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 32)?;
 
         let app_version = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
         let api_version = u32::from_be_bytes(data_slice[4..8].try_into().unwrap());
@@ -53,7 +53,7 @@ impl ResponseData for CoRdVersion {
         let mut app_description = [0; 16];
         app_description.copy_from_slice(&data_slice[16..32]);
 
-        Some(Self {
+        Ok(Self {
             app_version,
             api_version,
             chip_id,
@@ -82,7 +82,7 @@ pub struct CoRdSysLog {
     pub app_log_entries: MaxArray<u8, MAX_OPTIONAL_LENGTH>,
 }
 impl ResponseData for CoRdSysLog {
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
         let mut api_log_entries = MaxArray::new();
         for b in data_slice {
             api_log_entries.push(*b).unwrap();
@@ -93,7 +93,7 @@ impl ResponseData for CoRdSysLog {
             app_log_entries.push(*b).unwrap();
         }
 
-        Some(Self {
+        Ok(Self {
             api_log_entries,
             app_log_entries,
         })
@@ -108,19 +108,38 @@ impl ResponseData for CoRdSysLog {
     }
 }
 
+/// Response data to CommandData::CoRdSysLog, borrowed from the response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CoRdSysLogRef<'a> {
+    pub api_log_entries: &'a [u8],
+    pub app_log_entries: &'a [u8],
+}
+impl<'a> CoRdSysLogRef<'a> {
+    /// Borrows the response data from the given data and optional data slices.
+    pub fn from_data(data_slice: &'a [u8], optional_slice: &'a [u8]) -> Result<Self, ParseError> {
+        Ok(Self {
+            api_log_entries: data_slice,
+            app_log_entries: optional_slice,
+        })
+    }
+
+    /// Copies the response data into a [`CoRdSysLog`].
+    pub fn into_owned(self) -> CoRdSysLog {
+        CoRdSysLog::from_data(self.api_log_entries, self.app_log_entries).unwrap()
+    }
+}
+
 /// Response data to CommandData::CoWrBiSt.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct CoWrBiSt {
     pub bist_result: u8,
 }
 impl ResponseData for CoWrBiSt {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let bist_result = data_slice[0];
-        Some(Self {
+        Ok(Self {
             bist_result,
         })
     }
@@ -139,16 +158,14 @@ pub struct CoRdIdBase {
     pub opt_remaining_write_cycles: Option<u8>,
 }
 impl ResponseData for CoRdIdBase {
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 4 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 4)?;
 
         let base_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
-        let opt_remaining_write_cycles = (optional_slice.len() >= 1)
+        let opt_remaining_write_cycles = (!optional_slice.is_empty())
             .then(|| optional_slice[0]);
 
-        Some(Self {
+        Ok(Self {
             base_id,
             opt_remaining_write_cycles,
         })
@@ -176,14 +193,12 @@ pub struct CoRdRepeater {
     pub level: RepeaterLevel,
 }
 impl ResponseData for CoRdRepeater {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 2 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 2)?;
 
         let enabled = data_slice[0].into();
         let level = data_slice[1].into();
-        Some(Self {
+        Ok(Self {
             enabled,
             level,
         })
@@ -205,10 +220,8 @@ pub struct CoRdFilter {
     pub filters: MaxArray<FilterEntry, {(MAX_DATA_LENGTH - 1)/5}>,
 }
 impl ResponseData for CoRdFilter {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() % 5 != 0 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_entries(data_slice, 5)?;
 
         let mut filters = MaxArray::new();
         let mut i = 0;
@@ -223,7 +236,7 @@ impl ResponseData for CoRdFilter {
             i += 5;
         }
 
-        Some(Self {
+        Ok(Self {
             filters,
         })
     }
@@ -238,6 +251,36 @@ impl ResponseData for CoRdFilter {
     }
 }
 
+/// Response data to CommandData::CoRdFilter, borrowed from the response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CoRdFilterRef<'a> {
+    // 5 bytes per filter; see filters()
+    filter_bytes: &'a [u8],
+}
+impl<'a> CoRdFilterRef<'a> {
+    /// Borrows the response data from the given data and optional data slices.
+    pub fn from_data(data_slice: &'a [u8], _optional_slice: &'a [u8]) -> Result<Self, ParseError> {
+        expect_entries(data_slice, 5)?;
+        Ok(Self {
+            filter_bytes: data_slice,
+        })
+    }
+
+    /// The filters configured in the device.
+    pub fn filters(&self) -> impl Iterator<Item = FilterEntry> + 'a {
+        self.filter_bytes.chunks_exact(5)
+            .map(|chunk| FilterEntry {
+                criterion: chunk[0].into(),
+                value: u32::from_be_bytes(chunk[1..5].try_into().unwrap()),
+            })
+    }
+
+    /// Copies the response data into a [`CoRdFilter`].
+    pub fn into_owned(self) -> CoRdFilter {
+        CoRdFilter::from_data(self.filter_bytes, &[]).unwrap()
+    }
+}
+
 /// Response data to CommandData::CoRdMem.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct CoRdMem {
@@ -245,11 +288,11 @@ pub struct CoRdMem {
     pub data: MaxArray<u8, {MAX_DATA_LENGTH-1}>,
 }
 impl ResponseData for CoRdMem {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
         let data = MaxArray::from_iter_or_panic(
-            data_slice.iter().map(|b| *b).peekable()
+            data_slice.iter().copied().peekable()
         );
-        Some(Self {
+        Ok(Self {
             data,
         })
     }
@@ -259,6 +302,25 @@ impl ResponseData for CoRdMem {
     }
 }
 
+/// Response data to CommandData::CoRdMem, borrowed from the response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CoRdMemRef<'a> {
+    pub data: &'a [u8],
+}
+impl<'a> CoRdMemRef<'a> {
+    /// Borrows the response data from the given data and optional data slices.
+    pub fn from_data(data_slice: &'a [u8], _optional_slice: &'a [u8]) -> Result<Self, ParseError> {
+        Ok(Self {
+            data: data_slice,
+        })
+    }
+
+    /// Copies the response data into a [`CoRdMem`].
+    pub fn into_owned(self) -> CoRdMem {
+        CoRdMem::from_data(self.data, &[]).unwrap()
+    }
+}
+
 /// Response data to CommandData::CoRdMemAddress.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct CoRdMemAddress {
@@ -267,15 +329,13 @@ pub struct CoRdMemAddress {
     pub length: u32,
 }
 impl ResponseData for CoRdMemAddress {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 9 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 9)?;
 
         let memory_type = data_slice[0].into();
         let address = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
         let length = u32::from_be_bytes(data_slice[5..9].try_into().unwrap());
-        Some(Self {
+        Ok(Self {
             memory_type,
             address,
             length,
@@ -301,15 +361,13 @@ pub struct CoRdSecurity {
 }
 #[allow(deprecated)]
 impl ResponseData for CoRdSecurity {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 9 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 9)?;
 
-        let security_level = data_slice[0].into();
+        let security_level = data_slice[0];
         let key = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
         let rolling_code = u32::from_be_bytes(data_slice[5..9].try_into().unwrap());
-        Some(Self {
+        Ok(Self {
             security_level,
             key,
             rolling_code,
@@ -332,15 +390,13 @@ pub struct CoRdLearnMode {
     pub opt_channel: Option<ChannelNumber>,
 }
 impl ResponseData for CoRdLearnMode {
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let enabled = data_slice[0].into();
-        let opt_channel = (optional_slice.len() >= 1)
+        let opt_channel = (!optional_slice.is_empty())
             .then(|| optional_slice[0].into());
-        Some(Self {
+        Ok(Self {
             enabled,
             opt_channel,
         })
@@ -374,10 +430,8 @@ pub struct CoRdSecureDeviceByIndex {
 }
 #[allow(deprecated)]
 impl ResponseData for CoRdSecureDeviceByIndex {
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 5 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 5)?;
 
         let slf = data_slice[0];
         let device_id = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
@@ -408,7 +462,7 @@ impl ResponseData for CoRdSecureDeviceByIndex {
         let opt_teach_info = (optional_slice.len() >= 36)
             .then(|| optional_slice[35]);
 
-        Some(Self {
+        Ok(Self {
             slf,
             device_id,
             opt_private_key,
@@ -467,13 +521,11 @@ pub struct CoRdNumSecureDevices {
     pub number: u8,
 }
 impl ResponseData for CoRdNumSecureDevices {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let number = data_slice[0];
-        Some(Self {
+        Ok(Self {
             number,
         })
     }
@@ -492,17 +544,15 @@ pub struct CoRdSecureDeviceById {
     pub opt_index: Option<u8>,
 }
 impl ResponseData for CoRdSecureDeviceById {
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let slf = data_slice[0];
 
-        let opt_index = (optional_slice.len() >= 1)
+        let opt_index = (!optional_slice.is_empty())
             .then(|| optional_slice[0]);
 
-        Some(Self {
+        Ok(Self {
             slf,
             opt_index,
         })
@@ -531,10 +581,8 @@ pub struct CoRdSecureDevicePsk {
     pub psk: [u32; 4],
 }
 impl ResponseData for CoRdSecureDevicePsk {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 16 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 16)?;
 
         let psk = [
             u32::from_be_bytes(data_slice[0..4].try_into().unwrap()),
@@ -543,7 +591,7 @@ impl ResponseData for CoRdSecureDevicePsk {
             u32::from_be_bytes(data_slice[12..16].try_into().unwrap()),
         ];
 
-        Some(Self {
+        Ok(Self {
             psk,
         })
     }
@@ -568,10 +616,8 @@ pub struct CoRdDutyCycleLimit {
     pub load_after_actual: u8,
 }
 impl ResponseData for CoRdDutyCycleLimit {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 7 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 7)?;
 
         let available = data_slice[0];
         let slots = data_slice[1];
@@ -579,7 +625,7 @@ impl ResponseData for CoRdDutyCycleLimit {
         let actual_slot_left = u16::from_be_bytes(data_slice[4..6].try_into().unwrap());
         let load_after_actual = data_slice[6];
 
-        Some(Self {
+        Ok(Self {
             available,
             slots,
             slot_period,
@@ -606,15 +652,13 @@ pub struct CoGetFrequencyInfo {
     pub protocol: Protocol,
 }
 impl ResponseData for CoGetFrequencyInfo {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 2 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 2)?;
 
         let frequency = data_slice[0].into();
         let protocol = data_slice[1].into();
 
-        Some(Self {
+        Ok(Self {
             frequency,
             protocol,
         })
@@ -635,15 +679,13 @@ pub struct CoGetStepCode {
     pub status_code: u8,
 }
 impl ResponseData for CoGetStepCode {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 2 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 2)?;
 
         let step_code = data_slice[0];
         let status_code = data_slice[1];
 
-        Some(Self {
+        Ok(Self {
             step_code,
             status_code,
         })
@@ -663,14 +705,12 @@ pub struct CoRdReManRepeating {
     pub re_man_telegrams_repeated: OneByteBoolean,
 }
 impl ResponseData for CoRdReManRepeating {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let re_man_telegrams_repeated = data_slice[0].into();
 
-        Some(Self {
+        Ok(Self {
             re_man_telegrams_repeated,
         })
     }
@@ -688,14 +728,12 @@ pub struct CoGetNoiseThreshold {
     pub rssi_level: u8,
 }
 impl ResponseData for CoGetNoiseThreshold {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let rssi_level = data_slice[0];
 
-        Some(Self {
+        Ok(Self {
             rssi_level,
         })
     }
@@ -718,10 +756,8 @@ pub struct CoRdSecureDeviceV2ByIndex {
     pub opt_psk: Option<[u32; 4]>,
 }
 impl ResponseData for CoRdSecureDeviceV2ByIndex {
-    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 26 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 26)?;
 
         let slf = data_slice[0];
         let device_id = u32::from_be_bytes(data_slice[1..5].try_into().unwrap());
@@ -742,7 +778,7 @@ impl ResponseData for CoRdSecureDeviceV2ByIndex {
                 u32::from_be_bytes(optional_slice[12..16].try_into().unwrap()),
             ]);
 
-        Some(Self {
+        Ok(Self {
             slf,
             device_id,
             private_key,
@@ -783,13 +819,11 @@ pub struct OneByteBooleanResponseData {
     pub enabled: OneByteBoolean,
 }
 impl ResponseData for OneByteBooleanResponseData {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let enabled = data_slice[0].into();
-        Some(Self {
+        Ok(Self {
             enabled,
         })
     }
@@ -811,10 +845,8 @@ pub struct CoRdSecureDeviceMaintenanceKey {
     pub outbound_rolling_code: u32,
 }
 impl ResponseData for CoRdSecureDeviceMaintenanceKey {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 29 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 29)?;
 
         let device_id = u32::from_be_bytes(data_slice[0..4].try_into().unwrap());
         let private_key = [
@@ -827,7 +859,7 @@ impl ResponseData for CoRdSecureDeviceMaintenanceKey {
         let inbound_rolling_code = u32::from_be_bytes(data_slice[21..25].try_into().unwrap());
         let outbound_rolling_code = u32::from_be_bytes(data_slice[25..29].try_into().unwrap());
 
-        Some(Self {
+        Ok(Self {
             device_id,
             private_key,
             key_number,
@@ -856,13 +888,11 @@ pub struct CoRdTxOnlyMode {
     pub mode: TxOnlyMode,
 }
 impl ResponseData for CoRdTxOnlyMode {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let mode = data_slice[0].into();
-        Some(Self {
+        Ok(Self {
             mode,
         })
     }
@@ -874,17 +904,18 @@ impl ResponseData for CoRdTxOnlyMode {
     }
 }
 
-/// An enumeration of all common command responses.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// An enumeration of all common command responses, borrowing variable-length data from the
+/// response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[allow(deprecated)]
-pub enum CommonCommandResponse {
+pub enum CommonCommandResponse<'a> {
     RdVersion(CoRdVersion),
-    RdSysLog(CoRdSysLog),
+    RdSysLog(CoRdSysLogRef<'a>),
     WrBiSt(CoWrBiSt),
     RdIdBase(CoRdIdBase),
     RdRepeater(CoRdRepeater),
-    RdFilter(CoRdFilter),
-    RdMem(CoRdMem),
+    RdFilter(CoRdFilterRef<'a>),
+    RdMem(CoRdMemRef<'a>),
     RdMemAddress(CoRdMemAddress),
     RdSecurity(CoRdSecurity),
     RdLearnMode(CoRdLearnMode),
@@ -902,17 +933,23 @@ pub enum CommonCommandResponse {
     RdSecureDeviceMaintenanceKey(CoRdSecureDeviceMaintenanceKey),
     RdTxOnlyMode(CoRdTxOnlyMode),
 }
-impl CommonCommandResponse {
+impl<'a> CommonCommandResponse<'a> {
     /// Decodes response data for the command with the given command code.
-    pub fn decode_for_command(command: u8, data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+    pub fn decode_for_command(command: u8, data_slice: &'a [u8], optional_slice: &'a [u8]) -> Option<Self> {
+        Self::parse_for_command(command, data_slice, optional_slice).ok()
+    }
+
+    /// Decodes response data for the command with the given command code, reporting why decoding
+    /// failed. Offsets are relative to the start of the data slice.
+    pub fn parse_for_command(command: u8, data_slice: &'a [u8], optional_slice: &'a [u8]) -> Result<Self, ParseError> {
         match command {
             3 => CoRdVersion::from_data(data_slice, optional_slice).map(Self::RdVersion),
-            4 => CoRdSysLog::from_data(data_slice, optional_slice).map(Self::RdSysLog),
+            4 => CoRdSysLogRef::from_data(data_slice, optional_slice).map(Self::RdSysLog),
             6 => CoWrBiSt::from_data(data_slice, optional_slice).map(Self::WrBiSt),
             8 => CoRdIdBase::from_data(data_slice, optional_slice).map(Self::RdIdBase),
             10 => CoRdRepeater::from_data(data_slice, optional_slice).map(Self::RdRepeater),
-            15 => CoRdFilter::from_data(data_slice, optional_slice).map(Self::RdFilter),
-            19 => CoRdMem::from_data(data_slice, optional_slice).map(Self::RdMem),
+            15 => CoRdFilterRef::from_data(data_slice, optional_slice).map(Self::RdFilter),
+            19 => CoRdMemRef::from_data(data_slice, optional_slice).map(Self::RdMem),
             20 => CoRdMemAddress::from_data(data_slice, optional_slice).map(Self::RdMemAddress),
             #[allow(deprecated)] 21 => CoRdSecurity::from_data(data_slice, optional_slice).map(Self::RdSecurity),
             24 => CoRdLearnMode::from_data(data_slice, optional_slice).map(Self::RdLearnMode),
@@ -929,7 +966,7 @@ impl CommonCommandResponse {
             59|63 => OneByteBooleanResponseData::from_data(data_slice, optional_slice).map(Self::OneByteBoolean),
             61 => CoRdSecureDeviceMaintenanceKey::from_data(data_slice, optional_slice).map(Self::RdSecureDeviceMaintenanceKey),
             65 => CoRdTxOnlyMode::from_data(data_slice, optional_slice).map(Self::RdTxOnlyMode),
-            _ => None,
+            _ => Err(ParseError::new(0, ParseErrorKind::UnknownCode { code: command })),
         }
     }
 }
@@ -942,14 +979,12 @@ pub struct SaRdLearnMode {
     pub extended: ExtendedLearnMode,
 }
 impl ResponseData for SaRdLearnMode {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 2 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 2)?;
 
         let enabled = data_slice[0].into();
         let extended = data_slice[1].into();
-        Some(Self {
+        Ok(Self {
             enabled,
             extended,
         })
@@ -971,10 +1006,8 @@ pub struct SaRdLearnedClients {
     pub learned_clients: MaxArray<LearnedClient, {(MAX_DATA_LENGTH - 1)/9}>,
 }
 impl ResponseData for SaRdLearnedClients {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() % 9 != 0 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_entries(data_slice, 9)?;
 
         let mut learned_clients = MaxArray::new();
         let mut i = 0;
@@ -990,7 +1023,7 @@ impl ResponseData for SaRdLearnedClients {
             i += 9;
         }
 
-        Some(Self {
+        Ok(Self {
             learned_clients,
         })
     }
@@ -1006,19 +1039,48 @@ impl ResponseData for SaRdLearnedClients {
     }
 }
 
+/// Response data to SmartAckData::SaRdLearnedClients, borrowed from the response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SaRdLearnedClientsRef<'a> {
+    // 9 bytes per learned client; see learned_clients()
+    learned_client_bytes: &'a [u8],
+}
+impl<'a> SaRdLearnedClientsRef<'a> {
+    /// Borrows the response data from the given data and optional data slices.
+    pub fn from_data(data_slice: &'a [u8], _optional_slice: &'a [u8]) -> Result<Self, ParseError> {
+        expect_entries(data_slice, 9)?;
+        Ok(Self {
+            learned_client_bytes: data_slice,
+        })
+    }
+
+    /// The clients learned by the device.
+    pub fn learned_clients(&self) -> impl Iterator<Item = LearnedClient> + 'a {
+        self.learned_client_bytes.chunks_exact(9)
+            .map(|chunk| LearnedClient {
+                smart_ack_client_id: u32::from_be_bytes(chunk[0..4].try_into().unwrap()),
+                controller_id: u32::from_be_bytes(chunk[4..8].try_into().unwrap()),
+                mailbox_index: chunk[8],
+            })
+    }
+
+    /// Copies the response data into a [`SaRdLearnedClients`].
+    pub fn into_owned(self) -> SaRdLearnedClients {
+        SaRdLearnedClients::from_data(self.learned_client_bytes, &[]).unwrap()
+    }
+}
+
 /// Response data to SmartAckData::SaRdMailboxStatus.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct SaRdMailboxStatus {
     pub status: MailboxStatus,
 }
 impl ResponseData for SaRdMailboxStatus {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let status = data_slice[0].into();
-        Some(Self {
+        Ok(Self {
             status,
         })
     }
@@ -1030,19 +1092,44 @@ impl ResponseData for SaRdMailboxStatus {
     }
 }
 
+/// An enumeration of all Smart Ack command responses, borrowing variable-length data from the
+/// response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SmartAckResponse<'a> {
+    RdLearnMode(SaRdLearnMode),
+    RdLearnedClients(SaRdLearnedClientsRef<'a>),
+    RdMailboxStatus(SaRdMailboxStatus),
+}
+impl<'a> SmartAckResponse<'a> {
+    /// Decodes response data for the Smart Ack command with the given command code.
+    pub fn decode_for_command(command: u8, data_slice: &'a [u8], optional_slice: &'a [u8]) -> Option<Self> {
+        Self::parse_for_command(command, data_slice, optional_slice).ok()
+    }
+
+    /// Decodes response data for the command with the given command code, reporting why decoding
+    /// failed. Offsets are relative to the start of the data slice.
+    pub fn parse_for_command(command: u8, data_slice: &'a [u8], optional_slice: &'a [u8]) -> Result<Self, ParseError> {
+        match command {
+            2 => SaRdLearnMode::from_data(data_slice, optional_slice).map(Self::RdLearnMode),
+            6 => SaRdLearnedClientsRef::from_data(data_slice, optional_slice).map(Self::RdLearnedClients),
+            9 => SaRdMailboxStatus::from_data(data_slice, optional_slice).map(Self::RdMailboxStatus),
+            _ => Err(ParseError::new(0, ParseErrorKind::UnknownCode { code: command })),
+        }
+    }
+}
+
+
 /// Response data to Command24Data::ReadChannel.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct R802RdChannel {
     pub channel: u8,
 }
 impl ResponseData for R802RdChannel {
-    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Option<Self> {
-        if data_slice.len() != 1 {
-            return None;
-        }
+    fn from_data(data_slice: &[u8], _optional_slice: &[u8]) -> Result<Self, ParseError> {
+        expect_length(data_slice, 1)?;
 
         let channel = data_slice[0];
-        Some(Self {
+        Ok(Self {
             channel,
         })
     }
@@ -1053,3 +1140,24 @@ impl ResponseData for R802RdChannel {
         ret
     }
 }
+
+/// An enumeration of all 2.4 GHz command responses.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Command24Response {
+    RdChannel(R802RdChannel),
+}
+impl Command24Response {
+    /// Decodes response data for the 2.4 GHz command with the given command code.
+    pub fn decode_for_command(command: u8, data_slice: &[u8], optional_slice: &[u8]) -> Option<Self> {
+        Self::parse_for_command(command, data_slice, optional_slice).ok()
+    }
+
+    /// Decodes response data for the command with the given command code, reporting why decoding
+    /// failed. Offsets are relative to the start of the data slice.
+    pub fn parse_for_command(command: u8, data_slice: &[u8], optional_slice: &[u8]) -> Result<Self, ParseError> {
+        match command {
+            2 => R802RdChannel::from_data(data_slice, optional_slice).map(Self::RdChannel),
+            _ => Err(ParseError::new(0, ParseErrorKind::UnknownCode { code: command })),
+        }
+    }
+}
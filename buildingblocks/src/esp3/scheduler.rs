@@ -0,0 +1,603 @@
+//! Duty-cycle accounting and scheduling of outgoing radio packets.
+//!
+//! In the 868 MHz band, a device may only transmit for 1% of each hour. EnOcean modules enforce
+//! this limit themselves and refuse to send once it has been reached, which tends to happen at the
+//! worst possible moment. [`TransmitScheduler`] estimates the airtime of each outgoing packet,
+//! tracks the airtime budget with a [`DutyCycleTracker`], sends more important packets first and
+//! backs off when the channel is busy. It performs no I/O itself: the caller transmits the packets
+//! it returns, feeds it the packets it receives and supplies the current time as a wrapping
+//! millisecond counter.
+
+
+use crate::esp3::{Esp3Packet, Esp3PacketRef, EventData, OneByteBoolean, TransmissionFailureReason};
+use crate::esp3::builder::SEND_SUB_TELEGRAM_NUMBER;
+use crate::esp3::response_data::CoRdDutyCycleLimit;
+use crate::max_array::MaxArray;
+
+
+/// The period, in milliseconds, over which the duty cycle is calculated.
+pub const DUTY_CYCLE_PERIOD_MS: u32 = 60 * 60 * 1000;
+
+/// The number of slots into which the duty cycle period is divided, unless the device reports
+/// otherwise. This is also the maximum number of slots that can be tracked.
+///
+/// Airtime is released at the end of the period, one slot at a time.
+pub const DUTY_CYCLE_SLOTS: usize = 24;
+
+/// The airtime, in microseconds, that may be used within the duty cycle period (1% of an hour).
+pub const DEFAULT_DUTY_CYCLE_BUDGET_US: u32 = DUTY_CYCLE_PERIOD_MS / 100 * 1000;
+
+/// The time, in milliseconds, to wait before retrying a transmission after the channel has been
+/// found busy for the first time. The time is doubled for each further failure.
+pub const CSMA_BACKOFF_BASE_MS: u32 = 50;
+
+/// The number of times a transmission is attempted before it is given up if the channel is busy.
+pub const MAX_CSMA_ATTEMPTS: u8 = 5;
+
+/// The time, in milliseconds, to wait for the device to report the outcome of a transmission
+/// before the next packet is sent regardless.
+pub const DEFAULT_TRANSMIT_TIMEOUT_MS: u32 = 1000;
+
+/// The duration, in microseconds, of one bit transmitted using ERP1 (125 kbit/s).
+const ERP1_BIT_TIME_US: u32 = 8;
+
+/// The number of bits transmitted in addition to the encoded bytes of a subtelegram (preamble,
+/// start of frame and end of frame).
+const ERP1_FRAME_OVERHEAD_BITS: u32 = 8 + 8 + 8;
+
+/// The number of bits used to transmit one byte in an ERP1 subtelegram, including synchronization
+/// bits.
+const ERP1_BITS_PER_BYTE: u32 = 12;
+
+/// The number of bytes in an ERP1 telegram in addition to the data: RORG, sender ID and status.
+const ERP1_TELEGRAM_OVERHEAD: usize = 1 + 4 + 1;
+
+/// The maximum length of the data in an ERP1 telegram before it has to be split into a chain.
+const MAX_UNCHAINED_DATA_LENGTH: usize = 14;
+
+/// The number of data bytes carried by each telegram of a chain (conservatively, that of the
+/// first telegram, which also carries the total length).
+const CHAINED_DATA_LENGTH: usize = 8;
+
+
+/// Estimates the airtime, in microseconds, of an ERP1 telegram with the given length (RORG to
+/// status byte, inclusive) that is sent as the given number of subtelegrams.
+pub fn estimate_airtime_us(telegram_length: usize, sub_telegrams: u8) -> u32 {
+    // each subtelegram additionally carries a checksum byte
+    let bytes = u32::try_from(telegram_length + 1).unwrap_or(u32::MAX);
+    let sub_telegram_bits = bytes.saturating_mul(ERP1_BITS_PER_BYTE).saturating_add(ERP1_FRAME_OVERHEAD_BITS);
+    sub_telegram_bits
+        .saturating_mul(ERP1_BIT_TIME_US)
+        .saturating_mul(sub_telegrams.max(1).into())
+}
+
+/// Estimates the airtime, in microseconds, of data that is sent by the device as one or more ERP1
+/// telegrams, chaining them if the data is too long for one telegram.
+fn estimate_message_airtime_us(data_length: usize, sub_telegrams: u8) -> u32 {
+    if data_length <= MAX_UNCHAINED_DATA_LENGTH {
+        return estimate_airtime_us(data_length + ERP1_TELEGRAM_OVERHEAD, sub_telegrams);
+    }
+
+    let telegrams = data_length.div_ceil(CHAINED_DATA_LENGTH);
+    let telegram_airtime_us = estimate_airtime_us(CHAINED_DATA_LENGTH + 2 + ERP1_TELEGRAM_OVERHEAD, sub_telegrams);
+    telegram_airtime_us.saturating_mul(u32::try_from(telegrams).unwrap_or(u32::MAX))
+}
+
+/// Estimates the airtime, in microseconds, required to send the packet. Packets that are not sent
+/// via radio require no airtime.
+pub fn estimate_packet_airtime_us(packet: &Esp3Packet) -> u32 {
+    match packet {
+        Esp3Packet::RadioErp1 { radio_telegram, opt_sub_telegram_number, .. } => estimate_airtime_us(
+            radio_telegram.len(),
+            opt_sub_telegram_number.unwrap_or(SEND_SUB_TELEGRAM_NUMBER),
+        ),
+        Esp3Packet::RadioMessage { data, .. }
+            => estimate_message_airtime_us(data.len(), SEND_SUB_TELEGRAM_NUMBER),
+        // function and manufacturer are sent along with the message
+        Esp3Packet::RemoteManCommand { message, .. }
+            => estimate_message_airtime_us(message.len() + 4, SEND_SUB_TELEGRAM_NUMBER),
+        _ => 0,
+    }
+}
+
+
+/// Tracks the airtime used within the duty cycle period.
+///
+/// The period is divided into [`DUTY_CYCLE_SLOTS`] slots of [`DutyCycleTracker::SLOT_PERIOD_MS`]
+/// each, until the device reports its own slotting; airtime used within a slot becomes available
+/// again once the slot is older than the period.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DutyCycleTracker {
+    budget_us: u32,
+    slot_usage_us: [u32; DUTY_CYCLE_SLOTS],
+    slots: usize,
+    slot_period_ms: u32,
+    current_slot: usize,
+    slot_started_ms: u32,
+}
+impl DutyCycleTracker {
+    /// The default duration of a slot, in milliseconds.
+    pub const SLOT_PERIOD_MS: u32 = DUTY_CYCLE_PERIOD_MS / (DUTY_CYCLE_SLOTS as u32);
+
+    /// Creates a new tracker with the given airtime budget, in microseconds, within the duty cycle
+    /// period.
+    pub const fn new(budget_us: u32) -> Self {
+        Self {
+            budget_us,
+            slot_usage_us: [0; DUTY_CYCLE_SLOTS],
+            slots: DUTY_CYCLE_SLOTS,
+            slot_period_ms: Self::SLOT_PERIOD_MS,
+            current_slot: 0,
+            slot_started_ms: 0,
+        }
+    }
+
+    /// The airtime, in microseconds, that may be used within the duty cycle period.
+    pub fn budget_us(&self) -> u32 {
+        self.budget_us
+    }
+
+    /// The number of slots into which the duty cycle period is divided.
+    pub fn slots(&self) -> usize {
+        self.slots
+    }
+
+    /// The duration of a slot, in milliseconds.
+    pub fn slot_period_ms(&self) -> u32 {
+        self.slot_period_ms
+    }
+
+    /// The airtime, in microseconds, used within the duty cycle period.
+    pub fn used_us(&self) -> u32 {
+        self.slot_usage_us.iter()
+            .fold(0u32, |total, usage| total.saturating_add(*usage))
+    }
+
+    /// The airtime, in microseconds, that is still available within the duty cycle period.
+    pub fn available_us(&self) -> u32 {
+        self.budget_us.saturating_sub(self.used_us())
+    }
+
+    /// Releases the airtime of the slots that have become older than the duty cycle period.
+    pub fn advance(&mut self, now_ms: u32) {
+        let elapsed_ms = now_ms.wrapping_sub(self.slot_started_ms);
+        let elapsed_slots = elapsed_ms / self.slot_period_ms;
+        if elapsed_slots == 0 {
+            return;
+        }
+
+        if elapsed_slots >= self.slots as u32 {
+            self.slot_usage_us = [0; DUTY_CYCLE_SLOTS];
+        } else {
+            for _ in 0..elapsed_slots {
+                // the next slot is the oldest one
+                self.current_slot = (self.current_slot + 1) % self.slots;
+                self.slot_usage_us[self.current_slot] = 0;
+            }
+        }
+        self.slot_started_ms = now_ms.wrapping_sub(elapsed_ms % self.slot_period_ms);
+    }
+
+    /// Whether the given airtime, in microseconds, is still available.
+    pub fn can_send(&mut self, airtime_us: u32, now_ms: u32) -> bool {
+        self.advance(now_ms);
+        airtime_us <= self.available_us()
+    }
+
+    /// Records that the given airtime, in microseconds, has been used.
+    pub fn record(&mut self, airtime_us: u32, now_ms: u32) {
+        self.advance(now_ms);
+        let usage = &mut self.slot_usage_us[self.current_slot];
+        *usage = usage.saturating_add(airtime_us);
+    }
+
+    /// Replaces the tracked usage with the state reported by the device in response to
+    /// [`CommandData::CoRdDutyCycleLimit`](crate::esp3::CommandData::CoRdDutyCycleLimit).
+    ///
+    /// The device only reports percentages of its budget; they are applied to the budget of this
+    /// tracker. The airtime released after the current slot is attributed to the oldest slot; the
+    /// remaining airtime to the current slot.
+    ///
+    /// The slotting reported by the device is adopted. If the device uses more than
+    /// [`DUTY_CYCLE_SLOTS`] slots, the slots are made longer so that the period stays the same.
+    pub fn update_from_device(&mut self, limit: &CoRdDutyCycleLimit, now_ms: u32) {
+        let percent_of_budget = |percent: u8| -> u32 {
+            let percent = u64::from(percent.min(100));
+            (u64::from(self.budget_us) * percent / 100) as u32
+        };
+        let used_us = self.budget_us - percent_of_budget(limit.available);
+        let released_us = percent_of_budget(limit.load_after_actual).min(used_us);
+
+        if limit.slots > 0 && limit.slot_period > 0 {
+            let period_ms = u64::from(limit.slots) * u64::from(limit.slot_period) * 1000;
+            self.slots = usize::from(limit.slots).min(DUTY_CYCLE_SLOTS);
+            self.slot_period_ms = (period_ms / self.slots as u64).min(u64::from(u32::MAX)) as u32;
+        }
+        self.current_slot = 0;
+
+        let slot_left_ms = (u32::from(limit.actual_slot_left).saturating_mul(1000)).min(self.slot_period_ms);
+        self.slot_started_ms = now_ms.wrapping_sub(self.slot_period_ms - slot_left_ms);
+        self.slot_usage_us = [0; DUTY_CYCLE_SLOTS];
+        self.slot_usage_us[self.current_slot] = used_us - released_us;
+        self.slot_usage_us[(self.current_slot + 1) % self.slots] += released_us;
+    }
+}
+impl Default for DutyCycleTracker {
+    fn default() -> Self { Self::new(DEFAULT_DUTY_CYCLE_BUDGET_US) }
+}
+
+
+/// The importance of an outgoing packet. More important packets are sent first.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TransmitPriority {
+    /// Packets that can be delayed indefinitely, e.g. diagnostics.
+    Low,
+
+    /// Regular packets.
+    Normal,
+
+    /// Packets that control devices, e.g. valve setpoints.
+    High,
+}
+
+/// The outcome of a transmission, as reported by the device.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TransmitResult {
+    /// The device has sent the packet.
+    Sent,
+
+    /// The device has failed to send the packet and no further attempts will be made.
+    Failed(TransmissionFailureReason),
+
+    /// The device has not reported the outcome in time.
+    Unconfirmed,
+}
+
+/// The outcome of the transmission of a packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TransmitEvent {
+    /// The identifier returned by [`TransmitScheduler::enqueue`].
+    pub transmit_id: u32,
+
+    pub result: TransmitResult,
+}
+
+
+#[derive(Debug)]
+struct QueuedPacket {
+    transmit_id: u32,
+    priority: TransmitPriority,
+    packet: Esp3Packet,
+    airtime_us: u32,
+    attempts: u8,
+}
+
+
+/// Queues up to `QUEUE` outgoing packets and hands them out for transmission by priority, within
+/// the duty cycle budget.
+///
+/// Packets of the same priority are sent in the order in which they have been queued. If the most
+/// important packet does not fit into the remaining budget, no packet is sent, so that packets of
+/// lower priority cannot use up the budget in the meantime.
+#[derive(Debug)]
+pub struct TransmitScheduler<const QUEUE: usize> {
+    duty_cycle: DutyCycleTracker,
+    queue: MaxArray<QueuedPacket, QUEUE>,
+    in_flight: Option<(QueuedPacket, u32)>,
+    next_transmit_id: u32,
+    sending_possible: bool,
+    backoff_since_ms: u32,
+    backoff_ms: u32,
+    consecutive_failures: u8,
+    timeout_ms: u32,
+}
+impl<const QUEUE: usize> TransmitScheduler<QUEUE> {
+    /// Creates a new scheduler with the given duty cycle budget, in microseconds, which waits for
+    /// the outcome of a transmission for `timeout_ms` milliseconds.
+    pub const fn new(budget_us: u32, timeout_ms: u32) -> Self {
+        Self {
+            duty_cycle: DutyCycleTracker::new(budget_us),
+            queue: MaxArray::new(),
+            in_flight: None,
+            next_transmit_id: 0,
+            sending_possible: true,
+            backoff_since_ms: 0,
+            backoff_ms: 0,
+            consecutive_failures: 0,
+            timeout_ms,
+        }
+    }
+
+    /// The tracker of the duty cycle budget.
+    pub fn duty_cycle(&self) -> &DutyCycleTracker {
+        &self.duty_cycle
+    }
+
+    /// Adds a packet to the queue, returning an identifier that is reported along with the outcome
+    /// of its transmission. If the queue is full, the packet is returned.
+    #[allow(clippy::result_large_err)]
+    pub fn enqueue(&mut self, packet: Esp3Packet, priority: TransmitPriority) -> Result<u32, Esp3Packet> {
+        let transmit_id = self.next_transmit_id;
+        let airtime_us = estimate_packet_airtime_us(&packet);
+        self.queue.push(QueuedPacket { transmit_id, priority, packet, airtime_us, attempts: 0 })
+            .map_err(|q| q.packet)?;
+        self.next_transmit_id = self.next_transmit_id.wrapping_add(1);
+        Ok(transmit_id)
+    }
+
+    /// The number of packets that have not been transmitted yet.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Whether no packets are queued or awaiting the outcome of their transmission.
+    pub fn is_idle(&self) -> bool {
+        self.queue.len() == 0 && self.in_flight.is_none()
+    }
+
+    /// Returns the next packet to transmit, if the device is not busy transmitting another one, the
+    /// channel is not being backed off from and the duty cycle budget allows it.
+    pub fn poll_transmit(&mut self, now_ms: u32) -> Option<Esp3Packet> {
+        if self.in_flight.is_some() || !self.sending_possible {
+            return None;
+        }
+        if now_ms.wrapping_sub(self.backoff_since_ms) < self.backoff_ms {
+            return None;
+        }
+
+        // identifiers increase in queueing order, but may wrap around
+        let oldest_id = self.queue.iter().next()?.transmit_id;
+        let index = self.queue.iter()
+            .enumerate()
+            .max_by_key(|(_, q)| (q.priority, u32::MAX - q.transmit_id.wrapping_sub(oldest_id)))
+            .map(|(i, _)| i)
+            .unwrap();
+        if !self.duty_cycle.can_send(self.queue.as_slice()[index].airtime_us, now_ms) {
+            return None;
+        }
+
+        // keep the queueing order of the remaining packets; the airtime is only charged once the
+        // packet has actually been sent, which is not the case if the channel is busy
+        self.queue.as_mut_slice()[index..].rotate_left(1);
+        self.in_flight = self.queue.pop().map(|queued| (queued, now_ms));
+        let (queued, _) = self.in_flight.as_mut().unwrap();
+        queued.attempts += 1;
+        Some(queued.packet.clone())
+    }
+
+    /// Checks whether the device has failed to report the outcome of the transmission in progress
+    /// in time, returning it if so.
+    pub fn poll_timeout(&mut self, now_ms: u32) -> Option<TransmitEvent> {
+        let (_, since_ms) = self.in_flight.as_ref()?;
+        if now_ms.wrapping_sub(*since_ms) < self.timeout_ms {
+            return None;
+        }
+
+        // the packet may have been sent
+        self.complete(TransmitResult::Unconfirmed, now_ms)
+    }
+
+    /// Processes an event sent by the device.
+    ///
+    /// Returns the outcome of the transmission in progress if the event reports it. If the channel
+    /// was busy, the packet is queued again and transmissions are delayed, until the packet has
+    /// been attempted [`MAX_CSMA_ATTEMPTS`] times.
+    pub fn handle_event(&mut self, event: &EventData, now_ms: u32) -> Option<TransmitEvent> {
+        match event {
+            EventData::CoDutyCycleLimit { sending_possible } => {
+                self.sending_possible = *sending_possible != OneByteBoolean::No;
+                None
+            },
+            EventData::CoTxDone => {
+                let event = self.complete(TransmitResult::Sent, now_ms)?;
+                self.consecutive_failures = 0;
+                self.backoff_ms = 0;
+                Some(event)
+            },
+            EventData::CoTransmitFailed { reason } => {
+                if *reason != TransmissionFailureReason::CsmaFailed {
+                    // e.g. a missing acknowledgement; the packet has been sent
+                    return self.complete(TransmitResult::Failed(*reason), now_ms);
+                }
+
+                let (queued, _) = self.in_flight.as_ref()?;
+                let failed = Some(TransmitEvent {
+                    transmit_id: queued.transmit_id,
+                    result: TransmitResult::Failed(*reason),
+                });
+                let attempts = queued.attempts;
+
+                self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+                self.backoff_since_ms = now_ms;
+                self.backoff_ms = CSMA_BACKOFF_BASE_MS << (self.consecutive_failures - 1).min(6);
+                if attempts >= MAX_CSMA_ATTEMPTS || self.queue.len() == QUEUE {
+                    self.in_flight = None;
+                    return failed;
+                }
+
+                // retry before all other packets of the same priority
+                if let Some((queued, _)) = self.in_flight.take() {
+                    let _ = self.queue.push(queued);
+                }
+                self.queue.as_mut_slice().rotate_right(1);
+                None
+            },
+            _ => None,
+        }
+    }
+
+    /// Processes a received packet. Packets that are not events are ignored.
+    ///
+    /// Behaves like [`TransmitScheduler::handle_event`].
+    pub fn handle_packet(&mut self, packet: &Esp3Packet, now_ms: u32) -> Option<TransmitEvent> {
+        match packet {
+            Esp3Packet::Event(event) => self.handle_event(event, now_ms),
+            _ => None,
+        }
+    }
+
+    /// Processes a received packet that is borrowed from the receive buffer.
+    ///
+    /// Behaves like [`TransmitScheduler::handle_packet`].
+    pub fn handle_packet_ref(&mut self, packet: &Esp3PacketRef<'_>, now_ms: u32) -> Option<TransmitEvent> {
+        match packet {
+            Esp3PacketRef::Event { event_code, data, optional_data } => {
+                let event = EventData::from_data(*event_code, data, optional_data).ok()?;
+                self.handle_event(&event, now_ms)
+            },
+            _ => None,
+        }
+    }
+
+    /// Ends the transmission in flight, charging its airtime to the duty cycle budget.
+    fn complete(&mut self, result: TransmitResult, now_ms: u32) -> Option<TransmitEvent> {
+        let (queued, _) = self.in_flight.as_ref()?;
+        let transmit_id = queued.transmit_id;
+        self.duty_cycle.record(queued.airtime_us, now_ms);
+        self.in_flight = None;
+        Some(TransmitEvent { transmit_id, result })
+    }
+
+    /// Updates the duty cycle budget with the state reported by the device.
+    ///
+    /// See [`DutyCycleTracker::update_from_device`].
+    pub fn handle_duty_cycle_limit(&mut self, limit: &CoRdDutyCycleLimit, now_ms: u32) {
+        self.duty_cycle.update_from_device(limit, now_ms);
+        self.sending_possible = limit.available > 0;
+    }
+}
+impl<const QUEUE: usize> Default for TransmitScheduler<QUEUE> {
+    fn default() -> Self { Self::new(DEFAULT_DUTY_CYCLE_BUDGET_US, DEFAULT_TRANSMIT_TIMEOUT_MS) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DUTY_CYCLE_SLOTS, DutyCycleTracker, TransmitEvent, TransmitPriority, TransmitResult, TransmitScheduler,
+        estimate_airtime_us,
+    };
+    use crate::esp3::{Esp3Packet, EventData, OneByteBoolean, TransmissionFailureReason};
+    use crate::esp3::builder::RadioErp1Builder;
+    use crate::esp3::erp::{ErpData, FourByteTelegram};
+    use crate::esp3::response_data::CoRdDutyCycleLimit;
+
+    fn four_byte(data: u32) -> Esp3Packet {
+        let telegram = ErpData::FourByte(FourByteTelegram {
+            data,
+            sender: 0x01020304,
+            status: 0x00,
+        });
+        RadioErp1Builder::new(telegram).build().unwrap()
+    }
+
+    fn enqueue(scheduler: &mut TransmitScheduler<4>, data: u32, priority: TransmitPriority) -> u32 {
+        scheduler.enqueue(four_byte(data), priority).unwrap()
+    }
+
+    #[test]
+    fn test_duty_cycle() {
+        // a 4BS telegram sent as three subtelegrams
+        assert_eq!(estimate_airtime_us(10, 3), 3744);
+
+        let mut tracker = DutyCycleTracker::new(10_000);
+        tracker.record(6_000, 0);
+        assert!(tracker.can_send(4_000, 1));
+        assert!(!tracker.can_send(4_001, 1));
+
+        // the airtime is released once the slot is older than the period
+        tracker.record(1_000, DutyCycleTracker::SLOT_PERIOD_MS);
+        assert_eq!(tracker.available_us(), 3_000);
+        assert_eq!(tracker.used_us(), 7_000);
+        tracker.advance(24 * DutyCycleTracker::SLOT_PERIOD_MS);
+        assert_eq!(tracker.used_us(), 1_000);
+        tracker.advance(25 * DutyCycleTracker::SLOT_PERIOD_MS);
+        assert_eq!(tracker.used_us(), 0);
+
+        tracker.update_from_device(&CoRdDutyCycleLimit {
+            available: 40,
+            slots: 24,
+            slot_period: 150,
+            actual_slot_left: 10,
+            load_after_actual: 15,
+        }, 0);
+        assert_eq!(tracker.available_us(), 4_000);
+        tracker.advance(10_000);
+        assert_eq!(tracker.available_us(), 5_500);
+
+        // the device divides the hour into ten slots of six minutes
+        tracker.update_from_device(&CoRdDutyCycleLimit {
+            available: 50,
+            slots: 10,
+            slot_period: 360,
+            actual_slot_left: 60,
+            load_after_actual: 20,
+        }, 0);
+        assert_eq!(tracker.slots(), 10);
+        assert_eq!(tracker.slot_period_ms(), 360_000);
+        assert_eq!(tracker.available_us(), 5_000);
+        tracker.advance(60_000);
+        assert_eq!(tracker.available_us(), 7_000);
+        tracker.advance(60_000 + 9 * 360_000);
+        assert_eq!(tracker.available_us(), 10_000);
+
+        // more slots than can be tracked are combined, keeping the period
+        tracker.update_from_device(&CoRdDutyCycleLimit {
+            available: 100,
+            slots: 48,
+            slot_period: 75,
+            actual_slot_left: 75,
+            load_after_actual: 0,
+        }, 0);
+        assert_eq!(tracker.slots(), DUTY_CYCLE_SLOTS);
+        assert_eq!(tracker.slot_period_ms(), 150_000);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_priority_and_backoff() {
+        let mut scheduler: TransmitScheduler<4> = TransmitScheduler::new(6_000, 1000);
+        let diagnostics_id = enqueue(&mut scheduler, 1, TransmitPriority::Low);
+        let setpoint_id = enqueue(&mut scheduler, 2, TransmitPriority::High);
+
+        assert_eq!(scheduler.poll_transmit(0), Some(four_byte(2)));
+        // waiting for the outcome
+        assert_eq!(scheduler.poll_transmit(1), None);
+
+        // the channel is busy: retry the setpoint after backing off
+        let busy = EventData::CoTransmitFailed { reason: TransmissionFailureReason::CsmaFailed };
+        assert_eq!(scheduler.handle_event(&busy, 10), None);
+        assert_eq!(scheduler.queued(), 2);
+        assert_eq!(scheduler.poll_transmit(59), None);
+        assert_eq!(scheduler.poll_transmit(60), Some(four_byte(2)));
+        assert_eq!(
+            scheduler.handle_event(&EventData::CoTxDone, 70),
+            Some(TransmitEvent { transmit_id: setpoint_id, result: TransmitResult::Sent }),
+        );
+
+        // only the attempt that has been sent uses up the budget (3744 µs)
+        assert_eq!(scheduler.duty_cycle().used_us(), 3744);
+        assert_eq!(scheduler.poll_transmit(80), None);
+
+        // the device tells us otherwise
+        scheduler.handle_duty_cycle_limit(&CoRdDutyCycleLimit {
+            available: 100,
+            slots: 24,
+            slot_period: 150,
+            actual_slot_left: 150,
+            load_after_actual: 0,
+        }, 90);
+        assert_eq!(scheduler.poll_transmit(100), Some(four_byte(1)));
+        assert_eq!(scheduler.poll_timeout(1099), None);
+        assert_eq!(
+            scheduler.poll_timeout(1100),
+            Some(TransmitEvent { transmit_id: diagnostics_id, result: TransmitResult::Unconfirmed }),
+        );
+        assert!(scheduler.is_idle());
+
+        let limit_reached = EventData::CoDutyCycleLimit { sending_possible: OneByteBoolean::No };
+        assert_eq!(scheduler.handle_event(&limit_reached, 1200), None);
+        enqueue(&mut scheduler, 3, TransmitPriority::Normal);
+        assert_eq!(scheduler.poll_transmit(1300), None);
+    }
+}
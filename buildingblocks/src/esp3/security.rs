@@ -0,0 +1,1174 @@
+//! Verification and decryption of secure EnOcean telegrams.
+//!
+//! Secure telegrams ([`ErpData::Secure`] and [`ErpData::SecureEncapsulated`]) carry encrypted data
+//! followed by an optional rolling code (RLC) and an optional message authentication code (MAC).
+//! Which of these are present, and how the data is encrypted, is described by the security level
+//! format (SLF) which is agreed upon during secure teach-in.
+//!
+//! Secure teach-in telegrams ([`ErpData::SecureTeachIn`]) can be reassembled using
+//! [`SecureTeachInReassembler`] and the resulting parameters stored in a [`SecureLinkTable`],
+//! which then decrypts the telegrams of the taught-in devices and keeps track of their rolling
+//! codes.
+//!
+//! Specified in _Security of EnOcean Radio Networks_.
+
+
+use crate::aes::{Aes128, BLOCK_LENGTH, KEY_LENGTH, xor_into};
+use crate::esp3::{CommandData, DirectionTable};
+use crate::esp3::erp::{
+    ErpData, MAXIMUM_SEC_DATA_LENGTH, MAXIMUM_SEC_TI_DATA_LENGTH, SecureTeachInTelegram,
+    SecureTelegram,
+};
+use crate::esp3::response_data::CoRdSecureDeviceV2ByIndex;
+use crate::max_array::MaxArray;
+use crate::max_array_ext::MaxArrayPushIntExt;
+
+
+/// The public key used by the VAES algorithm.
+pub const VAES_PUBLIC_KEY: [u8; BLOCK_LENGTH] = [
+    0x34, 0x10, 0xDE, 0x8F, 0x1A, 0xBA, 0x3E, 0xFF, 0x9F, 0x5A, 0x11, 0x71, 0x72, 0xEA, 0xCA, 0xBD,
+];
+
+/// The RORG value of a decrypted SEC telegram (SECD).
+pub const DECRYPTED_SECURE_RORG: u8 = 0x32;
+
+/// The length of the MAC appended to a secure teach-in protected by a pre-shared key.
+pub const PSK_TEACH_IN_MAC_LENGTH: usize = 4;
+
+/// The RORG value of a secure teach-in telegram (SEC_TI).
+const SECURE_TEACH_IN_RORG: u8 = 0x35;
+
+/// The default number of rolling code values that a receiver looks ahead.
+pub const DEFAULT_RLC_WINDOW: u32 = 128;
+
+
+/// The rolling code algorithm in use.
+#[derive(Clone, Copy, Debug)]
+#[from_to_repr::from_to_other(base_type = u8, derive_compare = "as_int")]
+pub enum RlcAlgorithm {
+    NoRlc = 0b00,
+    Rlc16 = 0b01,
+    Rlc24 = 0b10,
+    Rlc32 = 0b11,
+    Other(u8),
+}
+impl RlcAlgorithm {
+    /// The length of the rolling code in bytes.
+    pub fn rlc_length(&self) -> Option<usize> {
+        match self {
+            Self::NoRlc => Some(0),
+            Self::Rlc16 => Some(2),
+            Self::Rlc24 => Some(3),
+            Self::Rlc32 => Some(4),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Increments the rolling code by the given value, wrapping around at the rolling code's width.
+    pub fn advance(&self, rlc: u32, by: u32) -> u32 {
+        match self {
+            Self::Rlc16 => rlc.wrapping_add(by) & 0xFFFF,
+            Self::Rlc24 => rlc.wrapping_add(by) & 0xFF_FFFF,
+            Self::Rlc32 => rlc.wrapping_add(by),
+            _ => rlc,
+        }
+    }
+
+    /// Returns how far the rolling code has advanced from `from` to `to`, taking wrap-around into
+    /// account.
+    fn distance(&self, from: u32, to: u32) -> u32 {
+        match self {
+            Self::Rlc16 => to.wrapping_sub(from) & 0xFFFF,
+            Self::Rlc24 => to.wrapping_sub(from) & 0xFF_FFFF,
+            Self::Rlc32 => to.wrapping_sub(from),
+            _ => 0,
+        }
+    }
+}
+
+/// The message authentication code algorithm in use.
+#[derive(Clone, Copy, Debug)]
+#[from_to_repr::from_to_other(base_type = u8, derive_compare = "as_int")]
+pub enum MacAlgorithm {
+    NoMac = 0b00,
+    Mac3 = 0b01,
+    Mac4 = 0b10,
+    Other(u8),
+}
+impl MacAlgorithm {
+    /// The length of the MAC in bytes.
+    pub fn mac_length(&self) -> Option<usize> {
+        match self {
+            Self::NoMac => Some(0),
+            Self::Mac3 => Some(3),
+            Self::Mac4 => Some(4),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// The data encryption algorithm in use.
+#[derive(Clone, Copy, Debug)]
+#[from_to_repr::from_to_other(base_type = u8, derive_compare = "as_int")]
+pub enum DataEncryption {
+    NotEncrypted = 0b000,
+    Vaes = 0b011,
+    AesCbc = 0b100,
+    Other(u8),
+}
+
+/// The security level format (SLF) of a secure device.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SecurityLevelFormat {
+    pub rlc_algorithm: RlcAlgorithm,
+
+    /// Whether the rolling code is transmitted as part of each telegram.
+    pub rlc_transmitted: bool,
+
+    pub mac_algorithm: MacAlgorithm,
+    pub data_encryption: DataEncryption,
+}
+impl From<u8> for SecurityLevelFormat {
+    fn from(b: u8) -> Self {
+        Self {
+            rlc_algorithm: ((b & 0b1100_0000) >> 6).into(),
+            rlc_transmitted: (b & 0b0010_0000) != 0,
+            mac_algorithm: ((b & 0b0001_1000) >> 3).into(),
+            data_encryption: (b & 0b0000_0111).into(),
+        }
+    }
+}
+impl From<SecurityLevelFormat> for u8 {
+    fn from(slf: SecurityLevelFormat) -> Self {
+        let mut b = 0;
+        b |= (u8::from(slf.rlc_algorithm) & 0b11) << 6;
+        if slf.rlc_transmitted {
+            b |= 0b0010_0000;
+        }
+        b |= (u8::from(slf.mac_algorithm) & 0b11) << 3;
+        b |= u8::from(slf.data_encryption) & 0b111;
+        b
+    }
+}
+
+
+/// The reason why a secure telegram could not be processed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SecurityError {
+    /// The telegram is not a secure telegram.
+    NotSecure,
+
+    /// The security level format uses reserved values.
+    UnsupportedFormat,
+
+    /// The payload is too short to contain the rolling code and MAC.
+    PayloadTooShort,
+
+    /// The length of the data is not supported by the encryption algorithm.
+    InvalidDataLength,
+
+    /// No rolling code is known for the sender.
+    UnknownSender,
+
+    /// The rolling code has been used before or is too far ahead of the last known one.
+    RlcOutOfWindow,
+
+    /// The MAC does not match the contents of the telegram.
+    MacMismatch,
+
+    /// The decrypted data does not form a valid telegram.
+    InvalidContents,
+}
+
+
+/// Tracks the most recently accepted rolling code of up to `SENDERS` secure devices.
+#[derive(Clone, Debug)]
+pub struct RlcTracker<const SENDERS: usize> {
+    entries: [Option<(u32, u32)>; SENDERS],
+    window: u32,
+}
+impl<const SENDERS: usize> RlcTracker<SENDERS> {
+    const EMPTY_ENTRY: Option<(u32, u32)> = None;
+
+    /// Creates a new tracker that accepts rolling codes which are at most `window` steps ahead of
+    /// the last accepted one.
+    pub const fn new(window: u32) -> Self {
+        Self {
+            entries: [Self::EMPTY_ENTRY; SENDERS],
+            window,
+        }
+    }
+
+    /// The most recently accepted rolling code of the given sender.
+    pub fn last_rlc(&self, sender: u32) -> Option<u32> {
+        self.entries.iter()
+            .flatten()
+            .find(|(s, _)| *s == sender)
+            .map(|(_, rlc)| *rlc)
+    }
+
+    /// Sets the most recently accepted rolling code of the given sender, e.g. after teach-in or
+    /// when restoring persisted state. Returns `false` if the sender is not yet known and the
+    /// tracker is full.
+    pub fn set_rlc(&mut self, sender: u32, rlc: u32) -> bool {
+        if let Some(entry) = self.entries.iter_mut().flatten().find(|(s, _)| *s == sender) {
+            entry.1 = rlc;
+            return true;
+        }
+        if let Some(free) = self.entries.iter_mut().find(|e| e.is_none()) {
+            *free = Some((sender, rlc));
+            return true;
+        }
+        false
+    }
+
+    /// Stops tracking the given sender.
+    pub fn forget(&mut self, sender: u32) {
+        for entry in &mut self.entries {
+            if entry.map(|(s, _)| s == sender).unwrap_or(false) {
+                *entry = None;
+            }
+        }
+    }
+
+    /// Checks whether the rolling code is acceptable for the given sender, i.e. whether it is
+    /// ahead of the last accepted one by at most the window size.
+    pub fn is_in_window(&self, sender: u32, rlc: u32, algorithm: RlcAlgorithm) -> Result<(), SecurityError> {
+        let last = self.last_rlc(sender)
+            .ok_or(SecurityError::UnknownSender)?;
+        let distance = algorithm.distance(last, rlc);
+        if distance == 0 || distance > self.window {
+            Err(SecurityError::RlcOutOfWindow)
+        } else {
+            Ok(())
+        }
+    }
+}
+impl<const SENDERS: usize> Default for RlcTracker<SENDERS> {
+    fn default() -> Self { Self::new(DEFAULT_RLC_WINDOW) }
+}
+
+
+/// Encodes the rolling code into its big-endian representation of the given length.
+fn rlc_bytes(rlc: u32, rlc_length: usize) -> MaxArray<u8, 4> {
+    let mut ret = MaxArray::new();
+    for b in &rlc.to_be_bytes()[4-rlc_length..] {
+        ret.push(*b).unwrap();
+    }
+    ret
+}
+
+/// Calculates the truncated MAC over the RORG, the encrypted data and the rolling code.
+fn calculate_mac(cipher: &Aes128, rorg: u8, encrypted_data: &[u8], rlc: &[u8], mac_length: usize) -> MaxArray<u8, 4> {
+    let mut message: MaxArray<u8, {1 + MAXIMUM_SEC_DATA_LENGTH + 4}> = MaxArray::new();
+    message.push(rorg).unwrap();
+    for b in encrypted_data.iter().chain(rlc.iter()) {
+        message.push(*b).unwrap();
+    }
+    let cmac = cipher.cmac(message.as_slice());
+    MaxArray::from_iter_or_panic(cmac[0..mac_length].iter().copied().peekable())
+}
+
+/// Derives the block used as VAES input and as the AES-CBC initialization vector.
+fn rlc_block(rlc: &[u8]) -> [u8; BLOCK_LENGTH] {
+    let mut block = [0u8; BLOCK_LENGTH];
+    block[0..rlc.len()].copy_from_slice(rlc);
+    xor_into(&mut block, &VAES_PUBLIC_KEY);
+    block
+}
+
+/// Encrypts or decrypts the data in place.
+fn apply_cipher(cipher: &Aes128, encryption: DataEncryption, rlc: &[u8], data: &mut [u8], decrypt: bool) -> Result<(), SecurityError> {
+    match encryption {
+        DataEncryption::NotEncrypted => Ok(()),
+        DataEncryption::Vaes => {
+            // VAES is symmetric: the data is XORed with a key stream derived from the rolling code
+            if data.len() > BLOCK_LENGTH {
+                return Err(SecurityError::InvalidDataLength);
+            }
+            let mut key_stream = rlc_block(rlc);
+            cipher.encrypt_block(&mut key_stream);
+            for (d, k) in data.iter_mut().zip(key_stream.iter()) {
+                *d ^= *k;
+            }
+            Ok(())
+        },
+        DataEncryption::AesCbc => {
+            let iv = rlc_block(rlc);
+            let success = if decrypt {
+                cipher.cbc_decrypt(&iv, data)
+            } else {
+                cipher.cbc_encrypt(&iv, data)
+            };
+            if success {
+                Ok(())
+            } else {
+                Err(SecurityError::InvalidDataLength)
+            }
+        },
+        DataEncryption::Other(_) => Err(SecurityError::UnsupportedFormat),
+    }
+}
+
+
+/// Verifies and decrypts the payload of a secure telegram.
+///
+/// `rorg` is the RORG value of the secure telegram (0x30 or 0x31). On success, the rolling code of
+/// the telegram is recorded in `tracker` and the decrypted data is returned along with it.
+pub fn decrypt_payload<const SENDERS: usize>(
+    rorg: u8,
+    sender: u32,
+    payload: &[u8],
+    key: &[u8; KEY_LENGTH],
+    slf: SecurityLevelFormat,
+    tracker: &mut RlcTracker<SENDERS>,
+) -> Result<(u32, MaxArray<u8, MAXIMUM_SEC_DATA_LENGTH>), SecurityError> {
+    let rlc_length = slf.rlc_algorithm.rlc_length()
+        .ok_or(SecurityError::UnsupportedFormat)?;
+    let mac_length = slf.mac_algorithm.mac_length()
+        .ok_or(SecurityError::UnsupportedFormat)?;
+    let transmitted_rlc_length = if slf.rlc_transmitted { rlc_length } else { 0 };
+
+    if payload.len() < 1 + transmitted_rlc_length + mac_length {
+        return Err(SecurityError::PayloadTooShort);
+    }
+    let data_end = payload.len() - transmitted_rlc_length - mac_length;
+    let encrypted_data = &payload[0..data_end];
+    let transmitted_rlc = &payload[data_end..data_end+transmitted_rlc_length];
+    let mac = &payload[data_end+transmitted_rlc_length..];
+
+    let cipher = Aes128::new(key);
+
+    let rlc = if rlc_length == 0 {
+        0
+    } else if slf.rlc_transmitted {
+        let mut rlc_be = [0u8; 4];
+        rlc_be[4-rlc_length..].copy_from_slice(transmitted_rlc);
+        let rlc = u32::from_be_bytes(rlc_be);
+        tracker.is_in_window(sender, rlc, slf.rlc_algorithm)?;
+        rlc
+    } else {
+        // try each rolling code in the window until the MAC matches
+        let last = tracker.last_rlc(sender)
+            .ok_or(SecurityError::UnknownSender)?;
+        if mac_length == 0 {
+            // nothing to test against; assume the next one
+            slf.rlc_algorithm.advance(last, 1)
+        } else {
+            (1..=tracker.window)
+                .map(|i| slf.rlc_algorithm.advance(last, i))
+                .find(|candidate| {
+                    let candidate_bytes = rlc_bytes(*candidate, rlc_length);
+                    calculate_mac(&cipher, rorg, encrypted_data, candidate_bytes.as_slice(), mac_length).as_slice() == mac
+                })
+                .ok_or(SecurityError::MacMismatch)?
+        }
+    };
+
+    let rlc_encoded = rlc_bytes(rlc, rlc_length);
+    if mac_length > 0 {
+        let expected_mac = calculate_mac(&cipher, rorg, encrypted_data, rlc_encoded.as_slice(), mac_length);
+        if expected_mac.as_slice() != mac {
+            return Err(SecurityError::MacMismatch);
+        }
+    }
+
+    let mut data: MaxArray<u8, MAXIMUM_SEC_DATA_LENGTH> = MaxArray::from_iter_or_panic(
+        encrypted_data.iter().copied().peekable()
+    );
+    apply_cipher(&cipher, slf.data_encryption, rlc_encoded.as_slice(), data.as_mut_slice(), true)?;
+
+    if rlc_length > 0 {
+        tracker.set_rlc(sender, rlc);
+    }
+    Ok((rlc, data))
+}
+
+/// Encrypts data into the payload of a secure telegram with the given rolling code.
+///
+/// `rorg` is the RORG value of the secure telegram (0x30 or 0x31).
+pub fn encrypt_payload(
+    rorg: u8,
+    data: &[u8],
+    rlc: u32,
+    key: &[u8; KEY_LENGTH],
+    slf: SecurityLevelFormat,
+) -> Result<MaxArray<u8, MAXIMUM_SEC_DATA_LENGTH>, SecurityError> {
+    let rlc_length = slf.rlc_algorithm.rlc_length()
+        .ok_or(SecurityError::UnsupportedFormat)?;
+    let mac_length = slf.mac_algorithm.mac_length()
+        .ok_or(SecurityError::UnsupportedFormat)?;
+    let transmitted_rlc_length = if slf.rlc_transmitted { rlc_length } else { 0 };
+    if data.is_empty() || data.len() + transmitted_rlc_length + mac_length > MAXIMUM_SEC_DATA_LENGTH {
+        return Err(SecurityError::InvalidDataLength);
+    }
+
+    let cipher = Aes128::new(key);
+    let rlc_encoded = rlc_bytes(rlc, rlc_length);
+
+    let mut ret: MaxArray<u8, MAXIMUM_SEC_DATA_LENGTH> = MaxArray::from_iter_or_panic(
+        data.iter().copied().peekable()
+    );
+    apply_cipher(&cipher, slf.data_encryption, rlc_encoded.as_slice(), ret.as_mut_slice(), false)?;
+
+    let mac = calculate_mac(&cipher, rorg, ret.as_slice(), rlc_encoded.as_slice(), mac_length);
+    if slf.rlc_transmitted {
+        for b in rlc_encoded.iter() {
+            ret.push(*b).unwrap();
+        }
+    }
+    for b in mac.iter() {
+        ret.push(*b).unwrap();
+    }
+    Ok(ret)
+}
+
+/// Verifies and decrypts a secure telegram.
+///
+/// A SEC_ENCAPS telegram is turned into the telegram it encapsulates; a SEC telegram is turned into
+/// an [`ErpData::Other`] with the RORG [`DECRYPTED_SECURE_RORG`], whose data has to be interpreted
+/// according to the EEP announced during teach-in. In both cases, the sender ID and status byte of
+/// the secure telegram are retained.
+pub fn decrypt_telegram<const SENDERS: usize>(
+    telegram: &ErpData,
+    key: &[u8; KEY_LENGTH],
+    slf: SecurityLevelFormat,
+    tracker: &mut RlcTracker<SENDERS>,
+) -> Result<ErpData, SecurityError> {
+    let (rorg, secure): (u8, &SecureTelegram) = match telegram {
+        ErpData::Secure(st) => (0x30, st),
+        ErpData::SecureEncapsulated(st) => (0x31, st),
+        _ => return Err(SecurityError::NotSecure),
+    };
+
+    let (_rlc, data) = decrypt_payload(rorg, secure.sender, secure.payload.as_slice(), key, slf, tracker)?;
+
+    let mut inner: MaxArray<u8, {MAXIMUM_SEC_DATA_LENGTH + 5}> = MaxArray::new();
+    for b in data.iter() {
+        inner.push(*b).unwrap();
+    }
+    inner.push_u32_be(secure.sender).unwrap();
+    inner.push(secure.status).unwrap();
+
+    let decrypted = if rorg == 0x31 {
+        ErpData::from_slice(inner.as_slice())
+    } else {
+        ErpData::from_rorg_and_data(DECRYPTED_SECURE_RORG, inner.as_slice())
+    };
+    decrypted.ok_or(SecurityError::InvalidContents)
+}
+
+
+/// Converts a key from the representation used in ESP3 commands into bytes.
+fn key_from_words(words: [u32; 4]) -> [u8; KEY_LENGTH] {
+    let mut key = [0u8; KEY_LENGTH];
+    for (chunk, word) in key.chunks_exact_mut(4).zip(words.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    key
+}
+
+/// Converts a key into the representation used in ESP3 commands.
+fn key_to_words(key: &[u8; KEY_LENGTH]) -> [u32; 4] {
+    let mut words = [0u32; 4];
+    for (word, chunk) in words.iter_mut().zip(key.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// Encrypts or decrypts the rolling code and key of a PSK-protected teach-in in place.
+///
+/// The data is XORed with a VAES key stream generated using the pre-shared key. The rolling code
+/// is not known yet, so the first block of the key stream is derived from a rolling code of zero,
+/// i.e. it is the encrypted VAES public key; each further block is the encryption of the previous
+/// one.
+///
+/// Specified in _Security of EnOcean Radio Networks_, in the description of the teach-in protected
+/// by a pre-shared key.
+fn apply_psk_cipher(cipher: &Aes128, data: &mut [u8]) {
+    let mut key_stream = rlc_block(&[]);
+    for chunk in data.chunks_mut(BLOCK_LENGTH) {
+        cipher.encrypt_block(&mut key_stream);
+        for (d, k) in chunk.iter_mut().zip(key_stream.iter()) {
+            *d ^= *k;
+        }
+    }
+}
+
+/// Calculates the MAC of a PSK-protected teach-in over the RORG, the teach-in information (without
+/// index and count), the security level format and the encrypted rolling code and key.
+///
+/// Specified in _Security of EnOcean Radio Networks_, in the description of the teach-in protected
+/// by a pre-shared key.
+fn calculate_psk_teach_in_mac(cipher: &Aes128, teach_in_info: u8, data: &[u8]) -> [u8; PSK_TEACH_IN_MAC_LENGTH] {
+    let mut message: MaxArray<u8, {2 + MAXIMUM_SEC_TI_DATA_LENGTH}> = MaxArray::new();
+    message.push(SECURE_TEACH_IN_RORG).unwrap();
+    message.push(teach_in_info).unwrap();
+    for b in data {
+        message.push(*b).unwrap();
+    }
+    let cmac = cipher.cmac(message.as_slice());
+    cmac[0..PSK_TEACH_IN_MAC_LENGTH].try_into().unwrap()
+}
+
+
+/// A complete secure teach-in as reassembled from one or two SEC_TI telegrams.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SecureTeachInMessage {
+    /// The PSK flag, teach-in type and information bit; the index and count are not retained.
+    pub teach_in_info: u8,
+
+    /// The security level format, rolling code and key. If the teach-in is protected by a PSK, the
+    /// rolling code and key are encrypted and followed by the MAC.
+    pub data: MaxArray<u8, MAXIMUM_SEC_TI_DATA_LENGTH>,
+
+    pub sender: u32,
+    pub status: u8,
+}
+impl SecureTeachInMessage {
+    /// Whether the rolling code and key are encrypted using the pre-shared key of the device.
+    pub fn is_psk_encrypted(&self) -> bool {
+        (self.teach_in_info & 0b0000_1000) != 0
+    }
+
+    /// Extracts the security parameters from this teach-in.
+    ///
+    /// If the teach-in is PSK-encrypted, the pre-shared key of the device must be supplied; the MAC
+    /// is verified before the rolling code and key are decrypted.
+    pub fn decode(&self, opt_psk: Option<&[u8; KEY_LENGTH]>) -> Result<SecureTeachIn, SecurityError> {
+        let bytes = self.data.as_slice();
+        if bytes.is_empty() {
+            return Err(SecurityError::PayloadTooShort);
+        }
+        let slf = SecurityLevelFormat::from(bytes[0]);
+        let rlc_length = slf.rlc_algorithm.rlc_length()
+            .ok_or(SecurityError::UnsupportedFormat)?;
+        let mac_length = if self.is_psk_encrypted() { PSK_TEACH_IN_MAC_LENGTH } else { 0 };
+        if bytes.len() != 1 + rlc_length + KEY_LENGTH + mac_length {
+            return Err(SecurityError::PayloadTooShort);
+        }
+        let (bytes, mac) = bytes.split_at(bytes.len() - mac_length);
+
+        let mut secret = [0u8; 4 + KEY_LENGTH];
+        let secret = &mut secret[0..rlc_length + KEY_LENGTH];
+        secret.copy_from_slice(&bytes[1..]);
+        if self.is_psk_encrypted() {
+            let psk = opt_psk.ok_or(SecurityError::UnknownSender)?;
+            let cipher = Aes128::new(psk);
+            if calculate_psk_teach_in_mac(&cipher, self.teach_in_info, bytes) != mac {
+                return Err(SecurityError::MacMismatch);
+            }
+            apply_psk_cipher(&cipher, secret);
+        }
+
+        let mut rlc_be = [0u8; 4];
+        rlc_be[4-rlc_length..].copy_from_slice(&secret[0..rlc_length]);
+        Ok(SecureTeachIn {
+            sender: self.sender,
+            slf,
+            rolling_code: u32::from_be_bytes(rlc_be),
+            key: secret[rlc_length..].try_into().unwrap(),
+            teach_info: self.teach_in_info & 0b0000_0111,
+        })
+    }
+
+    /// Encodes the security parameters of a device as a secure teach-in.
+    ///
+    /// If a pre-shared key is supplied, the rolling code and key are encrypted using it and the MAC
+    /// is appended.
+    pub fn encode(teach_in: &SecureTeachIn, opt_psk: Option<&[u8; KEY_LENGTH]>, status: u8) -> Result<Self, SecurityError> {
+        let rlc_length = teach_in.slf.rlc_algorithm.rlc_length()
+            .ok_or(SecurityError::UnsupportedFormat)?;
+
+        let mut secret = [0u8; 4 + KEY_LENGTH];
+        let secret = &mut secret[0..rlc_length + KEY_LENGTH];
+        secret[0..rlc_length].copy_from_slice(rlc_bytes(teach_in.rolling_code, rlc_length).as_slice());
+        secret[rlc_length..].copy_from_slice(&teach_in.key);
+        let mut teach_in_info = teach_in.teach_info & 0b0000_0111;
+        let opt_cipher = opt_psk.map(Aes128::new);
+        if let Some(cipher) = &opt_cipher {
+            apply_psk_cipher(cipher, secret);
+            teach_in_info |= 0b0000_1000;
+        }
+
+        let mut data = MaxArray::new();
+        data.push(u8::from(teach_in.slf)).unwrap();
+        for b in secret.iter() {
+            data.push(*b).unwrap();
+        }
+        if let Some(cipher) = &opt_cipher {
+            let mac = calculate_psk_teach_in_mac(cipher, teach_in_info, data.as_slice());
+            for b in mac {
+                data.push(b).unwrap();
+            }
+        }
+        Ok(Self {
+            teach_in_info,
+            data,
+            sender: teach_in.sender,
+            status,
+        })
+    }
+
+    /// Splits this teach-in into SEC_TI telegrams, each carrying at most `max_data_length` bytes
+    /// following the teach-in information byte.
+    ///
+    /// Returns `None` if more than two telegrams would be required.
+    pub fn telegrams(&self, max_data_length: usize) -> Option<MaxArray<SecureTeachInTelegram, 2>> {
+        if max_data_length == 0 {
+            return None;
+        }
+        let count = self.data.len().div_ceil(max_data_length).max(1);
+        if count > 2 {
+            return None;
+        }
+
+        let mut ret = MaxArray::new();
+        for index in 0..count {
+            let start = index * max_data_length;
+            let end = (start + max_data_length).min(self.data.len());
+            let mut teach_in_info = ((index as u8) << 6) | (self.teach_in_info & 0b0000_1111);
+            if index == 0 {
+                teach_in_info |= (count as u8) << 4;
+            }
+            ret.push(SecureTeachInTelegram {
+                teach_in_info,
+                data: MaxArray::from_iter_or_panic(self.data.as_slice()[start..end].iter().copied().peekable()),
+                sender: self.sender,
+                status: self.status,
+            }).unwrap();
+        }
+        Some(ret)
+    }
+}
+
+/// Reassembles secure teach-ins that are split across two SEC_TI telegrams, keeping track of up
+/// to `SLOTS` senders at once.
+#[derive(Clone, Debug)]
+pub struct SecureTeachInReassembler<const SLOTS: usize> {
+    pending: [Option<SecureTeachInTelegram>; SLOTS],
+}
+impl<const SLOTS: usize> SecureTeachInReassembler<SLOTS> {
+    const EMPTY_SLOT: Option<SecureTeachInTelegram> = None;
+
+    /// Creates a new, empty reassembler.
+    pub const fn new() -> Self {
+        Self {
+            pending: [Self::EMPTY_SLOT; SLOTS],
+        }
+    }
+
+    /// Discards all partially received teach-ins.
+    pub fn clear(&mut self) {
+        for slot in &mut self.pending {
+            *slot = None;
+        }
+    }
+
+    /// Processes a SEC_TI telegram, returning the teach-in once it is complete.
+    ///
+    /// A first telegram from a sender replaces any incomplete teach-in from the same sender. If all
+    /// slots are occupied, the teach-in of another sender is discarded.
+    pub fn push(&mut self, telegram: &SecureTeachInTelegram) -> Option<SecureTeachInMessage> {
+        let slot_index = self.pending.iter()
+            .position(|p| p.as_ref().map(|t| t.sender == telegram.sender).unwrap_or(false));
+
+        if telegram.index() == 0 {
+            if telegram.count() <= 1 {
+                if let Some(i) = slot_index {
+                    self.pending[i] = None;
+                }
+                return Some(SecureTeachInMessage {
+                    teach_in_info: telegram.teach_in_info & 0b0000_1111,
+                    data: telegram.data.clone(),
+                    sender: telegram.sender,
+                    status: telegram.status,
+                });
+            }
+            if telegram.count() > 2 || SLOTS == 0 {
+                return None;
+            }
+
+            let i = slot_index
+                .or_else(|| self.pending.iter().position(|p| p.is_none()))
+                .unwrap_or(0);
+            self.pending[i] = Some(telegram.clone());
+            return None;
+        }
+
+        if telegram.index() != 1 {
+            return None;
+        }
+        let first = self.pending[slot_index?].take()?;
+        if first.data.len() + telegram.data.len() > MAXIMUM_SEC_TI_DATA_LENGTH {
+            return None;
+        }
+        let mut data = first.data.clone();
+        for b in telegram.data.iter() {
+            data.push(*b).unwrap();
+        }
+        Some(SecureTeachInMessage {
+            teach_in_info: first.teach_in_info & 0b0000_1111,
+            data,
+            sender: first.sender,
+            status: telegram.status,
+        })
+    }
+}
+impl<const SLOTS: usize> Default for SecureTeachInReassembler<SLOTS> {
+    fn default() -> Self { Self::new() }
+}
+
+
+/// The security parameters of a device, as announced during secure teach-in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SecureTeachIn {
+    pub sender: u32,
+    pub slf: SecurityLevelFormat,
+    pub rolling_code: u32,
+    pub key: [u8; KEY_LENGTH],
+
+    /// The teach-in type and information bit.
+    pub teach_info: u8,
+}
+
+
+/// An entry in the secure link table.
+///
+/// Mirrors the information returned by
+/// [`CoRdSecureDeviceV2ByIndex`](crate::esp3::response_data::CoRdSecureDeviceV2ByIndex).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SecureLinkTableEntry {
+    pub slf: SecurityLevelFormat,
+    pub device_id: u32,
+    pub private_key: [u8; KEY_LENGTH],
+    pub rolling_code: u32,
+    pub teach_info: u8,
+    pub opt_psk: Option<[u8; KEY_LENGTH]>,
+}
+impl SecureLinkTableEntry {
+    /// The length of an entry serialized with [`to_bytes`](Self::to_bytes) without a PSK.
+    pub const LENGTH: usize = 26;
+
+    /// The length of an entry serialized with [`to_bytes`](Self::to_bytes) with a PSK.
+    pub const LENGTH_WITH_PSK: usize = Self::LENGTH + KEY_LENGTH;
+
+    /// Creates an entry from a completed teach-in.
+    pub fn from_teach_in(teach_in: &SecureTeachIn, opt_psk: Option<[u8; KEY_LENGTH]>) -> Self {
+        Self {
+            slf: teach_in.slf,
+            device_id: teach_in.sender,
+            private_key: teach_in.key,
+            rolling_code: teach_in.rolling_code,
+            teach_info: teach_in.teach_info,
+            opt_psk,
+        }
+    }
+
+    /// Serializes this entry, e.g. to persist it across restarts.
+    ///
+    /// The layout matches the data and optional data of the response to
+    /// `CO_RD_SECUREDEVICE_V2_BY_INDEX`.
+    pub fn to_bytes(&self) -> MaxArray<u8, {Self::LENGTH_WITH_PSK}> {
+        let mut ret = MaxArray::new();
+        ret.push(u8::from(self.slf)).unwrap();
+        ret.push_u32_be(self.device_id).unwrap();
+        for b in &self.private_key {
+            ret.push(*b).unwrap();
+        }
+        ret.push_u32_be(self.rolling_code).unwrap();
+        ret.push(self.teach_info).unwrap();
+        if let Some(psk) = &self.opt_psk {
+            for b in psk {
+                ret.push(*b).unwrap();
+            }
+        }
+        ret
+    }
+
+    /// Deserializes an entry serialized using [`to_bytes`](Self::to_bytes).
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::LENGTH && bytes.len() != Self::LENGTH_WITH_PSK {
+            return None;
+        }
+        Some(Self {
+            slf: bytes[0].into(),
+            device_id: u32::from_be_bytes(bytes[1..5].try_into().unwrap()),
+            private_key: bytes[5..21].try_into().unwrap(),
+            rolling_code: u32::from_be_bytes(bytes[21..25].try_into().unwrap()),
+            teach_info: bytes[25],
+            opt_psk: (bytes.len() == Self::LENGTH_WITH_PSK)
+                .then(|| bytes[26..42].try_into().unwrap()),
+        })
+    }
+
+    /// Creates the command that stores this entry in the secure link table of an EnOcean module.
+    pub fn to_add_command(&self, opt_direction: Option<DirectionTable>) -> CommandData {
+        CommandData::CoWrSecureDeviceV2Add {
+            slf: self.slf.into(),
+            device_id: self.device_id,
+            private_key: key_to_words(&self.private_key),
+            rolling_code: self.rolling_code,
+            teach_info: self.teach_info,
+            opt_direction,
+        }
+    }
+}
+impl From<&CoRdSecureDeviceV2ByIndex> for SecureLinkTableEntry {
+    fn from(device: &CoRdSecureDeviceV2ByIndex) -> Self {
+        Self {
+            slf: device.slf.into(),
+            device_id: device.device_id,
+            private_key: key_from_words(device.private_key),
+            rolling_code: device.rolling_code,
+            teach_info: device.teach_info,
+            opt_psk: device.opt_psk.map(key_from_words),
+        }
+    }
+}
+
+/// A table of up to `ENTRIES` secure devices along with their keys and most recent rolling codes.
+///
+/// Rolling codes are updated whenever a telegram is successfully decrypted. To prevent replay
+/// attacks after a restart, the table should be persisted (see
+/// [`SecureLinkTableEntry::to_bytes`]) whenever [`take_changed`](Self::take_changed) returns
+/// `true`.
+#[derive(Clone, Debug)]
+pub struct SecureLinkTable<const ENTRIES: usize> {
+    entries: [Option<SecureLinkTableEntry>; ENTRIES],
+    window: u32,
+    changed: bool,
+}
+impl<const ENTRIES: usize> SecureLinkTable<ENTRIES> {
+    const EMPTY_ENTRY: Option<SecureLinkTableEntry> = None;
+
+    /// Creates a new, empty table that accepts rolling codes which are at most `window` steps
+    /// ahead of the last accepted one.
+    pub const fn new(window: u32) -> Self {
+        Self {
+            entries: [Self::EMPTY_ENTRY; ENTRIES],
+            window,
+            changed: false,
+        }
+    }
+
+    /// The entry of the given device.
+    pub fn get(&self, device_id: u32) -> Option<&SecureLinkTableEntry> {
+        self.entries.iter()
+            .flatten()
+            .find(|e| e.device_id == device_id)
+    }
+
+    /// Iterates over all entries in the table.
+    pub fn iter(&self) -> impl Iterator<Item = &SecureLinkTableEntry> {
+        self.entries.iter().flatten()
+    }
+
+    /// The number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Whether the table contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds an entry to the table, replacing any existing entry of the same device. Returns
+    /// `false` if the device is not yet known and the table is full.
+    pub fn insert(&mut self, entry: SecureLinkTableEntry) -> bool {
+        let slot = self.entries.iter()
+            .position(|e| e.map(|e| e.device_id == entry.device_id).unwrap_or(false))
+            .or_else(|| self.entries.iter().position(|e| e.is_none()));
+        match slot {
+            Some(i) => {
+                self.entries[i] = Some(entry);
+                self.changed = true;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Removes the entry of the given device, returning it if it existed.
+    pub fn remove(&mut self, device_id: u32) -> Option<SecureLinkTableEntry> {
+        let i = self.entries.iter()
+            .position(|e| e.map(|e| e.device_id == device_id).unwrap_or(false))?;
+        self.changed = true;
+        self.entries[i].take()
+    }
+
+    /// Returns whether the table has changed since the last call, and resets the flag.
+    pub fn take_changed(&mut self) -> bool {
+        let changed = self.changed;
+        self.changed = false;
+        changed
+    }
+
+    /// Verifies and decrypts a secure telegram using the key of its sender, updating the stored
+    /// rolling code on success.
+    ///
+    /// See [`decrypt_telegram`] for the form of the returned telegram.
+    pub fn decrypt(&mut self, telegram: &ErpData) -> Result<ErpData, SecurityError> {
+        let sender = match telegram {
+            ErpData::Secure(st) => st.sender,
+            ErpData::SecureEncapsulated(st) => st.sender,
+            _ => return Err(SecurityError::NotSecure),
+        };
+        let entry = self.entries.iter_mut()
+            .flatten()
+            .find(|e| e.device_id == sender)
+            .ok_or(SecurityError::UnknownSender)?;
+
+        let mut tracker: RlcTracker<1> = RlcTracker::new(self.window);
+        tracker.set_rlc(sender, entry.rolling_code);
+        let decrypted = decrypt_telegram(telegram, &entry.private_key, entry.slf, &mut tracker)?;
+
+        let rolling_code = tracker.last_rlc(sender).unwrap();
+        if rolling_code != entry.rolling_code {
+            entry.rolling_code = rolling_code;
+            self.changed = true;
+        }
+        Ok(decrypted)
+    }
+}
+impl<const ENTRIES: usize> Default for SecureLinkTable<ENTRIES> {
+    fn default() -> Self { Self::new(DEFAULT_RLC_WINDOW) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DataEncryption, MacAlgorithm, PSK_TEACH_IN_MAC_LENGTH, RlcAlgorithm, RlcTracker, SecureLinkTable, SecureLinkTableEntry,
+        SecureTeachIn, SecureTeachInMessage, SecureTeachInReassembler, SecurityError,
+        SecurityLevelFormat, decrypt_payload, decrypt_telegram, encrypt_payload,
+    };
+    use crate::esp3::erp::{ErpData, FourByteTelegram, SecureTelegram};
+    use crate::esp3::response_data::CoRdSecureDeviceV2ByIndex;
+    use crate::max_array::MaxArray;
+
+    const KEY: [u8; 16] = [
+        0x86, 0x9F, 0xAB, 0x7D, 0x29, 0x6C, 0x9E, 0x48, 0xCE, 0xBF, 0xF3, 0x4D, 0xF6, 0x37, 0x35, 0x8A,
+    ];
+
+    #[test]
+    fn test_slf() {
+        // 24-bit RLC, not transmitted, 3-byte MAC, VAES
+        let slf = SecurityLevelFormat::from(0x8B);
+        assert_eq!(slf.rlc_algorithm, RlcAlgorithm::Rlc24);
+        assert!(!slf.rlc_transmitted);
+        assert_eq!(slf.mac_algorithm, MacAlgorithm::Mac3);
+        assert_eq!(slf.data_encryption, DataEncryption::Vaes);
+        assert_eq!(u8::from(slf), 0x8B);
+    }
+
+    #[test]
+    fn test_round_trip_transmitted_rlc() {
+        // 16-bit RLC, transmitted, 4-byte MAC, VAES
+        let slf = SecurityLevelFormat::from(0x73);
+        let payload = encrypt_payload(0x30, &[0x01, 0x02, 0x03, 0x04], 0x0010, &KEY, slf).unwrap();
+        assert_eq!(payload.len(), 4 + 2 + 4);
+        assert_ne!(&payload.as_slice()[0..4], &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(&payload.as_slice()[4..6], &[0x00, 0x10]);
+
+        let mut tracker: RlcTracker<4> = RlcTracker::new(16);
+        assert_eq!(
+            decrypt_payload(0x30, 0x01020304, payload.as_slice(), &KEY, slf, &mut tracker),
+            Err(SecurityError::UnknownSender),
+        );
+
+        tracker.set_rlc(0x01020304, 0x000F);
+        let (rlc, data) = decrypt_payload(0x30, 0x01020304, payload.as_slice(), &KEY, slf, &mut tracker).unwrap();
+        assert_eq!(rlc, 0x0010);
+        assert_eq!(data.as_slice(), &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(tracker.last_rlc(0x01020304), Some(0x0010));
+
+        // replay
+        assert_eq!(
+            decrypt_payload(0x30, 0x01020304, payload.as_slice(), &KEY, slf, &mut tracker),
+            Err(SecurityError::RlcOutOfWindow),
+        );
+
+        // tampering
+        let mut tampered = payload.clone();
+        tampered.as_mut_slice()[0] ^= 0x01;
+        tracker.set_rlc(0x01020304, 0x000F);
+        assert_eq!(
+            decrypt_payload(0x30, 0x01020304, tampered.as_slice(), &KEY, slf, &mut tracker),
+            Err(SecurityError::MacMismatch),
+        );
+    }
+
+    #[test]
+    fn test_rlc_window_wraps() {
+        // 16-bit RLC, transmitted, 3-byte MAC, not encrypted
+        let slf = SecurityLevelFormat::from(0x68);
+        let payload = encrypt_payload(0x30, &[0x42], 0x0002, &KEY, slf).unwrap();
+        assert_eq!(payload.as_slice()[0], 0x42);
+
+        let mut tracker: RlcTracker<1> = RlcTracker::new(8);
+        tracker.set_rlc(0x01020304, 0xFFFE);
+        assert!(decrypt_payload(0x30, 0x01020304, payload.as_slice(), &KEY, slf, &mut tracker).is_ok());
+
+        // too far ahead
+        tracker.set_rlc(0x01020304, 0xFF00);
+        assert_eq!(
+            decrypt_payload(0x30, 0x01020304, payload.as_slice(), &KEY, slf, &mut tracker),
+            Err(SecurityError::RlcOutOfWindow),
+        );
+
+        // the tracker is full
+        assert!(!tracker.set_rlc(0x05060708, 0x0000));
+    }
+
+    #[test]
+    fn test_encapsulated_hidden_rlc() {
+        // 24-bit RLC, not transmitted, 3-byte MAC, VAES
+        let slf = SecurityLevelFormat::from(0x8B);
+        let inner = [0xA5, 0x00, 0x00, 0x55, 0x08];
+        let payload = encrypt_payload(0x31, &inner, 0x000105, &KEY, slf).unwrap();
+        assert_eq!(payload.len(), 5 + 3);
+
+        let telegram = ErpData::SecureEncapsulated(SecureTelegram {
+            payload: MaxArray::from_iter_or_panic(payload.iter().copied().peekable()),
+            sender: 0x01020304,
+            status: 0x00,
+        });
+
+        let mut tracker: RlcTracker<4> = RlcTracker::new(16);
+        tracker.set_rlc(0x01020304, 0x000100);
+        let decrypted = decrypt_telegram(&telegram, &KEY, slf, &mut tracker).unwrap();
+        assert_eq!(
+            decrypted,
+            ErpData::FourByte(FourByteTelegram {
+                data: 0x00005508,
+                sender: 0x01020304,
+                status: 0x00,
+            }),
+        );
+        assert_eq!(tracker.last_rlc(0x01020304), Some(0x000105));
+
+        // the same RLC cannot be found again
+        assert_eq!(
+            decrypt_telegram(&telegram, &KEY, slf, &mut tracker),
+            Err(SecurityError::MacMismatch),
+        );
+    }
+
+    #[test]
+    fn test_teach_in_reassembly() {
+        let psk = [0x5Au8; 16];
+        let teach_in = SecureTeachIn {
+            sender: 0x01020304,
+            slf: SecurityLevelFormat::from(0x8B),
+            rolling_code: 0x000100,
+            key: KEY,
+            teach_info: 0b001,
+        };
+        let message = SecureTeachInMessage::encode(&teach_in, Some(&psk), 0x00).unwrap();
+        assert!(message.is_psk_encrypted());
+        assert_eq!(message.data.len(), 1 + 3 + 16 + PSK_TEACH_IN_MAC_LENGTH);
+        assert_ne!(&message.data.as_slice()[4..20], &KEY);
+
+        let telegrams = message.telegrams(12).unwrap();
+        assert_eq!(telegrams.len(), 2);
+        assert_eq!(telegrams.as_slice()[0].index(), 0);
+        assert_eq!(telegrams.as_slice()[0].count(), 2);
+        assert_eq!(telegrams.as_slice()[1].index(), 1);
+        assert!(message.telegrams(11).is_none());
+
+        let mut reassembler: SecureTeachInReassembler<2> = SecureTeachInReassembler::new();
+        // the second part alone is ignored
+        assert_eq!(reassembler.push(&telegrams.as_slice()[1]), None);
+        assert_eq!(reassembler.push(&telegrams.as_slice()[0]), None);
+        let reassembled = reassembler.push(&telegrams.as_slice()[1]).unwrap();
+        assert_eq!(reassembled, message);
+
+        assert_eq!(reassembled.decode(None), Err(SecurityError::UnknownSender));
+        assert_eq!(reassembled.decode(Some(&psk)), Ok(teach_in));
+
+        // unencrypted single-telegram teach-in
+        let plain = SecureTeachInMessage::encode(&teach_in, None, 0x00).unwrap();
+        let single = plain.telegrams(21).unwrap();
+        assert_eq!(single.len(), 1);
+        let reassembled = reassembler.push(&single.as_slice()[0]).unwrap();
+        assert_eq!(reassembled.decode(None), Ok(teach_in));
+    }
+
+    #[test]
+    fn test_psk_teach_in_mac() {
+        let psk = [0x5Au8; 16];
+        let teach_in = SecureTeachIn {
+            sender: 0x01020304,
+            slf: SecurityLevelFormat::from(0x8B),
+            rolling_code: 0x000100,
+            key: KEY,
+            teach_info: 0b001,
+        };
+        let message = SecureTeachInMessage::encode(&teach_in, Some(&psk), 0x00).unwrap();
+        assert_eq!(message.teach_in_info, 0b0000_1001);
+        assert_eq!(message.decode(Some(&psk)), Ok(teach_in));
+
+        // a tampered key or a wrong PSK is detected by the MAC
+        let mut tampered = message.clone();
+        tampered.data.as_mut_slice()[10] ^= 0x01;
+        assert_eq!(tampered.decode(Some(&psk)), Err(SecurityError::MacMismatch));
+        assert_eq!(message.decode(Some(&[0xA5; 16])), Err(SecurityError::MacMismatch));
+    }
+
+    #[test]
+    fn test_secure_link_table() {
+        // 24-bit RLC, not transmitted, 3-byte MAC, VAES
+        let slf = SecurityLevelFormat::from(0x8B);
+        let teach_in = SecureTeachIn {
+            sender: 0x01020304,
+            slf,
+            rolling_code: 0x000100,
+            key: KEY,
+            teach_info: 0,
+        };
+
+        let mut table: SecureLinkTable<2> = SecureLinkTable::new(16);
+        assert!(table.insert(SecureLinkTableEntry::from_teach_in(&teach_in, None)));
+        assert!(table.take_changed());
+        assert!(!table.take_changed());
+
+        let payload = encrypt_payload(0x31, &[0xA5, 0x00, 0x00, 0x55, 0x08], 0x000102, &KEY, slf).unwrap();
+        let telegram = ErpData::SecureEncapsulated(SecureTelegram {
+            payload: MaxArray::from_iter_or_panic(payload.iter().copied().peekable()),
+            sender: 0x01020304,
+            status: 0x00,
+        });
+        assert!(table.decrypt(&telegram).is_ok());
+        assert_eq!(table.get(0x01020304).unwrap().rolling_code, 0x000102);
+        assert!(table.take_changed());
+        assert_eq!(table.decrypt(&telegram), Err(SecurityError::MacMismatch));
+
+        let mut unknown = telegram.clone();
+        if let ErpData::SecureEncapsulated(st) = &mut unknown {
+            st.sender = 0x05060708;
+        }
+        assert_eq!(table.decrypt(&unknown), Err(SecurityError::UnknownSender));
+
+        // persistence
+        let entry = *table.get(0x01020304).unwrap();
+        let bytes = entry.to_bytes();
+        assert_eq!(bytes.len(), SecureLinkTableEntry::LENGTH);
+        assert_eq!(SecureLinkTableEntry::from_slice(bytes.as_slice()), Some(entry));
+
+        let device = CoRdSecureDeviceV2ByIndex {
+            slf: 0x8B,
+            device_id: 0x01020304,
+            private_key: [0x869FAB7D, 0x296C9E48, 0xCEBFF34D, 0xF637358A],
+            rolling_code: 0x000102,
+            teach_info: 0,
+            opt_psk: None,
+        };
+        assert_eq!(SecureLinkTableEntry::from(&device), entry);
+
+        assert_eq!(table.remove(0x01020304), Some(entry));
+        assert!(table.is_empty());
+    }
+}
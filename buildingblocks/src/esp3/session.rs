@@ -0,0 +1,368 @@
+//! Correlation of ESP3 commands with their responses.
+//!
+//! An ESP3 device processes one command at a time, and its responses do not refer back to the
+//! command that caused them. [`Esp3Session`] queues commands, hands them out one at a time for
+//! transmission and pairs each incoming response with the outstanding command. It performs no I/O
+//! itself: the caller transmits the packets it returns, feeds it the packets it receives and
+//! supplies the current time as a wrapping millisecond counter.
+
+
+use crate::esp3::{Command24Data, CommandData, Esp3Packet, Esp3PacketRef, ReturnCode, SmartAckData};
+use crate::esp3::response_data::{Command24Response, CommonCommandResponse, SmartAckResponse};
+use crate::max_array::MaxArray;
+
+
+/// The time, in milliseconds, within which an ESP3 device is expected to respond to a command.
+pub const DEFAULT_RESPONSE_TIMEOUT_MS: u32 = 500;
+
+
+/// A command that is answered by a response packet.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Esp3Request {
+    Common(CommandData),
+    SmartAck(SmartAckData),
+    Command24(Command24Data),
+}
+impl Esp3Request {
+    /// The command code of this request.
+    pub fn command_code(&self) -> u8 {
+        match self {
+            Self::Common(c) => c.command_type(),
+            Self::SmartAck(c) => c.command_type(),
+            Self::Command24(c) => c.command_type(),
+        }
+    }
+
+    /// The packet that transmits this request.
+    pub fn to_packet(&self) -> Esp3Packet {
+        match self {
+            Self::Common(c) => Esp3Packet::CommonCommand(c.clone()),
+            Self::SmartAck(c) => Esp3Packet::SmartAckCommand(c.clone()),
+            Self::Command24(c) => Esp3Packet::Command24(c.clone()),
+        }
+    }
+}
+impl From<CommandData> for Esp3Request {
+    fn from(c: CommandData) -> Self { Self::Common(c) }
+}
+impl From<SmartAckData> for Esp3Request {
+    fn from(c: SmartAckData) -> Self { Self::SmartAck(c) }
+}
+impl From<Command24Data> for Esp3Request {
+    fn from(c: Command24Data) -> Self { Self::Command24(c) }
+}
+
+/// The contents of a successful response, decoded according to the request it answers and
+/// borrowed from the response packet.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DecodedResponse<'a> {
+    /// The response carries no data.
+    Empty,
+
+    /// The response to a common command.
+    Common(CommonCommandResponse<'a>),
+
+    /// The response to a Smart Ack command.
+    SmartAck(SmartAckResponse<'a>),
+
+    /// The response to a 2.4 GHz command.
+    Command24(Command24Response),
+
+    /// The response carries data that cannot be decoded for the request.
+    Undecoded {
+        data: &'a [u8],
+        optional_data: &'a [u8],
+    },
+}
+impl<'a> DecodedResponse<'a> {
+    /// Decodes the data of a successful response to the given request.
+    pub fn decode_for_request(request: &Esp3Request, data_slice: &'a [u8], optional_slice: &'a [u8]) -> Self {
+        if data_slice.is_empty() && optional_slice.is_empty() {
+            return Self::Empty;
+        }
+
+        let decoded = match request {
+            Esp3Request::Common(c) => CommonCommandResponse::decode_for_command(c.command_type(), data_slice, optional_slice)
+                .map(Self::Common),
+            Esp3Request::SmartAck(c) => SmartAckResponse::decode_for_command(c.command_type(), data_slice, optional_slice)
+                .map(Self::SmartAck),
+            Esp3Request::Command24(c) => Command24Response::decode_for_command(c.command_type(), data_slice, optional_slice)
+                .map(Self::Command24),
+        };
+        decoded.unwrap_or(Self::Undecoded {
+            data: data_slice,
+            optional_data: optional_slice,
+        })
+    }
+}
+
+/// The reason why a request has failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SessionError {
+    /// The device responded with a return code other than [`ReturnCode::Ok`].
+    ReturnCode(ReturnCode),
+
+    /// The device did not respond in time.
+    TimedOut,
+}
+
+/// The outcome of a request, borrowing the response data from the response packet.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SessionEvent<'a> {
+    /// The identifier returned by [`Esp3Session::enqueue`].
+    pub request_id: u32,
+
+    pub request: Esp3Request,
+    pub result: Result<DecodedResponse<'a>, SessionError>,
+}
+
+
+/// The request that has been transmitted and is awaiting a response.
+#[derive(Clone, Debug)]
+struct Outstanding {
+    request_id: u32,
+    request: Esp3Request,
+    since_ms: u32,
+    wait_ms: u32,
+}
+
+
+/// Queues up to `QUEUE` requests and correlates them with their responses.
+#[derive(Debug)]
+pub struct Esp3Session<const QUEUE: usize> {
+    queue: MaxArray<(u32, Esp3Request), QUEUE>,
+    outstanding: Option<Outstanding>,
+    next_request_id: u32,
+    timeout_ms: u32,
+}
+impl<const QUEUE: usize> Esp3Session<QUEUE> {
+    /// Creates a new session that considers a request failed if no response has arrived within
+    /// `timeout_ms` milliseconds.
+    pub const fn new(timeout_ms: u32) -> Self {
+        Self {
+            queue: MaxArray::new(),
+            outstanding: None,
+            next_request_id: 0,
+            timeout_ms,
+        }
+    }
+
+    /// Adds a request to the queue, returning an identifier that is reported along with its
+    /// outcome. If the queue is full, the request is returned.
+    #[allow(clippy::result_large_err)]
+    pub fn enqueue<R: Into<Esp3Request>>(&mut self, request: R) -> Result<u32, Esp3Request> {
+        let request_id = self.next_request_id;
+        if let Err((_, request)) = self.queue.push((request_id, request.into())) {
+            return Err(request);
+        }
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        Ok(request_id)
+    }
+
+    /// The number of requests that have not been transmitted yet.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// The request that has been transmitted and is awaiting a response.
+    pub fn outstanding(&self) -> Option<&Esp3Request> {
+        self.outstanding.as_ref().map(|o| &o.request)
+    }
+
+    /// Whether no requests are queued or outstanding.
+    pub fn is_idle(&self) -> bool {
+        self.queue.len() == 0 && self.outstanding.is_none()
+    }
+
+    /// Discards all queued requests and forgets the outstanding one, e.g. after the device has
+    /// been reset.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.outstanding = None;
+    }
+
+    /// Returns the next packet to transmit, if no request is outstanding.
+    pub fn poll_transmit(&mut self, now_ms: u32) -> Option<Esp3Packet> {
+        if self.outstanding.is_some() || self.queue.len() == 0 {
+            return None;
+        }
+
+        // take the oldest request
+        self.queue.as_mut_slice().rotate_left(1);
+        let (request_id, request) = self.queue.pop().unwrap();
+        self.outstanding = Some(Outstanding {
+            request_id,
+            request,
+            since_ms: now_ms,
+            wait_ms: self.timeout_ms,
+        });
+        self.outstanding.as_ref().map(|o| o.request.to_packet())
+    }
+
+    /// Processes a received packet.
+    ///
+    /// Returns the outcome of the outstanding request if the packet is its response. Packets that
+    /// are not responses, as well as responses for which no request is outstanding, are ignored.
+    pub fn handle_packet<'a>(&mut self, packet: &'a Esp3Packet, now_ms: u32) -> Option<SessionEvent<'a>> {
+        match packet {
+            Esp3Packet::Response { return_code, response_data, optional_data } => {
+                let response = Esp3PacketRef::Response {
+                    return_code: *return_code,
+                    response_data: response_data.as_slice(),
+                    optional_data: optional_data.as_slice(),
+                };
+                self.handle_packet_ref(&response, now_ms)
+            },
+            Esp3Packet::CommandAccepted { is_blocking, estimated_time_ms } => {
+                let accepted = Esp3PacketRef::CommandAccepted {
+                    is_blocking: *is_blocking,
+                    estimated_time_ms: *estimated_time_ms,
+                };
+                self.handle_packet_ref(&accepted, now_ms)
+            },
+            _ => None,
+        }
+    }
+
+    /// Processes a received packet that is borrowed from the receive buffer.
+    ///
+    /// Behaves like [`Esp3Session::handle_packet`]; the response data is decoded in place.
+    pub fn handle_packet_ref<'a>(&mut self, packet: &Esp3PacketRef<'a>, now_ms: u32) -> Option<SessionEvent<'a>> {
+        match packet {
+            Esp3PacketRef::Response { return_code, response_data, optional_data } => {
+                let outstanding = self.outstanding.take()?;
+                let result = if *return_code == ReturnCode::Ok {
+                    Ok(DecodedResponse::decode_for_request(
+                        &outstanding.request,
+                        response_data,
+                        optional_data,
+                    ))
+                } else {
+                    Err(SessionError::ReturnCode(*return_code))
+                };
+                Some(SessionEvent {
+                    request_id: outstanding.request_id,
+                    request: outstanding.request,
+                    result,
+                })
+            },
+            Esp3PacketRef::CommandAccepted { estimated_time_ms, .. } => {
+                // the response will take longer; wait for it
+                if let Some(outstanding) = &mut self.outstanding {
+                    outstanding.since_ms = now_ms;
+                    outstanding.wait_ms = self.timeout_ms.saturating_add((*estimated_time_ms).into());
+                }
+                None
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks whether the outstanding request has timed out, returning its outcome if so.
+    pub fn poll_timeout(&mut self, now_ms: u32) -> Option<SessionEvent<'static>> {
+        let outstanding = self.outstanding.as_ref()?;
+        if now_ms.wrapping_sub(outstanding.since_ms) < outstanding.wait_ms {
+            return None;
+        }
+
+        let outstanding = self.outstanding.take().unwrap();
+        Some(SessionEvent {
+            request_id: outstanding.request_id,
+            request: outstanding.request,
+            result: Err(SessionError::TimedOut),
+        })
+    }
+}
+impl<const QUEUE: usize> Default for Esp3Session<QUEUE> {
+    fn default() -> Self { Self::new(DEFAULT_RESPONSE_TIMEOUT_MS) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodedResponse, Esp3Request, Esp3Session, SessionError};
+    use crate::esp3::{Command24Data, CommandData, Esp3Packet, Esp3PacketRef, ReturnCode, SmartAckData};
+    use crate::esp3::response_data::{
+        CoRdIdBase, Command24Response, CommonCommandResponse, R802RdChannel,
+    };
+
+    fn response(return_code: ReturnCode, data: &[u8]) -> Esp3PacketRef<'_> {
+        Esp3PacketRef::Response {
+            return_code,
+            response_data: data,
+            optional_data: &[],
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "full_esp3_packet", ignore = "owned packets overflow the stack of a test thread")]
+    fn test_request_response() {
+        let mut session: Esp3Session<2> = Esp3Session::new(100);
+        assert!(session.is_idle());
+        assert_eq!(session.poll_transmit(0), None);
+
+        let id_base = session.enqueue(CommandData::CoRdIdBase).unwrap();
+        let learn_mode = session.enqueue(SmartAckData::SaRdLearnMode).unwrap();
+        assert_eq!(
+            session.enqueue(CommandData::CoRdVersion),
+            Err(Esp3Request::Common(CommandData::CoRdVersion)),
+        );
+
+        // unsolicited response
+        assert_eq!(session.handle_packet_ref(&response(ReturnCode::Ok, &[]), 0), None);
+
+        assert_eq!(session.poll_transmit(0), Some(Esp3Packet::CommonCommand(CommandData::CoRdIdBase)));
+        // only one request at a time
+        assert_eq!(session.poll_transmit(0), None);
+
+        let event = session.handle_packet_ref(&response(ReturnCode::Ok, &[0xFF, 0x80, 0x00, 0x00]), 10).unwrap();
+        assert_eq!(event.request_id, id_base);
+        assert_eq!(
+            event.result,
+            Ok(DecodedResponse::Common(CommonCommandResponse::RdIdBase(CoRdIdBase {
+                base_id: 0xFF800000,
+                opt_remaining_write_cycles: None,
+            }))),
+        );
+
+        assert_eq!(session.poll_transmit(10), Some(Esp3Packet::SmartAckCommand(SmartAckData::SaRdLearnMode)));
+        let event = session.handle_packet_ref(&response(ReturnCode::NotSupported, &[]), 20).unwrap();
+        assert_eq!(event.request_id, learn_mode);
+        assert_eq!(event.result, Err(SessionError::ReturnCode(ReturnCode::NotSupported)));
+        assert!(session.is_idle());
+
+        session.enqueue(Command24Data::ReadChannel).unwrap();
+        assert!(session.poll_transmit(30).is_some());
+        let event = session.handle_packet_ref(&response(ReturnCode::Ok, &[0x0B]), 40).unwrap();
+        assert_eq!(
+            event.result,
+            Ok(DecodedResponse::Command24(Command24Response::RdChannel(R802RdChannel {
+                channel: 0x0B,
+            }))),
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut session: Esp3Session<2> = Esp3Session::new(100);
+        session.enqueue(CommandData::CoWrReset).unwrap();
+        assert!(session.poll_transmit(u32::MAX - 10).is_some());
+        assert_eq!(session.poll_timeout(50), None);
+
+        // the device needs more time
+        let accepted = Esp3PacketRef::CommandAccepted {
+            is_blocking: true.into(),
+            estimated_time_ms: 1000,
+        };
+        assert_eq!(session.handle_packet_ref(&accepted, 80), None);
+        assert_eq!(session.poll_timeout(1000), None);
+
+        let event = session.poll_timeout(1180).unwrap();
+        assert_eq!(event.request, Esp3Request::Common(CommandData::CoWrReset));
+        assert_eq!(event.result, Err(SessionError::TimedOut));
+        assert!(session.is_idle());
+
+        // a late response is ignored
+        assert_eq!(session.handle_packet_ref(&response(ReturnCode::Ok, &[]), 1200), None);
+    }
+}
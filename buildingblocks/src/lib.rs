@@ -5,6 +5,7 @@
 #![cfg_attr(not(test), no_std)]
 
 
+pub mod aes;
 pub mod bit_field;
 pub mod crc8;
 pub mod esp3;
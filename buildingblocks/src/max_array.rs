@@ -13,11 +13,8 @@ pub struct MaxArray<T, const MAX_SIZE: usize> {
 impl<T, const MAX_SIZE: usize> MaxArray<T, MAX_SIZE> {
     /// Creates a new, empty `MaxArray`.
     pub const fn new() -> Self {
-        let buf = unsafe {
-            MaybeUninit::<[MaybeUninit<T>; MAX_SIZE]>::uninit().assume_init()
-        };
         Self {
-            array: buf,
+            array: [const { MaybeUninit::uninit() }; MAX_SIZE],
             length: 0,
         }
     }
@@ -41,13 +38,13 @@ impl<T, const MAX_SIZE: usize> MaxArray<T, MAX_SIZE> {
     /// Appends a single element to the end of the array. Returns `Ok(())` if there was enough space
     /// for the element and `Err(val)` (where `val` is the element to be appended) if not.
     pub fn push(&mut self, val: T) -> Result<(), T> {
-        if self.length < self.array.len() {
-            self.array[self.length] = MaybeUninit::new(val);
-            self.length += 1;
-            Ok(())
-        } else {
-            Err(val)
+        if self.length == MAX_SIZE {
+            return Err(val);
         }
+        // write in place; unoptimized builds would otherwise copy large elements around
+        self.array[self.length].write(val);
+        self.length += 1;
+        Ok(())
     }
 
     /// Removes a single item from the end of the array. Returns `Some(element)` if the array
@@ -62,6 +59,12 @@ impl<T, const MAX_SIZE: usize> MaxArray<T, MAX_SIZE> {
         }
     }
 
+    /// Removes all elements from the array.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {
+        }
+    }
+
     /// Returns an iterator that iterates over references to the elements currently contained in the
     /// MaxArray.
     pub fn iter(&self) -> Iter<T, MAX_SIZE> {
@@ -213,6 +216,17 @@ impl<T: Hash, const MAX_SIZE: usize> Hash for MaxArray<T, MAX_SIZE> {
         }
     }
 }
+impl<const MAX_SIZE: usize> fmt::Write for MaxArray<u8, MAX_SIZE> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.can_fit(s.len()) {
+            return Err(fmt::Error);
+        }
+        for b in s.bytes() {
+            self.push(b).unwrap();
+        }
+        Ok(())
+    }
+}
 impl<T: fmt::Debug, const MAX_SIZE: usize> fmt::Debug for MaxArray<T, MAX_SIZE> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("MaxArray")
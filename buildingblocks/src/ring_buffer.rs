@@ -113,6 +113,29 @@ impl<T: Copy + Default, const SIZE: usize> RingBuffer<T, SIZE> {
         true
     }
 
+    /// Removes up to `count` values from the beginning of the buffer. Returns the number of values
+    /// that have actually been removed.
+    #[inline]
+    pub fn discard(&mut self, count: usize) -> usize {
+        let discarded = count.min(self.count_set);
+        for _ in 0..discarded {
+            self.pop();
+        }
+        discarded
+    }
+
+    /// Rearranges the buffer so that its elements are stored contiguously, then returns them as a
+    /// slice, oldest element first.
+    pub fn make_contiguous(&mut self) -> &[T] {
+        if self.first_set + self.count_set > SIZE {
+            // the elements wrap around; move the first one to the start of the buffer
+            self.buffer.rotate_left(self.first_set);
+            self.first_set = 0;
+        }
+        let occupied = &self.buffer[self.first_set..self.first_set+self.count_set];
+        unsafe { &*(occupied as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
     /// Obtains an iterator that runs through the buffer and returns copies of each element.
     #[inline]
     pub fn iter(&self) -> Iter<T, SIZE> {
@@ -356,4 +379,39 @@ mod tests {
             assert_eq!(iter.next(), None);
         }
     }
+
+    #[test]
+    fn test_make_contiguous() {
+        let mut rb: RingBuffer<u8, 4> = RingBuffer::new();
+        assert_eq!(rb.make_contiguous(), &[]);
+
+        rb.force_push(b'1');
+        rb.force_push(b'2');
+        rb.force_push(b'3');
+        assert_eq!(rb.make_contiguous(), b"123");
+
+        // wrap around
+        rb.force_push(b'4');
+        rb.force_push(b'5');
+        rb.force_push(b'6');
+        assert_eq!(rb.make_contiguous(), b"3456");
+        assert_eq!(rb.pop(), Some(b'3'));
+        assert!(rb.push(b'7'));
+        assert_eq!(rb.make_contiguous(), b"4567");
+    }
+
+    #[test]
+    fn test_discard() {
+        let mut rb: RingBuffer<u8, 4> = RingBuffer::new();
+        rb.force_push(b'1');
+        rb.force_push(b'2');
+        rb.force_push(b'3');
+
+        assert_eq!(rb.discard(2), 2);
+        assert_eq!(rb.len(), 1);
+        assert_eq!(rb.discard(2), 1);
+        assert_eq!(rb.len(), 0);
+        assert_eq!(rb.discard(2), 0);
+        assert_eq!(rb.pop(), None);
+    }
 }
@@ -5,30 +5,40 @@
 mod click_spi;
 mod display;
 mod esp3_serial;
-mod ring_buffer;
 mod usart;
 
 
+use core::fmt::Write;
 use core::panic::PanicInfo;
+use core::ptr::addr_of_mut;
 use core::time::Duration;
 
 use atsam3x8e::Peripherals;
 use atsam3x8e_ext::i2c_controller::{I2cController, Twi1I2cController};
 use atsam3x8e_ext::sam_pin;
 use atsam3x8e_ext::setup::system_init;
-use atsam3x8e_ext::tick::{delay, enable_tick_clock};
+use atsam3x8e_ext::tick::{delay, enable_tick_clock, tick_millis};
 use atsam3x8e_ext::uart;
 use buildingblocks::bit_field;
 use buildingblocks::crc8;
-use buildingblocks::esp3::{CommandData, Esp3Packet, EventData};
+use buildingblocks::esp3::{CommandData, Esp3PacketRef, EventData, MAX_ESP3_PACKET_LENGTH};
+use buildingblocks::esp3::decoder::Esp3Decoder;
+use buildingblocks::esp3::session::{DEFAULT_RESPONSE_TIMEOUT_MS, Esp3Request, Esp3Session};
 use buildingblocks::max_array::MaxArray;
 use cortex_m::Peripherals as CorePeripherals;
 use cortex_m_rt::{entry, exception};
 
 use crate::display::{DisplayCommand, Mikrobus1Twi1I2cOledDisplay, OledDisplay};
+use crate::esp3_serial::Esp3Buffer;
 use crate::usart::{Usart, Usart3};
 
 
+// these are too large for the stack; they are only ever accessed from main
+static mut ESP3_BUFFER: Esp3Buffer = Esp3Buffer::new();
+static mut ESP3_SESSION: Esp3Session<4> = Esp3Session::new(DEFAULT_RESPONSE_TIMEOUT_MS);
+static mut HEX_BUFFER: MaxArray<u8, {2*MAX_ESP3_PACKET_LENGTH}> = MaxArray::new();
+
+
 #[exception]
 unsafe fn DefaultHandler(_: i16) {
 }
@@ -63,11 +73,11 @@ fn hex_dump<const N: usize>(bytes: &[u8], hex: &mut MaxArray<u8, N>) {
     }
 }
 
-fn uart_send_hex_dump_outgoing(peripherals: &mut Peripherals, bytes: &[u8]) {
-    let mut outgoing_hex: MaxArray<u8, {2*buildingblocks::esp3::MAX_ESP3_PACKET_LENGTH}> = MaxArray::new();
-    hex_dump(bytes, &mut outgoing_hex);
+fn uart_send_hex_dump_outgoing<const N: usize>(peripherals: &mut Peripherals, hex: &mut MaxArray<u8, N>, bytes: &[u8]) {
+    hex.clear();
+    hex_dump(bytes, hex);
     uart::send(peripherals, b"sending an ESP3 packet: ");
-    uart::send(peripherals, outgoing_hex.as_slice());
+    uart::send(peripherals, hex.as_slice());
     uart::send(peripherals, b"\r\n");
 }
 
@@ -84,7 +94,7 @@ fn main() -> ! {
 
     // initialize system
     let mut clock = system_init(&mut peripherals);
-    enable_tick_clock(&mut core_peripherals, clock.clock_speed / 1000);
+    enable_tick_clock(&mut core_peripherals, clock.clock_speed, clock.clock_speed / 1000 - 1);
 
     // configure reset pins for all mikroBUS devices
     sam_pin!(enable_io, peripherals, PIOC, p14, p15, p16);
@@ -239,72 +249,75 @@ fn main() -> ! {
     }
     */
 
-    enum AwaitingWhat {
-        Nothing,
-        Version,
-    }
-    let mut awaiting = AwaitingWhat::Nothing;
+    let esp3_buffer = unsafe { &mut *addr_of_mut!(ESP3_BUFFER) };
+    let mut esp3_decoder = Esp3Decoder::new();
+    let esp3_session = unsafe { &mut *addr_of_mut!(ESP3_SESSION) };
+    let hex = unsafe { &mut *addr_of_mut!(HEX_BUFFER) };
 
     loop {
         // transfer from USART to ESP3 buffer
-        if let Some(buf) = Usart3::take_receive_buffer() {
-            for b in buf.iter() {
-                esp3_serial::push_to_buffer(*b);
-            }
-        }
+        esp3_serial::receive_into(esp3_buffer);
 
-        // try taking a packet
-        if let Some(packet) = esp3_serial::take_esp3_packet() {
+        // try taking a frame; it remains in the ESP3 buffer until the next poll
+        if let Some(frame) = esp3_decoder.poll_frame(esp3_buffer) {
             // hex-dump it
-            let mut hex: MaxArray<u8, {2*buildingblocks::esp3::MAX_ESP3_PACKET_LENGTH}> = MaxArray::new();
-            hex_dump(packet.as_slice(), &mut hex);
+            hex.clear();
+            hex_dump(frame, hex);
 
             // send the hex dump via UART
             uart::send(&mut peripherals, b"got an ESP3 packet: ");
             uart::send(&mut peripherals, hex.as_slice());
             uart::send(&mut peripherals, b"\r\n");
 
-            // decode it
-            let decoded_packet_opt = Esp3Packet::from_slice(packet.as_slice());
-            if let Some(decoded_packet) = decoded_packet_opt {
-                if let Esp3Packet::Event(event_packet) = decoded_packet {
-                    if let EventData::CoReady { .. } = event_packet {
-                        // send the version packet
-                        let version_packet_opt = Esp3Packet::CommonCommand(CommandData::CoRdVersion)
-                            .to_packet();
-                        let version_packet = match &version_packet_opt {
-                            Some(vp) => {
-                                uart::send(&mut peripherals, b"VERSION PACKET PACKAGED\r\n");
-                                vp
-                            },
-                            None => {
-                                uart::send(&mut peripherals, b"OH NO\r\n");
-                                loop {
-                                    delay(Duration::from_secs(5));
-                                }
-                            }
-                        };
-                        uart_send_hex_dump_outgoing(&mut peripherals, version_packet.as_slice());
-                        Usart3::transmit(&mut peripherals, version_packet.as_slice());
-                        awaiting = AwaitingWhat::Version;
-                    }
-                } else if let Esp3Packet::Response { .. } = decoded_packet {
-                    if let AwaitingWhat::Version = awaiting {
-                        // version packet received
-                        awaiting = AwaitingWhat::Nothing;
-
-                        // switch to transparent mode
-                        let pkt = Esp3Packet::CommonCommand(CommandData::CoWrTransparentMode {
-                            enable: true.into(),
-                        }).to_packet().unwrap();
+            // decode it in place
+            if let Some(packet) = esp3_decoder.decode(frame) {
+                let is_ready = match packet {
+                    Esp3PacketRef::Event { event_code, data, optional_data } => matches!(
+                        EventData::from_data(event_code, data, optional_data),
+                        Ok(EventData::CoReady { .. }),
+                    ),
+                    _ => false,
+                };
+                if is_ready {
+                    // the module has (re)started; ask for its version
+                    esp3_session.clear();
+                    let _ = esp3_session.enqueue(CommandData::CoRdVersion);
+                } else if let Some(event) = esp3_session.handle_packet_ref(&packet, tick_millis()) {
+                    if event.request == Esp3Request::Common(CommandData::CoRdVersion) && event.result.is_ok() {
+                        // version packet received; switch to transparent mode
                         uart::send(&mut peripherals, b"switching to transparent mode\r\n");
-                        uart_send_hex_dump_outgoing(&mut peripherals, pkt.as_slice());
-                        Usart3::transmit(&mut peripherals, pkt.as_slice());
+                        let _ = esp3_session.enqueue(CommandData::CoWrTransparentMode {
+                            enable: true.into(),
+                        });
                     }
                 }
             }
         }
 
+        // report corrupted packets
+        if let Some(error) = esp3_decoder.take_error() {
+            let mut message: MaxArray<u8, 160> = MaxArray::new();
+            let _ = write!(message, "failed to decode ESP3 packet {}\r\n", error);
+            uart::send(&mut peripherals, message.as_slice());
+        }
+
+        if esp3_session.poll_timeout(tick_millis()).is_some() {
+            uart::send(&mut peripherals, b"ESP3 command timed out\r\n");
+        }
+
+        // send the next command
+        if let Some(packet) = esp3_session.poll_transmit(tick_millis()) {
+            match packet.to_packet() {
+                Some(pkt) => {
+                    uart_send_hex_dump_outgoing(&mut peripherals, hex, pkt.as_slice());
+                    Usart3::transmit(&mut peripherals, pkt.as_slice());
+                },
+                None => {
+                    uart::send(&mut peripherals, b"OH NO\r\n");
+                },
+            }
+        }
+
         // doze off for a bit
         delay(Duration::from_millis(10));
     }
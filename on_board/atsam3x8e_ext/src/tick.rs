@@ -33,6 +33,8 @@ unsafe impl<T: Copy> Sync for VolatileStorage<T> {
 
 
 pub(crate) static TICK_CLOCK: VolatileStorage<u32> = VolatileStorage::new(0);
+static TICK_CLOCK_SPEED: VolatileStorage<u32> = VolatileStorage::new(1000);
+static TICK_RELOAD_VALUE: VolatileStorage<u32> = VolatileStorage::new(0);
 
 
 #[exception]
@@ -40,13 +42,20 @@ unsafe fn SysTick() {
     TICK_CLOCK.set(TICK_CLOCK.get().wrapping_add(1))
 }
 
-pub fn enable_tick_clock(core_peripherals: &mut Peripherals, frequency: u32) {
+/// Starts counting SysTick interrupts.
+///
+/// `clock_speed` is the frequency of the core clock in Hz; an interrupt is raised every
+/// `reload_value + 1` cycles of it. A reload value of `clock_speed / 1000 - 1` ticks once per
+/// millisecond.
+pub fn enable_tick_clock(core_peripherals: &mut Peripherals, clock_speed: u32, reload_value: u32) {
     const SYST_CSR_ENABLE_ENABLED: u32 = 1 << 0;
     const SYST_CSR_TICKINT_ENABLED: u32 = 1 << 1;
     const SYST_CSR_CLKSOURCE_MCK: u32 = 1 << 2;
 
+    TICK_CLOCK_SPEED.set(clock_speed);
+    TICK_RELOAD_VALUE.set(reload_value);
     unsafe {
-        core_peripherals.SYST.rvr.write(frequency)
+        core_peripherals.SYST.rvr.write(reload_value)
     };
     unsafe {
         core_peripherals.SYST.csr.write(
@@ -57,6 +66,16 @@ pub fn enable_tick_clock(core_peripherals: &mut Peripherals, frequency: u32) {
     };
 }
 
+/// The number of milliseconds since the tick clock has been enabled, wrapping around.
+///
+/// Unless a tick lasts exactly one millisecond, the value jumps when the tick count wraps around.
+#[inline]
+pub fn tick_millis() -> u32 {
+    let cycles_per_tick = u64::from(TICK_RELOAD_VALUE.get()) + 1;
+    let cycles = u64::from(TICK_CLOCK.get()) * cycles_per_tick;
+    (cycles * 1000 / u64::from(TICK_CLOCK_SPEED.get())) as u32
+}
+
 #[inline]
 pub fn delay(duration: Duration) {
     let ms_u128 = duration.as_millis();
@@ -66,8 +85,8 @@ pub fn delay(duration: Duration) {
         ms_u128 as u32
     };
 
-    let start = TICK_CLOCK.get();
-    while TICK_CLOCK.get() < start + ms {
+    let start = tick_millis();
+    while tick_millis().wrapping_sub(start) < ms {
         // nop
     }
 }
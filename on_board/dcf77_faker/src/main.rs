@@ -93,7 +93,7 @@ fn main() -> ! {
     let clock = system_init(&mut peripherals);
 
     // enable the tick clock and give us 3s to connect the debugger
-    atsam3x8e_ext::tick::enable_tick_clock(&mut core_peripherals, clock.clock_speed / 1000);
+    atsam3x8e_ext::tick::enable_tick_clock(&mut core_peripherals, clock.clock_speed, clock.clock_speed / 1000 - 1);
     //atsam3x8e_ext::tick::delay(core::time::Duration::from_secs(3));
 
     // initialize UART
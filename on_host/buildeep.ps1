@@ -1,2 +1,6 @@
-$downloadDir = (New-Object -ComObject "Shell.Application").NameSpace("shell:Downloads").Self.Path
-& cargo run --bin eepxml2rust --release "$downloadDir\enocean-eep268.xml" ..\buildingblocks\src\esp3\eep.rs
+# Regenerates buildingblocks\src\esp3\eep.rs from the vendored eep.xml.
+# Any changes to the features it prints have to be copied into buildingblocks\Cargo.toml.
+# To use a full eep.xml downloaded from the EEP Viewer instead, pass its path, e.g.:
+# .\buildeep.ps1 "$((New-Object -ComObject "Shell.Application").NameSpace("shell:Downloads").Self.Path)\enocean-eep268.xml"
+param([string]$EepXml = "..\buildingblocks\eep\eep.xml")
+& cargo run --bin eepxml2rust --release -- --print-features --fixed-point-decimals 2 "$EepXml" ..\buildingblocks\src\esp3\eep.rs
@@ -0,0 +1,13 @@
+#!/bin/sh
+# Regenerates buildingblocks/src/esp3/eep.rs from the vendored eep.xml.
+# Any changes to the features it prints have to be copied into buildingblocks/Cargo.toml.
+# To use a full eep.xml downloaded from the EEP Viewer instead, pass its path, e.g.:
+# ./buildeep.sh ~/Downloads/enocean-eep268.xml
+set -e
+if [ $# -gt 0 ]; then
+    EEP_XML="$(realpath "$1")"
+else
+    EEP_XML=../buildingblocks/eep/eep.xml
+fi
+cd "$(dirname "$0")"
+cargo run --bin eepxml2rust --release -- --print-features --fixed-point-decimals 2 "$EEP_XML" ../buildingblocks/src/esp3/eep.rs
@@ -1,7 +1,10 @@
 use buildingblocks::esp3::Esp3Packet;
-use buildingblocks::esp3::eep;
+use buildingblocks::esp3::eep_payload::{EepId, EepPayload};
 use buildingblocks::esp3::erp::ErpData;
-use buildingblocks::esp3::response_data::CommonCommandResponse;
+use buildingblocks::esp3::erp2::Erp2Telegram;
+use buildingblocks::esp3::response_data::{
+    Command24Response, CommonCommandResponse, SmartAckResponse,
+};
 use clap::Parser;
 
 
@@ -10,9 +13,18 @@ struct Args {
     #[arg(short, long)]
     pub eep: Option<String>,
 
+    #[arg(short, long)]
+    pub direction: Option<u8>,
+
     #[arg(short, long)]
     pub common_command: Option<u8>,
 
+    #[arg(short, long)]
+    pub smart_ack_command: Option<u8>,
+
+    #[arg(long)]
+    pub command24: Option<u8>,
+
     pub packet_hex_dump: String,
 }
 
@@ -68,88 +80,103 @@ fn main() {
         buf.push(unhex_byte(&hex_packet[2*i..2*i+2]));
     }
 
-    // attempt to decode this
-    let pkt_opt = Esp3Packet::from_slice(&buf);
-    println!("{:#?}", pkt_opt);
-
-    if let Some(pkt) = pkt_opt {
-        match pkt {
-            Esp3Packet::RadioErp1 { radio_telegram, .. } => {
-                // attempt to decode this message
-                let message_opt = ErpData::from_slice(radio_telegram.as_slice());
-                match message_opt {
-                    Some(msg) => {
-                        println!("decoded radio message: {:#?}", msg);
-
-                        if let Some((rorg, func, tp)) = rorg_func_type_opt {
-                            // decode further, using EEP
-                            match msg {
-                                ErpData::RepeatedSwitch(rst) => {
-                                    let reversed_bytes = [rst.data.reverse_bits()];
-                                    let decoded_opt = eep::Eep::from_reversed_bytes(rorg, func, tp, &reversed_bytes);
-                                    if let Some(decoded) = decoded_opt {
-                                        println!("decoded EEP: {:#?}", decoded);
-                                    } else {
-                                        println!("failed to decode EEP");
-                                    }
-                                },
-                                ErpData::OneByte(ob) => {
-                                    let reversed_bytes = [ob.data.reverse_bits()];
-                                    let decoded_opt = eep::Eep::from_reversed_bytes(rorg, func, tp, &reversed_bytes);
-                                    if let Some(decoded) = decoded_opt {
-                                        println!("decoded EEP: {:#?}", decoded);
-                                    } else {
-                                        println!("failed to decode EEP");
-                                    }
-                                },
-                                ErpData::FourByte(fb) => {
-                                    let bytes: [u8; 4] = fb.data.to_be_bytes();
-                                    let reversed_bytes = [
-                                        bytes[0].reverse_bits(),
-                                        bytes[1].reverse_bits(),
-                                        bytes[2].reverse_bits(),
-                                        bytes[3].reverse_bits(),
-                                    ];
-                                    let decoded_opt = eep::Eep::from_reversed_bytes(rorg, func, tp, &reversed_bytes);
-                                    if let Some(decoded) = decoded_opt {
-                                        println!("decoded EEP: {:#?}", decoded);
-                                    } else {
-                                        println!("failed to decode EEP");
-                                    }
-                                },
-                                ErpData::VariableLength(fb) => {
-                                    let mut reversed_bytes: Vec<u8> = Vec::with_capacity(fb.data.len());
-                                    reversed_bytes.extend(fb.data.iter().map(|b| b.reverse_bits()));
-                                    let decoded_opt = eep::Eep::from_reversed_bytes(rorg, func, tp, &reversed_bytes);
-                                    if let Some(decoded) = decoded_opt {
-                                        println!("decoded EEP: {:#?}", decoded);
-                                    } else {
-                                        println!("failed to decode EEP");
-                                    }
-                                },
-                                _ => {},
-                            }
-                        }
-                    },
-                    None => println!("failed to decode radio message"),
-                }
+    // attempt to decode all packets in the dump
+    for pkt_res in Esp3Packet::parse_all(&buf) {
+        match pkt_res {
+            Ok((offset, pkt)) => {
+                println!("packet at offset {}: {:#?}", offset, pkt);
+                decode_packet(&args, rorg_func_type_opt, pkt);
             },
-            Esp3Packet::Response { response_data, optional_data, .. } => {
-                if let Some(common_command) = args.common_command {
-                    // the user is hinting that this is a response to a common command
-                    let decoded_opt = CommonCommandResponse::decode_for_command(
-                        common_command,
-                        response_data.as_slice(),
-                        optional_data.as_slice(),
-                    );
-                    if let Some(decoded) = decoded_opt {
-                        println!("decoded common command response: {:#?}", decoded);
-                    } else {
-                        println!("failed to decode common command response");
+            Err(error) => println!("failed to decode packet {}", error),
+        }
+    }
+}
+
+
+fn decode_packet(args: &Args, rorg_func_type_opt: Option<(u8, u8, u8)>, pkt: Esp3Packet) {
+    match pkt {
+        Esp3Packet::RadioErp1 { radio_telegram, .. } => {
+            // attempt to decode this message
+            let message_res = ErpData::parse(radio_telegram.as_slice());
+            match message_res {
+                Ok(mut msg) => {
+                    println!("decoded radio message: {:#?}", msg);
+
+                    if let ErpData::AddressingDestination(adt) = &msg {
+                        // the profile applies to the encapsulated telegram
+                        match adt.decapsulate() {
+                            Some(inner) => {
+                                println!("decapsulated radio message: {:#?}", inner);
+                                msg = inner;
+                            },
+                            None => println!("failed to decapsulate radio message"),
+                        }
+                    }
+
+                    if let Some((rorg, func, tp)) = rorg_func_type_opt {
+                        // decode further, using EEP
+                        match EepPayload::new(&msg, EepId::new(rorg, func, tp)) {
+                            Ok(mut payload) => {
+                                if let Some(direction) = args.direction {
+                                    payload = payload.with_direction(direction.into());
+                                }
+                                match payload.decode() {
+                                    Ok(decoded) => println!("decoded EEP: {:#?}", decoded),
+                                    Err(error) => println!("failed to decode EEP: {}", error),
+                                }
+                            },
+                            Err(error) => println!("failed to decode EEP: {}", error),
+                        }
                     }
+                },
+                Err(error) => println!("failed to decode radio message {}", error),
+            }
+        },
+        Esp3Packet::RadioErp2 { data, .. } => {
+            // attempt to decode this ERP2 frame
+            match Erp2Telegram::from_slice(data.as_slice()) {
+                Some(telegram) => println!("decoded ERP2 radio message: {:#?}", telegram),
+                None => println!("failed to decode ERP2 radio message"),
+            }
+        },
+        Esp3Packet::Response { response_data, optional_data, .. } => {
+            if let Some(common_command) = args.common_command {
+                // the user is hinting that this is a response to a common command
+                let decoded_res = CommonCommandResponse::parse_for_command(
+                    common_command,
+                    response_data.as_slice(),
+                    optional_data.as_slice(),
+                );
+                match decoded_res {
+                    Ok(decoded) => println!("decoded common command response: {:#?}", decoded),
+                    Err(error) => println!("failed to decode common command response {}", error),
                 }
-            },
-            _ => {},
-        }
+            }
+            if let Some(smart_ack_command) = args.smart_ack_command {
+                // the user is hinting that this is a response to a Smart Ack command
+                let decoded_res = SmartAckResponse::parse_for_command(
+                    smart_ack_command,
+                    response_data.as_slice(),
+                    optional_data.as_slice(),
+                );
+                match decoded_res {
+                    Ok(decoded) => println!("decoded Smart Ack command response: {:#?}", decoded),
+                    Err(error) => println!("failed to decode Smart Ack command response {}", error),
+                }
+            }
+            if let Some(command24) = args.command24 {
+                // the user is hinting that this is a response to a 2.4 GHz command
+                let decoded_res = Command24Response::parse_for_command(
+                    command24,
+                    response_data.as_slice(),
+                    optional_data.as_slice(),
+                );
+                match decoded_res {
+                    Ok(decoded) => println!("decoded 2.4 GHz command response: {:#?}", decoded),
+                    Err(error) => println!("failed to decode 2.4 GHz command response {}", error),
+                }
+            }
+        },
+        _ => {},
     }
 }
@@ -14,8 +14,9 @@ use sxd_document;
 use sxd_xpath;
 
 use crate::model::{
-    Case, ConstEnumValue, Eeps, EnumeratedProperty, EnumValue, Func, NumericProperty, Property,
-    PropertyCommon, RangedEnumValue, RawOnlyProperty, Rorg, Type,
+    Case, Condition, ConstEnumValue, Eeps, EnumeratedProperty, EnumValue, FixedPointScaling, Func,
+    NumericProperty, Property, PropertyCommon, RangedEnumValue, RawOnlyProperty, Rorg,
+    StatementCondition, Type,
 };
 use crate::xpath_ext::{FactoryExt, XPathExt};
 
@@ -27,6 +28,12 @@ struct Args {
 
     #[clap(help = "Path to the eep.rs file to write.")]
     pub eep_rs: PathBuf,
+
+    #[clap(long, help = "Output the cargo features gating the profiles.", long_help = "Output the cargo features gating the profiles, to be pasted into the [features] section of the Cargo.toml file of the crate containing the eep.rs file.")]
+    pub print_features: bool,
+
+    #[clap(long, help = "Also generate fixed-point accessors with the given number of decimal places.", long_help = "Also generate accessors for numeric properties that use integer arithmetic, returning and accepting values scaled by 10 to the power of the given number of decimal places (e.g. 2 for hundredths of a degree Celsius). The floating-point accessors are then only compiled if the eep_float feature is enabled, which allows targets without an FPU to avoid floating-point arithmetic.")]
+    pub fixed_point_decimals: Option<u32>,
 }
 
 
@@ -43,10 +50,51 @@ fn parse_u32(s: &str) -> Result<u32, ParseIntError> {
 }
 
 
+/// Decodes the bytes of an eep.xml file: as UTF-16LE if they start with the respective byte order
+/// mark (as the file downloaded from the EEP Viewer does), otherwise as UTF-8.
+fn decode_xml_bytes(xml_bytes: Vec<u8>) -> String {
+    if !xml_bytes.starts_with(&[0xFF, 0xFE]) {
+        let xml_string = String::from_utf8(xml_bytes)
+            .expect("failed to decode eep.xml as UTF-8");
+        return match xml_string.strip_prefix('\u{FEFF}') {
+            Some(rest) => rest.to_owned(),
+            None => xml_string,
+        };
+    }
+
+    if !xml_bytes.len().is_multiple_of(2) {
+        panic!("eep.xml file (expected to be encoded using UTF-16LE) has an odd number of bits!");
+    }
+
+    let mut xml_units = Vec::with_capacity(xml_bytes.len()/2);
+    let mut xml_bytes_iter = xml_bytes.into_iter();
+    let mut first_character = true;
+    while let Some(first_byte) = xml_bytes_iter.next() {
+        // we checked this before...
+        let second_byte = xml_bytes_iter.next()
+            .expect("UTF-16 first byte without a second byte?!");
+
+        let u16_char = u16::from_le_bytes([first_byte, second_byte]);
+
+        if first_character && u16_char == 0xFEFF {
+            // skip it
+            first_character = false;
+            continue;
+        }
+        first_character = false;
+
+        xml_units.push(u16_char);
+    }
+
+    String::from_utf16(&xml_units)
+        .expect("failed to decode eep.xml as UTF-16LE")
+}
+
+
 fn main() {
     let args = Args::parse();
 
-    // read eep.xml -- warning: it's in UTF-16LE
+    // read eep.xml -- warning: the downloaded file is in UTF-16LE
     let xml_package = {
         let mut xml_file = File::open(&args.eep_xml)
             .expect("failed to open eep.xml file");
@@ -55,32 +103,7 @@ fn main() {
         xml_file.read_to_end(&mut xml_bytes)
             .expect("failed to read eep.xml file");
 
-        if xml_bytes.len() % 2 != 0 {
-            panic!("eep.xml file (expected to be encoded using UTF-16LE) has an odd number of bits!");
-        }
-
-        let mut xml_units = Vec::with_capacity(xml_bytes.len()/2);
-        let mut xml_bytes_iter = xml_bytes.into_iter();
-        let mut first_character = true;
-        while let Some(first_byte) = xml_bytes_iter.next() {
-            // we checked this before...
-            let second_byte = xml_bytes_iter.next()
-                .expect("UTF-16 first byte without a second byte?!");
-
-            let u16_char = u16::from_le_bytes([first_byte, second_byte]);
-
-            if first_character && u16_char == 0xFEFF {
-                // skip it
-                first_character = false;
-                continue;
-            }
-            first_character = false;
-
-            xml_units.push(u16_char);
-        }
-
-        let xml_string = String::from_utf16(&xml_units)
-            .expect("failed to decode eep.xml as UTF-16LE");
+        let xml_string = decode_xml_bytes(xml_bytes);
         sxd_document::parser::parse(&xml_string)
             .expect("failed to parse eep.xml")
     };
@@ -94,6 +117,8 @@ fn main() {
     let funcs_exp = xpath_factory.build_strict("./func");
     let types_exp = xpath_factory.build_strict("./type");
     let cases_exp = xpath_factory.build_strict("./case");
+    let condition_statements_exp = xpath_factory.build_strict("./condition/statement");
+    let condition_direction_exp = xpath_factory.build_strict("./condition/direction");
     let data_fields_exp = xpath_factory.build_strict("./datafield");
     let reserved_exp = xpath_factory.build_strict("./reserved");
     let enum_item_exp = xpath_factory.build_strict("./enum/item");
@@ -104,6 +129,7 @@ fn main() {
     let bit_offset_sxp = xpath_factory.build_strict("./bitoffs/text()");
     let bit_size_sxp = xpath_factory.build_strict("./bitsize/text()");
     let data_sxp = xpath_factory.build_strict("./data/text()");
+    let shortcut_sxp = xpath_factory.build_strict("./shortcut/text()");
     let min_sxp = xpath_factory.build_strict("./min/text()");
     let max_sxp = xpath_factory.build_strict("./max/text()");
     let range_min_sxp = xpath_factory.build_strict("./range/min/text()");
@@ -114,9 +140,11 @@ fn main() {
     let unit_sxp = xpath_factory.build_strict("./unit/text()");
     let value_sxp = xpath_factory.build_strict("./value/text()");
     let description_sxp = xpath_factory.build_strict("./description/text()");
+    let direction_sxp = xpath_factory.build_strict("./condition/direction/text()");
 
     let mut eeps = Eeps {
         rorgs: Vec::new(),
+        fixed_point_decimals: args.fixed_point_decimals,
     };
 
     // run through it
@@ -158,21 +186,58 @@ fn main() {
                 for (i, case) in cases.into_iter().enumerate() {
                     let mut case_def = Case {
                         number: (case_count > 1).then(|| i),
+                        bit_count: 0,
+                        conditions: Vec::new(),
                         properties: Vec::new(),
+                        lrn_bit_index: None,
                     };
 
+                    for statement in condition_statements_exp.eval_strict_nodeset(&xpath_ctx, case) {
+                        let bit_offset = bit_offset_sxp.eval_strict_stru32(&xpath_ctx, statement);
+                        let bit_size = bit_size_sxp.eval_strict_stru32(&xpath_ctx, statement);
+                        if bit_size > 64 {
+                            panic!("condition of {:?} checks more than 64 bits", type_def.name);
+                        }
+                        let value_string = value_sxp.eval_strict_string(&xpath_ctx, statement);
+                        let value = match parse_u32(value_string.trim()) {
+                            Ok(v) => v,
+                            Err(e) => panic!(
+                                "failed to parse condition value {:?} of {:?} as u32: {}",
+                                value_string, type_def.name, e,
+                            ),
+                        };
+                        case_def.conditions.push(Condition::Statement(StatementCondition {
+                            lowest_bit_index: bit_offset.try_into().unwrap(),
+                            bit_count: bit_size.try_into().unwrap(),
+                            value: value.into(),
+                        }));
+                    }
+                    if condition_direction_exp.eval_strict_node_exists(&xpath_ctx, case) {
+                        let direction = direction_sxp.eval_strict_stru8(&xpath_ctx, case);
+                        case_def.conditions.push(Condition::Direction(direction));
+                    }
+
                     let mut field_duplicate_counters: HashMap<String, usize> = HashMap::new();
                     for field in data_fields_exp.eval_strict_nodeset(&xpath_ctx, case) {
+                        let bit_offset = bit_offset_sxp.eval_strict_stru32(&xpath_ctx, field);
+                        let bit_size = bit_size_sxp.eval_strict_stru32(&xpath_ctx, field);
+
+                        // reserved bits count towards the length of the data too
+                        let field_end: usize = (bit_offset + bit_size).try_into().unwrap();
+                        case_def.bit_count = case_def.bit_count.max(field_end);
+
                         if reserved_exp.eval_strict_node_exists(&xpath_ctx, field) {
                             // bits are reserved; skip them
                             continue;
                         }
 
+                        if bit_size == 1 && shortcut_sxp.eval_strict_string(&xpath_ctx, field).trim() == "LRNB" {
+                            case_def.lrn_bit_index = Some(bit_offset.try_into().unwrap());
+                        }
+
                         let mut field_name = data_sxp.eval_strict_string(&xpath_ctx, field)
                             .trim()
                             .replace("\n", " ");
-                        let bit_offset = bit_offset_sxp.eval_strict_stru32(&xpath_ctx, field);
-                        let bit_size = bit_size_sxp.eval_strict_stru32(&xpath_ctx, field);
                         let unit = unit_sxp.eval_strict_string(&xpath_ctx, field)
                             .trim().to_owned();
 
@@ -256,12 +321,26 @@ fn main() {
                                 let max_range = range_max_sxp.eval_strict_strf64(&xpath_ctx, field);
                                 let min_scale = scale_min_sxp.eval_strict_strf64(&xpath_ctx, field);
                                 let max_scale = scale_max_sxp.eval_strict_strf64(&xpath_ctx, field);
+                                let fixed = args.fixed_point_decimals.and_then(|decimals| {
+                                    let scaling_res = FixedPointScaling::new(
+                                        decimals, common.raw_primitive_type,
+                                        min_range, max_range, min_scale, max_scale,
+                                    );
+                                    match scaling_res {
+                                        Ok(scaling) => Some(scaling),
+                                        Err(reason) => {
+                                            eprintln!("warning: {}; cannot generate fixed-point accessors for property {:?}", reason, common.name);
+                                            None
+                                        },
+                                    }
+                                });
                                 let num_prop = NumericProperty {
                                     common,
                                     min_range,
                                     max_range,
                                     min_scale,
                                     max_scale,
+                                    fixed,
                                 };
                                 Property::Numeric(num_prop)
                             }
@@ -386,6 +465,10 @@ fn main() {
         eeps.rorgs.push(rorg_def);
     }
 
+    if args.print_features {
+        print!("{}", eeps.features_toml());
+    }
+
     // render
     let rendered_template = eeps.render()
         .expect("failed to render template");
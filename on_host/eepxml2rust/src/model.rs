@@ -5,14 +5,99 @@ use askama::Template;
 #[template(path = "unpack.rs.askama", escape = "none", syntax = "asplike")]
 pub(crate) struct Eeps {
     pub rorgs: Vec<Rorg>,
+
+    /// If set, numeric properties additionally obtain fixed-point accessors with this many decimal
+    /// places, and the floating-point accessors are only compiled with the `eep_float` feature.
+    pub fixed_point_decimals: Option<u32>,
 }
 impl Eeps {
-    pub fn max_cases_per_type(&self) -> usize {
-        self.rorgs
+
+    /// The configuration predicate under which any profile is compiled.
+    pub fn cfg_any(&self) -> String {
+        let features: Vec<String> = self.rorgs
             .iter()
-            .map(|r| r.max_cases_per_type())
-            .max()
-            .unwrap_or(0)
+            .flat_map(|r| r.funcs.iter().map(|f| r.func_feature(f)))
+            .collect();
+        cfg_any_feature(&features)
+    }
+
+    /// The configuration predicate under which the floating-point helpers are compiled.
+    pub fn float_cfg(&self) -> String {
+        if self.fixed_point_decimals.is_some() {
+            format!("all({}, feature = \"eep_float\")", self.cfg_any())
+        } else {
+            self.cfg_any()
+        }
+    }
+
+    /// The configuration predicate under which the `<type_name>_from_bits` helper is compiled, or
+    /// `None` if no profile uses it.
+    pub fn bits_helper_cfg(&self, type_name: &str) -> Option<String> {
+        let features = self.bits_helper_features(&[type_name]);
+        if features.is_empty() {
+            None
+        } else {
+            Some(cfg_any_feature(&features))
+        }
+    }
+
+    /// The configuration predicate under which the `implement_horner` macro is compiled.
+    pub fn horner_cfg(&self) -> String {
+        cfg_any_feature(&self.bits_helper_features(&["u8", "u16", "u32", "u64"]))
+    }
+
+    /// The features of the FUNCs whose profiles use any of the `_from_bits` helpers of the given
+    /// types.
+    fn bits_helper_features(&self, type_names: &[&str]) -> Vec<String> {
+        let mut features = Vec::new();
+        for rorg in &self.rorgs {
+            for func in &rorg.funcs {
+                let uses_type = func.types
+                    .iter()
+                    .flat_map(|t| t.cases.iter())
+                    .any(|c| type_names.iter().any(|tn| c.uses_from_bits(tn)));
+                if uses_type {
+                    features.push(rorg.func_feature(func));
+                }
+            }
+        }
+        features
+    }
+
+    /// The `[features]` entries of the `Cargo.toml` file of the crate containing the profiles.
+    pub fn features_toml(&self) -> String {
+        use std::fmt::Write;
+
+        let mut ret = String::new();
+        let mut all_features = Vec::new();
+        for rorg in &self.rorgs {
+            let func_features: Vec<String> = rorg.funcs
+                .iter()
+                .map(|f| format!("{:?}", rorg.func_feature(f)))
+                .collect();
+            writeln!(ret, "{} = [{}]", rorg.feature(), func_features.join(", ")).unwrap();
+            for func in &rorg.funcs {
+                writeln!(ret, "{} = []", rorg.func_feature(func)).unwrap();
+            }
+            all_features.push(format!("{:?}", rorg.feature()));
+        }
+        writeln!(ret, "eep_all = [{}]", all_features.join(", ")).unwrap();
+        if self.fixed_point_decimals.is_some() {
+            writeln!(ret, "eep_float = []").unwrap();
+        }
+        ret
+    }
+}
+
+fn cfg_any_feature(features: &[String]) -> String {
+    let predicates: Vec<String> = features
+        .iter()
+        .map(|f| format!("feature = {:?}", f))
+        .collect();
+    if predicates.len() == 1 {
+        predicates.into_iter().next().unwrap()
+    } else {
+        format!("any({})", predicates.join(", "))
     }
 }
 
@@ -22,12 +107,23 @@ pub(crate) struct Rorg {
     pub funcs: Vec<Func>,
 }
 impl Rorg {
-    pub fn max_cases_per_type(&self) -> usize {
-        self.funcs
+    /// The name of the feature enabling all FUNCs of this RORG.
+    pub fn feature(&self) -> String {
+        format!("eep_{:02x}", self.code)
+    }
+
+    /// The name of the feature enabling the given FUNC of this RORG.
+    pub fn func_feature(&self, func: &Func) -> String {
+        format!("eep_{:02x}_{:02x}", self.code, func.code)
+    }
+
+    /// The configuration predicate under which this RORG is compiled.
+    pub fn cfg_any(&self) -> String {
+        let features: Vec<String> = self.funcs
             .iter()
-            .map(|t| t.max_cases_per_type())
-            .max()
-            .unwrap_or(0)
+            .map(|f| self.func_feature(f))
+            .collect();
+        cfg_any_feature(&features)
     }
 }
 
@@ -36,15 +132,6 @@ pub(crate) struct Func {
     pub code: u8,
     pub types: Vec<Type>,
 }
-impl Func {
-    pub fn max_cases_per_type(&self) -> usize {
-        self.types
-            .iter()
-            .map(|t| t.cases.len())
-            .max()
-            .unwrap_or(0)
-    }
-}
 
 pub(crate) struct Type {
     pub name: String,
@@ -54,7 +141,101 @@ pub(crate) struct Type {
 
 pub(crate) struct Case {
     pub number: Option<usize>,
+    pub bit_count: usize,
+    pub conditions: Vec<Condition>,
     pub properties: Vec<Property>,
+
+    /// The index of the LRN bit, which is set in data telegrams and cleared in teach-in telegrams.
+    pub lrn_bit_index: Option<usize>,
+}
+impl Case {
+    pub fn byte_count(&self) -> usize {
+        self.bit_count.div_ceil(8)
+    }
+
+    /// The Rust array expression of the data with the LRN bit marking a data telegram and the bits
+    /// checked by the conditions of this case set accordingly, and all other bits cleared.
+    pub fn initial_reversed_bytes(&self) -> String {
+        let mut bytes = vec![0u8; self.byte_count()];
+        let mut set_bits = |lowest_bit_index: usize, bit_count: usize, value: u64| {
+            // same bit order as the BitTwiddlingMut implementation
+            for i in 0..bit_count {
+                if value & (1 << i) != 0 {
+                    let bit_index = lowest_bit_index + bit_count - (i + 1);
+                    bytes[bit_index / 8] |= 1 << (bit_index % 8);
+                }
+            }
+        };
+        if let Some(lrn_bit_index) = self.lrn_bit_index {
+            set_bits(lrn_bit_index, 1, 1);
+        }
+        for condition in &self.conditions {
+            if let Condition::Statement(st) = condition {
+                set_bits(st.lowest_bit_index, st.bit_count, st.value);
+            }
+        }
+        let byte_strings: Vec<String> = bytes
+            .iter()
+            .map(|b| format!("0x{:02X}", b))
+            .collect();
+        format!("[{}]", byte_strings.join(", "))
+    }
+
+    /// Whether the accessors of this case use the `_from_bits` helper of the given type.
+    pub fn uses_from_bits(&self, type_name: &str) -> bool {
+        let in_conditions = type_name == "u64" && self.conditions
+            .iter()
+            .any(|c| matches!(c, Condition::Statement(_)));
+        in_conditions || self.properties
+            .iter()
+            .any(|p| p.common().raw_primitive_type == type_name)
+    }
+
+    pub fn has_direction_condition(&self) -> bool {
+        self.conditions
+            .iter()
+            .any(|c| matches!(c, Condition::Direction(_)))
+    }
+
+    /// The Rust expression checking whether the data fulfills all conditions of this case.
+    pub fn condition_expr(&self) -> String {
+        if self.conditions.is_empty() {
+            return "true".to_owned();
+        }
+        let predicates: Vec<String> = self.conditions
+            .iter()
+            .map(|c| c.expr())
+            .collect();
+        predicates.join(" && ")
+    }
+}
+
+pub(crate) enum Condition {
+    /// The value of the given bits has to equal the given value.
+    Statement(StatementCondition),
+
+    /// The telegram has to be sent in the given direction.
+    Direction(u8),
+}
+impl Condition {
+    pub fn expr(&self) -> String {
+        match self {
+            Self::Statement(st) => format!(
+                "self.reversed_bytes.u64_from_bits({}, {}) == Some({})",
+                st.lowest_bit_index, st.bit_count, st.value,
+            ),
+            Self::Direction(d) => format!(
+                "opt_direction.map(u8::from) == Some({})",
+                d,
+            ),
+        }
+    }
+}
+
+pub(crate) struct StatementCondition {
+    pub lowest_bit_index: usize,
+    pub bit_count: usize,
+    pub value: u64,
 }
 
 pub(crate) enum Property {
@@ -79,6 +260,25 @@ pub(crate) struct PropertyCommon {
     pub bit_count: usize,
     pub unit: Option<String>,
 }
+impl PropertyCommon {
+    /// The largest raw value that fits into the bits of this property, if the raw type can hold
+    /// larger values.
+    pub fn raw_max(&self) -> Option<u128> {
+        let type_bits = match self.raw_primitive_type {
+            "u8" => 8,
+            "u16" => 16,
+            "u32" => 32,
+            "u64" => 64,
+            "u128" => 128,
+            _ => return None,
+        };
+        if self.bit_count < type_bits {
+            Some((1 << self.bit_count) - 1)
+        } else {
+            None
+        }
+    }
+}
 
 pub(crate) struct NumericProperty {
     pub common: PropertyCommon,
@@ -86,6 +286,167 @@ pub(crate) struct NumericProperty {
     pub max_range: f64,
     pub min_scale: f64,
     pub max_scale: f64,
+    pub fixed: Option<FixedPointScaling>,
+}
+
+/// Scaling of a numeric property using integer arithmetic.
+///
+/// The fixed-point value is the scaled value multiplied by `10^decimals`. Since the range consists
+/// of raw (integer) values and the scale has to be representable with the given number of decimal
+/// places, the conversion between both is an exact rational function.
+pub(crate) struct FixedPointScaling {
+    pub decimals: u32,
+    pub primitive_type: &'static str,
+    pub min_range: i64,
+    pub max_range: i64,
+    pub min_scale: i64,
+    pub max_scale: i64,
+
+    /// The numerator of the factor converting raw values to fixed-point values.
+    pub numerator: i64,
+
+    /// The denominator of the factor converting raw values to fixed-point values; always positive.
+    pub denominator: i64,
+}
+impl FixedPointScaling {
+    /// Attempts to derive an exact fixed-point scaling from the given floating-point scaling.
+    pub fn new(decimals: u32, raw_primitive_type: &str, min_range: f64, max_range: f64, min_scale: f64, max_scale: f64) -> Result<Self, String> {
+        fn exact_integer(value: f64) -> Option<i64> {
+            let rounded = value.round();
+            if (value - rounded).abs() > 1e-6 || rounded.abs() > (1u64 << 53) as f64 {
+                None
+            } else {
+                Some(rounded as i64)
+            }
+        }
+        fn gcd(mut a: i64, mut b: i64) -> i64 {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a.abs()
+        }
+
+        if !matches!(raw_primitive_type, "u8" | "u16" | "u32") {
+            return Err(format!("raw type {} not supported", raw_primitive_type));
+        }
+        let factor = 10f64.powi(decimals.try_into().unwrap());
+        let min_range_int = exact_integer(min_range)
+            .ok_or_else(|| format!("range minimum {} is not an integer", min_range))?;
+        let max_range_int = exact_integer(max_range)
+            .ok_or_else(|| format!("range maximum {} is not an integer", max_range))?;
+        let min_scale_int = exact_integer(min_scale * factor)
+            .ok_or_else(|| format!("scale minimum {} has more than {} decimal places", min_scale, decimals))?;
+        let max_scale_int = exact_integer(max_scale * factor)
+            .ok_or_else(|| format!("scale maximum {} has more than {} decimal places", max_scale, decimals))?;
+        if min_range_int == max_range_int {
+            return Err("range is empty".to_owned());
+        }
+        if min_scale_int == max_scale_int {
+            return Err("scale is empty".to_owned());
+        }
+
+        let mut numerator = max_scale_int - min_scale_int;
+        let mut denominator = max_range_int - min_range_int;
+        if denominator < 0 {
+            numerator = -numerator;
+            denominator = -denominator;
+        }
+        let divisor = gcd(numerator, denominator);
+        numerator /= divisor;
+        denominator /= divisor;
+
+        // the intermediate products must fit into an i64
+        let range_span = i128::from(denominator) * i128::from(divisor);
+        let scale_span = i128::from(numerator.abs()) * i128::from(divisor);
+        if range_span * i128::from(numerator.abs()) > i128::from(i64::MAX)
+                || scale_span * i128::from(denominator) > i128::from(i64::MAX) {
+            return Err("scaling overflows 64-bit integers".to_owned());
+        }
+
+        let fits_i32 = i32::try_from(min_scale_int).is_ok() && i32::try_from(max_scale_int).is_ok();
+        Ok(Self {
+            decimals,
+            primitive_type: if fits_i32 { "i32" } else { "i64" },
+            min_range: min_range_int,
+            max_range: max_range_int,
+            min_scale: min_scale_int,
+            max_scale: max_scale_int,
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn bottom_range(&self) -> i64 { self.min_range.min(self.max_range) }
+    pub fn top_range(&self) -> i64 { self.min_range.max(self.max_range) }
+    pub fn bottom_scale(&self) -> i64 { self.min_scale.min(self.max_scale) }
+    pub fn top_scale(&self) -> i64 { self.min_scale.max(self.max_scale) }
+
+    /// The unit of the fixed-point value, e.g. "0.01 °C".
+    pub fn unit(&self, opt_unit: &Option<String>) -> String {
+        let mut ret = if self.decimals == 0 {
+            "1".to_owned()
+        } else {
+            format!("0.{}1", "0".repeat((self.decimals - 1).try_into().unwrap()))
+        };
+        if let Some(unit) = opt_unit {
+            ret.push(' ');
+            ret.push_str(unit);
+        }
+        ret
+    }
+
+    /// The Rust expression converting `raw_value` (an `i64`) into the fixed-point value (an
+    /// `i64`).
+    pub fn decode_expr(&self) -> String {
+        let offset_raw = offset_expr("raw_value", self.min_range);
+        let scaled = scale_expr(offset_raw, self.numerator, self.denominator);
+        if self.min_scale == 0 {
+            unparenthesize(scaled)
+        } else {
+            format!("{} + {}", self.min_scale, scaled)
+        }
+    }
+
+    /// The Rust expression converting `value` (an `i64`) into the raw value (an `i64`).
+    pub fn encode_expr(&self) -> String {
+        let offset_value = offset_expr("value", self.min_scale);
+        let unscaled = scale_expr(offset_value, self.denominator, self.numerator);
+        if self.min_range == 0 {
+            unparenthesize(unscaled)
+        } else {
+            format!("{} + {}", self.min_range, unscaled)
+        }
+    }
+}
+
+fn scale_expr(operand: String, multiplier: i64, divisor: i64) -> String {
+    let product = match multiplier {
+        1 => operand,
+        -1 => format!("-{}", operand),
+        _ => format!("{} * {}", operand, multiplier),
+    };
+    if divisor == 1 {
+        product
+    } else {
+        format!("div_round({}, {})", product, divisor)
+    }
+}
+
+fn unparenthesize(expr: String) -> String {
+    match expr.strip_prefix('(').and_then(|e| e.strip_suffix(')')) {
+        Some(inner) if !inner.contains('(') => inner.to_owned(),
+        _ => expr,
+    }
+}
+
+fn offset_expr(variable: &str, offset: i64) -> String {
+    if offset == 0 {
+        variable.to_owned()
+    } else if offset < 0 {
+        format!("({} + {})", variable, -offset)
+    } else {
+        format!("({} - {})", variable, offset)
+    }
 }
 
 pub(crate) struct EnumeratedProperty {